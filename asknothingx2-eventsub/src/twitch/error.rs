@@ -9,6 +9,84 @@ pub enum HeaderVerificationError {
     SignatureVerification(#[from] hmac::digest::MacError),
     #[error("Invalid hex signature")]
     InvalidHexSignature(#[from] hex::FromHexError),
+    #[error("Invalid message timestamp: {0}")]
+    InvalidTimestamp(String),
+    #[error("Message is {age:?} old, older than {max_age:?}")]
+    MessageExpired {
+        age: std::time::Duration,
+        max_age: std::time::Duration,
+    },
+    #[error("Message timestamp is {0:?} in the future")]
+    TimestampInFuture(std::time::Duration),
+    #[error("Message already processed: {0}")]
+    DuplicateMessage(String),
+}
+
+#[cfg(feature = "twitch-webhook")]
+#[derive(Debug, thiserror::Error)]
+pub enum SecretError {
+    #[error("Secret must be 10 to 100 characters long, got {0}")]
+    InvalidLength(usize),
+    #[error("Secret must only contain ASCII characters")]
+    NonAscii,
+    #[error("Failed to read secret from environment: {0}")]
+    Env(#[from] std::env::VarError),
+    #[error("Failed to read secret file: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(feature = "twitch-webhook")]
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    #[error("Signature verification failed: {0}")]
+    Verification(#[from] HeaderVerificationError),
+    #[error("Missing required header: {0}")]
+    MissingHeader(&'static str),
+    #[error("Invalid header value: {name}={value}")]
+    InvalidHeader { name: &'static str, value: String },
+    #[error("Invalid message type: {0}")]
+    InvalidMessageType(String),
+    #[error("JSON parsing error: {0}")]
+    JsonParse(#[from] serde_json::Error),
+}
+
+#[cfg(feature = "twitch-webhook")]
+impl WebhookError {
+    /// The status to answer the callback request with.
+    /// Duplicates are acknowledged so Twitch stops retrying them.
+    pub fn status(&self) -> http::StatusCode {
+        match self {
+            Self::Verification(HeaderVerificationError::DuplicateMessage(_)) => {
+                http::StatusCode::NO_CONTENT
+            }
+            Self::Verification(_) => http::StatusCode::FORBIDDEN,
+            _ => http::StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+#[cfg(feature = "twitch-webhook-server")]
+#[derive(Debug, thiserror::Error)]
+pub enum ServerError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid PEM: {0}")]
+    Pem(#[from] tokio_rustls::rustls::pki_types::pem::Error),
+    #[error("TLS error: {0}")]
+    Tls(#[from] tokio_rustls::rustls::Error),
+}
+
+#[cfg(feature = "twitch-webhook-test")]
+#[derive(Debug, thiserror::Error)]
+pub enum SenderError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("HTTP error: {0}")]
+    Http(#[from] hyper::Error),
+    #[error("Invalid callback URL: {0}")]
+    InvalidUrl(String),
+    #[error("An https URL requires a TLS config")]
+    MissingTlsConfig,
 }
 
 #[cfg(feature = "twitch-websocket")]
@@ -23,3 +101,99 @@ pub enum WebSocketError {
     #[error("Deserialization error: {0}")]
     DeserializeError(String),
 }
+
+#[cfg(feature = "twitch-router")]
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error("WebSocket error: {0}")]
+    WebSocket(#[from] tokio_tungstenite::tungstenite::Error),
+    #[error("Message error: {0}")]
+    Message(#[from] WebSocketError),
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("Connection closed: {0}")]
+    Closed(crate::twitch::websocket_message::CloseReason),
+    #[error("Connection closed without a close frame")]
+    Disconnected,
+    #[error("No message received within {0:?}")]
+    KeepaliveTimeout(std::time::Duration),
+    #[error("Reconnect message without reconnect_url")]
+    MissingReconnectUrl,
+    #[error("Unexpected message: {0}")]
+    UnexpectedMessage(String),
+    #[error("No subscription was created within {0:?} of the Welcome message")]
+    SubscribeWindowExpired(std::time::Duration),
+}
+
+#[cfg(feature = "twitch-router")]
+impl ClientError {
+    /// What the client should do about this error.
+    /// Closes without a close frame and transport failures are treated as transient.
+    pub fn action(&self) -> crate::twitch::websocket_message::CloseAction {
+        use crate::twitch::websocket_message::CloseAction;
+
+        match self {
+            Self::Closed(reason) => reason.action(),
+            Self::SubscribeWindowExpired(_) => CloseAction::Resubscribe,
+            Self::Disconnected
+            | Self::WebSocket(_)
+            | Self::KeepaliveTimeout(_)
+            | Self::MissingReconnectUrl
+            | Self::UnexpectedMessage(_) => CloseAction::Reconnect,
+            Self::Message(_) | Self::InvalidUrl(_) => CloseAction::Fatal,
+        }
+    }
+}
+
+#[cfg(feature = "twitch-router")]
+#[derive(Debug, thiserror::Error)]
+pub enum HandlerError {
+    #[error("Handler for {kind} failed: {source}")]
+    Failed {
+        kind: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("Handler for {kind} panicked: {message}")]
+    Panicked { kind: String, message: String },
+}
+
+#[cfg(any(feature = "twitch-router", feature = "twitch-manage"))]
+#[derive(Debug, thiserror::Error)]
+pub enum RequestError {
+    #[error("Request failed: {0}")]
+    Request(#[from] twitch_highway::Error),
+    #[error("Twitch API error ({status}): {message}")]
+    Api {
+        status: asknothingx2_util::api::StatusCode,
+        message: String,
+    },
+    #[error("Failed to decode response: {0}")]
+    Decode(#[from] serde_json::Error),
+}
+
+#[cfg(feature = "twitch-router")]
+#[derive(Debug, thiserror::Error)]
+pub enum SubscribeError {
+    #[error(transparent)]
+    Request(#[from] RequestError),
+    #[error("Not created before the subscribe window closed")]
+    WindowExpired,
+    #[error("Rejected locally: {0}")]
+    Limit(#[from] LimitError),
+    #[error("Shard was not updated: {message} ({code})")]
+    ShardRejected { code: String, message: String },
+}
+
+#[cfg(feature = "twitch-router")]
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum LimitError {
+    #[error("Session already has the maximum of {0} subscriptions")]
+    SessionFull(usize),
+    #[error("All of the maximum {0} WebSocket connections are full")]
+    TooManyConnections(usize),
+    #[error("Not enough subscription cost left: {total_cost}/{max_total_cost}")]
+    CostExceeded {
+        total_cost: u64,
+        max_total_cost: u64,
+    },
+}
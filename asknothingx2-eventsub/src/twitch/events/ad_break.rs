@@ -0,0 +1,20 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::twitch::types::{timestamp::rfc3339_nanos, BroadcasterUserId};
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-ad-break-begin-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AdBreakBeginEvent {
+    pub duration_seconds: u64,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    pub is_automatic: bool,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    /// The broadcaster for automatic ad breaks.
+    pub requester_user_id: String,
+    pub requester_user_login: String,
+    pub requester_user_name: String,
+}
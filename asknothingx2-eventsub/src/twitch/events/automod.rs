@@ -1,20 +1,195 @@
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
+use super::chat::ChatMessage;
+use crate::twitch::types::{timestamp::rfc3339_nanos, BroadcasterUserId};
+
+/// `automod.message.hold` v1
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-message-hold-event>
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MessageHoldEvent {
-    message_id: String,
-    message: MessageBody,
-    category: String,
-    level: u64,
-    held_at: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub message_id: String,
+    pub message: ChatMessage,
+    pub category: String,
+    pub level: u8,
+    #[serde(with = "rfc3339_nanos")]
+    pub held_at: DateTime<FixedOffset>,
 }
 
+/// `automod.message.hold` v2
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-message-hold-v2-event>
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MessageHoldV2Event {
-    message_id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub message_id: String,
+    pub message: ChatMessage,
+    /// `reason` and its metadata.
+    #[serde(flatten)]
+    pub reason: HoldReason,
+    #[serde(with = "rfc3339_nanos")]
+    pub held_at: DateTime<FixedOffset>,
+}
+
+/// `automod.message.update` v1
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-message-update-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MessageUpdateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub message_id: String,
+    pub message: ChatMessage,
+    pub category: String,
+    pub level: u8,
+    pub status: MessageStatus,
+    #[serde(with = "rfc3339_nanos")]
+    pub held_at: DateTime<FixedOffset>,
+}
+
+/// `automod.message.update` v2
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-message-update-v2-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MessageUpdateV2Event {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub message_id: String,
+    pub message: ChatMessage,
+    /// `reason` and its metadata.
+    #[serde(flatten)]
+    pub reason: HoldReason,
+    pub status: MessageStatus,
+    #[serde(with = "rfc3339_nanos")]
+    pub held_at: DateTime<FixedOffset>,
+}
+
+field_tagged!(
+    /// Why a message was held, from `reason`.
+    pub enum HoldReason: "reason" {
+        Automod(AutomodReason) => "automod",
+        BlockedTerm(BlockedTermReason) => "blocked_term",
+    }
+);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomodReason {
+    pub category: String,
+    pub level: u8,
+    /// The flagged spans of the message text.
+    pub boundaries: Vec<Boundary>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BlockedTermReason {
+    pub terms_found: Vec<TermFound>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct MessageBody {
-    text: String,
+pub struct TermFound {
+    pub term_id: String,
+    pub boundary: Boundary,
+    /// The broadcaster whose blocked term list the term is on, which may be
+    /// another channel in a shared chat session.
+    pub owner_broadcaster_user_id: String,
+    pub owner_broadcaster_user_login: String,
+    pub owner_broadcaster_user_name: String,
+}
+
+/// Inclusive character positions in the message text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Boundary {
+    pub start_pos: u64,
+    pub end_pos: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MessageStatus {
+    Approved,
+    Denied,
+    /// No moderator acted before the hold timed out.
+    Expired,
+    /// A value this crate doesn't know yet, kept as sent so it round-trips.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-settings-update-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SettingsUpdateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    /// `None` if the categories were set individually.
+    pub overall_level: Option<u8>,
+    #[serde(flatten)]
+    pub levels: Levels,
+}
+
+/// The level per category, from 0 (no filtering) to 4 (most filtering).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Levels {
+    pub disability: u8,
+    pub aggression: u8,
+    pub sexuality_sex_or_gender: u8,
+    pub misogyny: u8,
+    pub bullying: u8,
+    pub swearing: u8,
+    pub race_ethnicity_or_religion: u8,
+    pub sex_based_terms: u8,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-terms-update-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TermsUpdateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub action: TermsUpdateAction,
+    /// Whether the terms were added by AutoMod rather than a moderator.
+    pub from_automod: bool,
+    pub terms: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TermsUpdateAction {
+    AddPermitted,
+    RemovePermitted,
+    AddBlocked,
+    RemoveBlocked,
+    /// A value this crate doesn't know yet, kept as sent so it round-trips.
+    #[serde(untagged)]
+    Unknown(String),
 }
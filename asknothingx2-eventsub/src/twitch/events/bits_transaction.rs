@@ -1,13 +1,16 @@
 use serde::{Deserialize, Serialize};
-use twitch_highway::types::UserId;
 
-use crate::twitch::types::objects::Product;
+use crate::twitch::types::{objects::Product, BroadcasterUserId};
 
 /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#extension-bits-transaction-create-event>
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BitsTransactionEvent {
+    pub id: String,
     pub extension_client_id: String,
-    pub user_id: UserId,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
     pub user_login: String,
     pub user_name: String,
     pub product: Product,
@@ -1,27 +1,29 @@
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ChannelEvent {
-    moderator_user_id: String,
-    moderator_user_login: String,
-    moderator_user_name: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct WarningSendEvent {
-    pub reason: Option<String>,
-    pub chat_rules_cited: Option<Vec<String>>,
-}
+use crate::twitch::types::{timestamp::rfc3339_nanos, BroadcasterUserId};
 
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-follow-event>
 #[derive(Debug, Serialize, Deserialize)]
-pub struct SuspiciousUserMessageEvent {
-    low_trust_status: String,
-    shared_ban_channel_ids: Vec<String>,
-    types: Vec<String>,
-    ban_evasion_evaluation: String,
+pub struct ChannelFollowEvent {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    #[serde(with = "rfc3339_nanos")]
+    pub followed_at: DateTime<FixedOffset>,
 }
 
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-raid-event>
 #[derive(Debug, Serialize, Deserialize)]
-pub struct SubscriptionMessageEvent {
-    tier: String,
+pub struct ChannelRaidEvent {
+    pub from_broadcaster_user_id: BroadcasterUserId,
+    pub from_broadcaster_user_login: String,
+    pub from_broadcaster_user_name: String,
+    pub to_broadcaster_user_id: BroadcasterUserId,
+    pub to_broadcaster_user_login: String,
+    pub to_broadcaster_user_name: String,
+    pub viewers: u64,
 }
@@ -0,0 +1,83 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::twitch::types::{
+    objects::{AutomaticReward, GlobalCooldown, Image, MaxPerStream, Message, Reward},
+    timestamp::rfc3339_nanos,
+    BroadcasterUserId,
+};
+
+/// `channel.channel_points_custom_reward.add`, `.update` and `.remove`
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-points-custom-reward-add-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomRewardEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub is_enabled: bool,
+    pub is_paused: bool,
+    pub is_in_stock: bool,
+    pub title: String,
+    pub cost: u64,
+    pub prompt: String,
+    pub is_user_input_required: bool,
+    pub should_redemptions_skip_request_queue: bool,
+    pub max_per_stream: MaxPerStream,
+    pub max_per_user_per_stream: MaxPerStream,
+    pub background_color: String,
+    /// `None` if the broadcaster didn't upload images; see `default_image`.
+    pub image: Option<Image>,
+    pub default_image: Image,
+    pub global_cooldown: GlobalCooldown,
+    /// `None` unless the reward is on cooldown.
+    #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
+    pub cooldown_expires_at: Option<DateTime<FixedOffset>>,
+    /// `None` if the stream is offline or `max_per_stream` is off.
+    pub redemptions_redeemed_current_stream: Option<u64>,
+}
+
+/// `channel.channel_points_custom_reward_redemption.add` and `.update`
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-points-custom-reward-redemption-add-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomRewardRedemptionEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    /// Empty unless the reward requires user input.
+    pub user_input: String,
+    pub status: RedemptionStatus,
+    pub reward: Reward,
+    #[serde(with = "rfc3339_nanos")]
+    pub redeemed_at: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedemptionStatus {
+    Unknown,
+    Unfulfilled,
+    Fulfilled,
+    Canceled,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-points-automatic-reward-redemption-add-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomaticRewardRedemptionEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub id: String,
+    pub reward: AutomaticReward,
+    pub message: Message,
+    pub user_input: Option<String>,
+    #[serde(with = "rfc3339_nanos")]
+    pub redeemed_at: DateTime<FixedOffset>,
+}
@@ -1,6 +1,9 @@
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 use twitch_highway::charity::types::Amount;
 
+use crate::twitch::types::timestamp::rfc3339_nanos;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CharityEvent {
     pub charity_name: String,
@@ -13,8 +16,10 @@ pub struct CharityEvent {
 pub struct CharityCampaignEvent {
     pub current_amount: Amount,
     pub target_amount: Amount,
-    pub started_at: Option<String>,
-    pub stopped_at: Option<String>,
+    #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
+    pub started_at: Option<DateTime<FixedOffset>>,
+    #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
+    pub stopped_at: Option<DateTime<FixedOffset>>,
 }
 
 // With Event, UserEvent,CharityEvent
@@ -0,0 +1,371 @@
+use serde::{Deserialize, Serialize};
+use twitch_highway::charity::types::Amount;
+
+use crate::twitch::types::{objects::Tier, BroadcasterUserId};
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-message-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatMessageEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub chatter_user_id: String,
+    pub chatter_user_login: String,
+    pub chatter_user_name: String,
+    pub message_id: String,
+    pub message: ChatMessage,
+    pub message_type: ChatMessageType,
+    pub badges: Vec<Badge>,
+    pub cheer: Option<Cheer>,
+    /// Hex color of the chatter's name, empty if they never set one.
+    pub color: String,
+    pub reply: Option<Reply>,
+    pub channel_points_custom_reward_id: Option<String>,
+    /// Set when the message was sent from another channel in a shared chat session.
+    #[serde(default)]
+    pub source_broadcaster_user_id: Option<String>,
+    #[serde(default)]
+    pub source_broadcaster_user_login: Option<String>,
+    #[serde(default)]
+    pub source_broadcaster_user_name: Option<String>,
+    #[serde(default)]
+    pub source_message_id: Option<String>,
+    #[serde(default)]
+    pub source_badges: Option<Vec<Badge>>,
+}
+
+/// The text of a chat message, split into fragments.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub text: String,
+    pub fragments: Vec<Fragment>,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-message-event>
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Fragment {
+    Text {
+        text: String,
+    },
+    Cheermote {
+        text: String,
+        cheermote: FragmentCheermote,
+    },
+    Emote {
+        text: String,
+        emote: FragmentEmote,
+    },
+    Mention {
+        text: String,
+        mention: FragmentMention,
+    },
+}
+
+impl Fragment {
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Text { text }
+            | Self::Cheermote { text, .. }
+            | Self::Emote { text, .. }
+            | Self::Mention { text, .. } => text,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FragmentCheermote {
+    pub prefix: String,
+    pub bits: u64,
+    pub tier: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FragmentEmote {
+    pub id: String,
+    pub emote_set_id: String,
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    /// `static` and, if the emote is animated, `animated`.
+    #[serde(default)]
+    pub format: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FragmentMention {
+    pub user_id: String,
+    pub user_name: String,
+    pub user_login: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatMessageType {
+    Text,
+    ChannelPointsHighlighted,
+    ChannelPointsSubOnly,
+    UserIntro,
+    PowerUpsMessageEffect,
+    PowerUpsGigantifiedEmote,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Badge {
+    pub set_id: String,
+    pub id: String,
+    /// e.g. the number of months for a subscriber badge.
+    pub info: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Cheer {
+    pub bits: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Reply {
+    pub parent_message_id: String,
+    pub parent_message_body: String,
+    pub parent_user_id: String,
+    pub parent_user_name: String,
+    pub parent_user_login: String,
+    pub thread_message_id: String,
+    pub thread_user_id: String,
+    pub thread_user_name: String,
+    pub thread_user_login: String,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-clear-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatClearEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-clear-user-messages-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatClearUserMessagesEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub target_user_id: String,
+    pub target_user_login: String,
+    pub target_user_name: String,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-message-delete-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatMessageDeleteEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub target_user_id: String,
+    pub target_user_login: String,
+    pub target_user_name: String,
+    pub message_id: String,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-settings-update-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatSettingsUpdateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub emote_mode: bool,
+    pub follower_mode: bool,
+    /// `None` unless `follower_mode` is on.
+    pub follower_mode_duration_minutes: Option<u64>,
+    pub slow_mode: bool,
+    /// `None` unless `slow_mode` is on.
+    pub slow_mode_wait_time_seconds: Option<u64>,
+    pub subscriber_mode: bool,
+    pub unique_chat_mode: bool,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-user-message-hold-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatUserMessageHoldEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub message_id: String,
+    pub message: ChatMessage,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-user-message-update-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatUserMessageUpdateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub status: HeldMessageStatus,
+    pub message_id: String,
+    pub message: ChatMessage,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HeldMessageStatus {
+    Approved,
+    Denied,
+    Invalid,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-notification-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatNotificationEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub chatter_user_id: String,
+    pub chatter_user_login: String,
+    pub chatter_user_name: String,
+    pub chatter_is_anonymous: bool,
+    pub color: String,
+    pub badges: Vec<Badge>,
+    /// The message Twitch shows in chat for this notification.
+    pub system_message: String,
+    pub message_id: String,
+    /// The chatter's own message, if they added one.
+    pub message: ChatMessage,
+    #[serde(default)]
+    pub source_broadcaster_user_id: Option<String>,
+    #[serde(default)]
+    pub source_broadcaster_user_login: Option<String>,
+    #[serde(default)]
+    pub source_broadcaster_user_name: Option<String>,
+    #[serde(default)]
+    pub source_message_id: Option<String>,
+    #[serde(default)]
+    pub source_badges: Option<Vec<Badge>>,
+    /// `notice_type` and the object named after it.
+    #[serde(flatten)]
+    pub notice: Notice,
+}
+
+field_tagged!(
+    /// The kind of notification, from `notice_type`.
+    pub enum Notice: "notice_type" {
+        Sub(Sub) => "sub",
+        Resub(Resub) => "resub",
+        SubGift(SubGift) => "sub_gift",
+        CommunitySubGift(CommunitySubGift) => "community_sub_gift",
+        GiftPaidUpgrade(GiftPaidUpgrade) => "gift_paid_upgrade",
+        PrimePaidUpgrade(PrimePaidUpgrade) => "prime_paid_upgrade",
+        Raid(Raid) => "raid",
+        Unraid(Unraid) => "unraid",
+        PayItForward(PayItForward) => "pay_it_forward",
+        Announcement(Announcement) => "announcement",
+        BitsBadgeTier(BitsBadgeTier) => "bits_badge_tier",
+        CharityDonation(NoticeCharityDonation) => "charity_donation",
+        SharedChatSub(Sub) => "shared_chat_sub",
+        SharedChatResub(Resub) => "shared_chat_resub",
+        SharedChatSubGift(SubGift) => "shared_chat_sub_gift",
+        SharedChatCommunitySubGift(CommunitySubGift) => "shared_chat_community_sub_gift",
+        SharedChatGiftPaidUpgrade(GiftPaidUpgrade) => "shared_chat_gift_paid_upgrade",
+        SharedChatPrimePaidUpgrade(PrimePaidUpgrade) => "shared_chat_prime_paid_upgrade",
+        SharedChatRaid(Raid) => "shared_chat_raid",
+        SharedChatPayItForward(PayItForward) => "shared_chat_pay_it_forward",
+        SharedChatAnnouncement(Announcement) => "shared_chat_announcement",
+    }
+);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Sub {
+    pub sub_tier: Tier,
+    pub is_prime: bool,
+    pub duration_months: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Resub {
+    pub cumulative_months: u64,
+    pub duration_months: u64,
+    /// `None` if the chatter chose not to share their streak.
+    pub streak_months: Option<u64>,
+    pub sub_tier: Tier,
+    pub is_prime: Option<bool>,
+    pub is_gift: bool,
+    pub gifter_is_anonymous: Option<bool>,
+    pub gifter_user_id: Option<String>,
+    pub gifter_user_name: Option<String>,
+    pub gifter_user_login: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubGift {
+    pub duration_months: u64,
+    /// `None` for anonymous gifts or if the gifter chose not to share it.
+    pub cumulative_total: Option<u64>,
+    pub recipient_user_id: String,
+    pub recipient_user_name: String,
+    pub recipient_user_login: String,
+    pub sub_tier: Tier,
+    /// Set when the gift is part of a community gift.
+    pub community_gift_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommunitySubGift {
+    pub id: String,
+    pub total: u64,
+    pub sub_tier: Tier,
+    pub cumulative_total: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GiftPaidUpgrade {
+    pub gifter_is_anonymous: bool,
+    pub gifter_user_id: Option<String>,
+    pub gifter_user_name: Option<String>,
+    pub gifter_user_login: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PrimePaidUpgrade {
+    pub sub_tier: Tier,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Raid {
+    pub user_id: String,
+    pub user_name: String,
+    pub user_login: String,
+    pub viewer_count: u64,
+    pub profile_image_url: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Unraid {}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PayItForward {
+    pub gifter_is_anonymous: bool,
+    pub gifter_user_id: Option<String>,
+    pub gifter_user_name: Option<String>,
+    pub gifter_user_login: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Announcement {
+    /// `BLUE`, `PURPLE`, `ORANGE`, `GREEN` or `PRIMARY`.
+    pub color: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitsBadgeTier {
+    pub tier: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NoticeCharityDonation {
+    pub charity_name: String,
+    pub amount: Amount,
+}
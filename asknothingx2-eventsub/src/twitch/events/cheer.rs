@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use super::MaybeAnonymous;
+use crate::twitch::types::BroadcasterUserId;
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-cheer-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheerEvent {
+    /// The cheerer, `is_anonymous` and the user fields.
+    #[serde(flatten)]
+    pub user: MaybeAnonymous,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub message: String,
+    pub bits: u64,
+}
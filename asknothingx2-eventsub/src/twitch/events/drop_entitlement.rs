@@ -1,6 +1,8 @@
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
+use crate::twitch::types::timestamp::rfc3339_nanos;
+
 /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#drop-entitlement-grant-event>
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DropEntitlementGrantEvent {
@@ -20,5 +22,6 @@ pub struct Data {
     pub user_login: String,
     pub entitlement_id: String,
     pub benefit_id: String,
+    #[serde(with = "rfc3339_nanos")]
     pub created_at: DateTime<FixedOffset>,
 }
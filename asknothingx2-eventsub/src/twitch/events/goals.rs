@@ -1,6 +1,8 @@
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
+use crate::twitch::types::timestamp::rfc3339_nanos;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GoalsEvent {
     #[serde(rename = "type")]
@@ -9,7 +11,9 @@ pub struct GoalsEvent {
     pub is_achieved: bool,
     pub current_amount: u64,
     pub target_amount: u64,
+    #[serde(with = "rfc3339_nanos")]
     pub started_at: DateTime<FixedOffset>,
+    #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
     pub ended_at: Option<DateTime<FixedOffset>>,
 }
 
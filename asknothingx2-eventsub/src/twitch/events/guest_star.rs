@@ -0,0 +1,102 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::twitch::types::{timestamp::rfc3339_nanos, BroadcasterUserId};
+
+/// `channel.guest_star_session.begin` (beta)
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-guest-star-session-begin-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestStarSessionBeginEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub session_id: String,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    /// The user hosting the session, the broadcaster or a moderator.
+    pub host_user_id: String,
+    pub host_user_login: String,
+    pub host_user_name: String,
+}
+
+/// `channel.guest_star_session.end` (beta)
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-guest-star-session-end-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestStarSessionEndEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub session_id: String,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    #[serde(with = "rfc3339_nanos")]
+    pub ended_at: DateTime<FixedOffset>,
+    pub host_user_id: String,
+    pub host_user_login: String,
+    pub host_user_name: String,
+}
+
+/// `channel.guest_star_guest.update` (beta)
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-guest-star-guest-update-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestStarGuestUpdateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub session_id: String,
+    /// `None` if the guest changed the state themselves.
+    pub moderator_user_id: Option<String>,
+    pub moderator_user_login: Option<String>,
+    pub moderator_user_name: Option<String>,
+    /// `None` if the slot is now empty.
+    pub guest_user_id: Option<String>,
+    pub guest_user_login: Option<String>,
+    pub guest_user_name: Option<String>,
+    /// `None` if the guest is not assigned to a slot, `"0"` is the host.
+    pub slot_id: Option<String>,
+    /// `None` if the guest left the session.
+    pub state: Option<GuestState>,
+    pub host_user_id: String,
+    pub host_user_login: String,
+    pub host_user_name: String,
+    /// `None` unless the guest is in a slot.
+    pub host_video_enabled: Option<bool>,
+    pub host_audio_enabled: Option<bool>,
+    /// 0 to 100.
+    pub host_volume: Option<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuestState {
+    Invited,
+    Accepted,
+    Ready,
+    Backstage,
+    Live,
+    Removed,
+}
+
+/// `channel.guest_star_settings.update` (beta)
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-guest-star-settings-update-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestStarSettingsUpdateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub is_moderator_send_live_enabled: bool,
+    pub slot_count: u64,
+    pub is_browser_source_audio_enabled: bool,
+    pub group_layout: GroupLayout,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupLayout {
+    Tiled,
+    Screenshare,
+    HorizontalTop,
+    HorizontalBottom,
+    VerticalLeft,
+    VerticalRight,
+}
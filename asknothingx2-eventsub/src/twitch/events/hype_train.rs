@@ -1,6 +1,7 @@
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
-use crate::twitch::types::objects::Contribution;
+use crate::twitch::types::{objects::Contribution, timestamp::rfc3339_nanos};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HypeEvent {
@@ -10,9 +11,13 @@ pub struct HypeEvent {
     pub top_contributions: Contribution,
     pub last_contribution: Option<Contribution>,
     pub level: u64,
-    pub started_at: String,
-    pub ended_at: Option<String>,
-    pub expires_at: Option<String>,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
+    pub ended_at: Option<DateTime<FixedOffset>>,
+    #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
+    pub expires_at: Option<DateTime<FixedOffset>>,
     pub is_golden_kappa_train: bool,
-    pub cooldown_ends_at: Option<String>,
+    #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
+    pub cooldown_ends_at: Option<DateTime<FixedOffset>>,
 }
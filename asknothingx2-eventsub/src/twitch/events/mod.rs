@@ -3,15 +3,142 @@ use twitch_highway::types::{Id, UserId};
 
 use super::types::BroadcasterUserId;
 
+/// An enum Twitch encodes as a tag field next to one object per variant,
+/// e.g. `"notice_type": "sub"` and `"sub": {...}`. The objects of the other
+/// variants are `null`. A variant reads its object from the field named after
+/// its tag unless given `in "field"`; variants without a type have no object.
+/// A tag this crate doesn't know decodes as `Unknown` instead of failing the
+/// whole event.
+/// Meant to be `#[serde(flatten)]`ed into the event.
+macro_rules! field_tagged {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident: $tag:literal {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident $(($type:ty))? => $value:literal $(in $field:literal)?
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        #[non_exhaustive]
+        pub enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant $(($type))?,
+            )+
+            /// A tag this crate doesn't know yet, with the rest of the object,
+            /// kept as sent so it round-trips.
+            Unknown {
+                kind: String,
+                data: serde_json::Value,
+            },
+        }
+
+        impl $name {
+            /// The value of the tag field.
+            pub fn tag(&self) -> &str {
+                match self {
+                    $(Self::$variant { .. } => $value,)+
+                    Self::Unknown { kind, .. } => kind,
+                }
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeMap;
+
+                let mut map = serializer.serialize_map(None)?;
+                map.serialize_entry($tag, self.tag())?;
+                $(field_tagged!(@serialize self, map, $variant $(($type))?, field_tagged!(@field $value $(, $field)?));)+
+                if let Self::Unknown {
+                    data: serde_json::Value::Object(data),
+                    ..
+                } = self
+                {
+                    for (key, value) in data {
+                        map.serialize_entry(key, value)?;
+                    }
+                }
+                map.end()
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                use serde::de::Error;
+
+                let mut map =
+                    serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+                let tag = match map.remove($tag) {
+                    Some(serde_json::Value::String(tag)) => tag,
+                    _ => return Err(D::Error::missing_field($tag)),
+                };
+
+                match tag.as_str() {
+                    $($value => field_tagged!(
+                        @deserialize map, D, $variant $(($type))?,
+                        field_tagged!(@field $value $(, $field)?)
+                    ),)+
+                    _ => Ok(Self::Unknown {
+                        kind: tag,
+                        data: serde_json::Value::Object(map),
+                    }),
+                }
+            }
+        }
+    };
+    (@field $value:literal) => {
+        $value
+    };
+    (@field $value:literal, $field:literal) => {
+        $field
+    };
+    (@serialize $self:ident, $map:ident, $variant:ident($type:ty), $field:expr) => {
+        if let Self::$variant(value) = $self {
+            $map.serialize_entry($field, value)?;
+        }
+    };
+    (@serialize $self:ident, $map:ident, $variant:ident, $field:expr) => {};
+    (@deserialize $map:ident, $d:ident, $variant:ident($type:ty), $field:expr) => {
+        serde_json::from_value($map.remove($field).unwrap_or(serde_json::Value::Null))
+            .map(Self::$variant)
+            .map_err($d::Error::custom)
+    };
+    (@deserialize $map:ident, $d:ident, $variant:ident, $field:expr) => {
+        Ok(Self::$variant)
+    };
+}
+
+pub mod ad_break;
 pub mod automod;
 pub mod bits_transaction;
 pub mod channel;
+pub mod channel_points;
 pub mod charity;
+pub mod chat;
+pub mod cheer;
 pub mod conduit_shard;
 pub mod drop_entitlement;
 pub mod goals;
+pub mod guest_star;
 pub mod hype_train;
+pub mod moderation;
+pub mod poll;
+pub mod prediction;
+pub mod shared_chat;
+pub mod shoutout;
 pub mod stream;
+pub mod subscribe;
+pub mod suspicious_user;
 pub mod types;
 pub mod user;
 pub mod whisper;
@@ -30,3 +157,79 @@ pub struct UserEvent {
     pub user_login: Option<String>,
     pub user_name: Option<String>,
 }
+
+/// A user who may have chosen to stay anonymous, e.g. the gifter of
+/// `channel.subscription.gift` or the cheerer of `channel.cheer`. Twitch sends
+/// `is_anonymous` and `null` user fields for anonymous users.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "AnonymousFields", into = "AnonymousFields")]
+pub enum MaybeAnonymous {
+    Anonymous,
+    User {
+        user_id: String,
+        user_login: String,
+        user_name: String,
+    },
+}
+
+impl MaybeAnonymous {
+    pub fn is_anonymous(&self) -> bool {
+        matches!(self, Self::Anonymous)
+    }
+
+    pub fn user_id(&self) -> Option<&str> {
+        match self {
+            Self::Anonymous => None,
+            Self::User { user_id, .. } => Some(user_id),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct AnonymousFields {
+    is_anonymous: bool,
+    user_id: Option<String>,
+    user_login: Option<String>,
+    user_name: Option<String>,
+}
+
+impl From<AnonymousFields> for MaybeAnonymous {
+    fn from(fields: AnonymousFields) -> Self {
+        match fields {
+            AnonymousFields {
+                is_anonymous: false,
+                user_id: Some(user_id),
+                user_login,
+                user_name,
+            } => Self::User {
+                user_id,
+                user_login: user_login.unwrap_or_default(),
+                user_name: user_name.unwrap_or_default(),
+            },
+            _ => Self::Anonymous,
+        }
+    }
+}
+
+impl From<MaybeAnonymous> for AnonymousFields {
+    fn from(user: MaybeAnonymous) -> Self {
+        match user {
+            MaybeAnonymous::Anonymous => Self {
+                is_anonymous: true,
+                user_id: None,
+                user_login: None,
+                user_name: None,
+            },
+            MaybeAnonymous::User {
+                user_id,
+                user_login,
+                user_name,
+            } => Self {
+                is_anonymous: false,
+                user_id: Some(user_id),
+                user_login: Some(user_login),
+                user_name: Some(user_name),
+            },
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::twitch::types::{timestamp::rfc3339_nanos, BroadcasterUserId};
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-ban-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelBanEvent {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub reason: String,
+    #[serde(with = "rfc3339_nanos")]
+    pub banned_at: DateTime<FixedOffset>,
+    /// `None` if the ban is permanent.
+    #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
+    pub ends_at: Option<DateTime<FixedOffset>>,
+    pub is_permanent: bool,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-unban-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelUnbanEvent {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-unban-request-create-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnbanRequestCreateEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub text: String,
+    #[serde(with = "rfc3339_nanos")]
+    pub created_at: DateTime<FixedOffset>,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-unban-request-resolve-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnbanRequestResolveEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    /// `None` if the request was canceled by the user.
+    pub moderator_user_id: Option<String>,
+    pub moderator_user_login: Option<String>,
+    pub moderator_user_name: Option<String>,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub resolution_text: Option<String>,
+    pub status: UnbanRequestStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum UnbanRequestStatus {
+    Approved,
+    Canceled,
+    Denied,
+    /// A value this crate doesn't know yet, kept as sent so it round-trips.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// `channel.moderator.add` and `channel.moderator.remove`
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-moderator-add-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModeratorEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+}
+
+/// `channel.vip.add` and `channel.vip.remove`
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-vip-add-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VipEvent {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-warning-acknowledge-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WarningAcknowledgeEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-warning-send-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WarningSendEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub reason: Option<String>,
+    pub chat_rules_cited: Option<Vec<String>>,
+}
+
+/// `channel.moderate` v1 and v2; `warn` and the `shared_chat_*` actions are v2 only.
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-moderate-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelModerateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    /// Set when the action was taken in another channel of a shared chat session.
+    #[serde(default)]
+    pub source_broadcaster_user_id: Option<String>,
+    #[serde(default)]
+    pub source_broadcaster_user_login: Option<String>,
+    #[serde(default)]
+    pub source_broadcaster_user_name: Option<String>,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    /// `action` and its metadata.
+    #[serde(flatten)]
+    pub action: ModerateAction,
+}
+
+field_tagged!(
+    /// The action a moderator took, from `action`.
+    pub enum ModerateAction: "action" {
+        Ban(Ban) => "ban",
+        Timeout(Timeout) => "timeout",
+        Unban(ModeratedUser) => "unban",
+        Untimeout(ModeratedUser) => "untimeout",
+        Clear => "clear",
+        EmoteOnly => "emoteonly",
+        EmoteOnlyOff => "emoteonlyoff",
+        Followers(Followers) => "followers",
+        FollowersOff => "followersoff",
+        UniqueChat => "uniquechat",
+        UniqueChatOff => "uniquechatoff",
+        Slow(Slow) => "slow",
+        SlowOff => "slowoff",
+        Subscribers => "subscribers",
+        SubscribersOff => "subscribersoff",
+        Unraid(ModeratedUser) => "unraid",
+        Delete(Delete) => "delete",
+        Unvip(ModeratedUser) => "unvip",
+        Vip(ModeratedUser) => "vip",
+        Raid(Raid) => "raid",
+        AddBlockedTerm(AutomodTerms) => "add_blocked_term" in "automod_terms",
+        AddPermittedTerm(AutomodTerms) => "add_permitted_term" in "automod_terms",
+        RemoveBlockedTerm(AutomodTerms) => "remove_blocked_term" in "automod_terms",
+        RemovePermittedTerm(AutomodTerms) => "remove_permitted_term" in "automod_terms",
+        Mod(ModeratedUser) => "mod",
+        Unmod(ModeratedUser) => "unmod",
+        ApproveUnbanRequest(UnbanRequest) => "approve_unban_request" in "unban_request",
+        DenyUnbanRequest(UnbanRequest) => "deny_unban_request" in "unban_request",
+        Warn(Warn) => "warn",
+        SharedChatBan(Ban) => "shared_chat_ban",
+        SharedChatTimeout(Timeout) => "shared_chat_timeout",
+        SharedChatUnban(ModeratedUser) => "shared_chat_unban",
+        SharedChatUntimeout(ModeratedUser) => "shared_chat_untimeout",
+        SharedChatDelete(Delete) => "shared_chat_delete",
+    }
+);
+
+/// The user an action was taken against.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModeratedUser {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Ban {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Timeout {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub reason: Option<String>,
+    #[serde(with = "rfc3339_nanos")]
+    pub expires_at: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Followers {
+    pub follow_duration_minutes: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Slow {
+    pub wait_time_seconds: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Delete {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub message_id: String,
+    pub message_body: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Raid {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub viewer_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomodTerms {
+    pub action: AutomodTermsAction,
+    pub list: AutomodTermsList,
+    pub terms: Vec<String>,
+    /// Whether the terms were added by AutoMod rather than a moderator.
+    pub from_automod: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AutomodTermsAction {
+    Add,
+    Remove,
+    /// A value this crate doesn't know yet, kept as sent so it round-trips.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AutomodTermsList {
+    Blocked,
+    Permitted,
+    /// A value this crate doesn't know yet, kept as sent so it round-trips.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnbanRequest {
+    pub is_approved: bool,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub moderator_message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Warn {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub reason: Option<String>,
+    pub chat_rules_cited: Option<Vec<String>>,
+}
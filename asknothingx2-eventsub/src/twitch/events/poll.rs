@@ -0,0 +1,52 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::twitch::types::{
+    objects::{BitsVoting, ChannelPointsVoting, Choices},
+    timestamp::rfc3339_nanos,
+    BroadcasterUserId,
+};
+
+/// `channel.poll.begin` and `channel.poll.progress`
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-poll-begin-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PollEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub choices: Vec<Choices>,
+    pub bits_voting: BitsVoting,
+    pub channel_points_voting: ChannelPointsVoting,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    #[serde(with = "rfc3339_nanos")]
+    pub ends_at: DateTime<FixedOffset>,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-poll-end-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PollEndEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub choices: Vec<Choices>,
+    pub bits_voting: BitsVoting,
+    pub channel_points_voting: ChannelPointsVoting,
+    pub status: PollStatus,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    #[serde(with = "rfc3339_nanos")]
+    pub ended_at: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PollStatus {
+    Completed,
+    Archived,
+    Terminated,
+}
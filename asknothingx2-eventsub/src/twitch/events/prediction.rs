@@ -0,0 +1,60 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::twitch::types::{objects::Outcomes, timestamp::rfc3339_nanos, BroadcasterUserId};
+
+/// `channel.prediction.begin` and `channel.prediction.progress`
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-prediction-begin-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PredictionEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub outcomes: Vec<Outcomes>,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    #[serde(with = "rfc3339_nanos")]
+    pub locks_at: DateTime<FixedOffset>,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-prediction-lock-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PredictionLockEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub outcomes: Vec<Outcomes>,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    #[serde(with = "rfc3339_nanos")]
+    pub locked_at: DateTime<FixedOffset>,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-prediction-end-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PredictionEndEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    /// `None` if the prediction was canceled.
+    pub winning_outcome_id: Option<String>,
+    pub outcomes: Vec<Outcomes>,
+    pub status: PredictionStatus,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    #[serde(with = "rfc3339_nanos")]
+    pub ended_at: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PredictionStatus {
+    Resolved,
+    Canceled,
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::twitch::types::BroadcasterUserId;
+
+/// `channel.shared_chat.begin` and `channel.shared_chat.update`
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-shared-chat-begin-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SharedChatSessionEvent {
+    pub session_id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub host_broadcaster_user_id: String,
+    pub host_broadcaster_user_login: String,
+    pub host_broadcaster_user_name: String,
+    /// Every channel in the session, including the host.
+    pub participants: Vec<SharedChatParticipant>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SharedChatParticipant {
+    pub broadcaster_user_id: String,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-shared-chat-end-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SharedChatSessionEndEvent {
+    pub session_id: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub host_broadcaster_user_id: String,
+    pub host_broadcaster_user_login: String,
+    pub host_broadcaster_user_name: String,
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::twitch::types::{timestamp::rfc3339_nanos, BroadcasterUserId};
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-shoutout-create-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShoutoutCreateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub to_broadcaster_user_id: BroadcasterUserId,
+    pub to_broadcaster_user_login: String,
+    pub to_broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub viewer_count: u64,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+    #[serde(with = "rfc3339_nanos")]
+    pub cooldown_ends_at: DateTime<FixedOffset>,
+    #[serde(with = "rfc3339_nanos")]
+    pub target_cooldown_ends_at: DateTime<FixedOffset>,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-shoutout-receive-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShoutoutReceivedEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub from_broadcaster_user_id: BroadcasterUserId,
+    pub from_broadcaster_user_login: String,
+    pub from_broadcaster_user_name: String,
+    pub viewer_count: u64,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
+}
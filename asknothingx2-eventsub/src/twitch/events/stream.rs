@@ -1,10 +1,14 @@
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
+use crate::twitch::types::timestamp::rfc3339_nanos;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StreamOnlineEvent {
     #[serde(rename = "type")]
     pub kind: StreamType,
-    pub started_at: String,
+    #[serde(with = "rfc3339_nanos")]
+    pub started_at: DateTime<FixedOffset>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use super::MaybeAnonymous;
+use crate::twitch::types::{
+    objects::{Message, Tier},
+    BroadcasterUserId,
+};
+
+/// `channel.subscribe` and `channel.subscription.end`
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-subscribe-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubscribeEvent {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub tier: Tier,
+    pub is_gift: bool,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-subscription-gift-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubscriptionGiftEvent {
+    /// The gifter, `is_anonymous` and the user fields.
+    #[serde(flatten)]
+    pub gifter: MaybeAnonymous,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub total: u64,
+    pub tier: Tier,
+    /// `None` if the gifter is anonymous or chose not to share it.
+    pub cumulative_total: Option<u64>,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-subscription-message-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubscriptionMessageEvent {
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub tier: Tier,
+    pub message: Message,
+    pub cumulative_months: u64,
+    /// `None` if the user chose not to share their streak.
+    pub streak_months: Option<u64>,
+    pub duration_months: u64,
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use super::chat::Fragment;
+use crate::twitch::types::BroadcasterUserId;
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-suspicious-user-message-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SuspiciousUserMessageEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub low_trust_status: LowTrustStatus,
+    /// Channels that also banned the user.
+    pub shared_ban_channel_ids: Vec<String>,
+    /// Why the user is considered suspicious.
+    pub types: Vec<SuspiciousUserType>,
+    pub ban_evasion_evaluation: BanEvasionEvaluation,
+    pub message: SuspiciousUserMessage,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SuspiciousUserMessage {
+    pub message_id: String,
+    pub text: String,
+    pub fragments: Vec<Fragment>,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-suspicious-user-update-event>
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SuspiciousUserUpdateEvent {
+    pub broadcaster_user_id: BroadcasterUserId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    pub user_id: String,
+    pub user_login: String,
+    pub user_name: String,
+    pub low_trust_status: LowTrustStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LowTrustStatus {
+    None,
+    /// Messages are shown to moderators with a warning.
+    ActiveMonitoring,
+    /// Messages are held for moderators.
+    Restricted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuspiciousUserType {
+    /// Added by a moderator.
+    Manual,
+    BanEvaderDetector,
+    /// Banned in a channel from `shared_ban_channel_ids`.
+    SharedChannelBan,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BanEvasionEvaluation {
+    Unknown,
+    Possible,
+    Likely,
+}
@@ -0,0 +1,196 @@
+//! <https://dev.twitch.tv/docs/eventsub/manage-subscriptions/#subscription-limits>
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use crate::twitch::{
+    request::CreateEventSubRequest,
+    response::EventSubscriptionsResponse,
+    types::{Condition, SubscriptionType},
+};
+
+/// Why a subscription costs what it does.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CostReason {
+    /// The type can only be created once a user authorized the app, e.g.
+    /// `channel.follow`, so the subscription is free.
+    RequiresAuthorization,
+    /// The condition names no user, e.g. `conduit.shard.disabled`, so the
+    /// subscription is free.
+    AppLevel,
+    /// The user in the condition has authorized the app, so the subscription
+    /// is free.
+    Authorized(String),
+    /// No user in the condition has authorized the app, e.g. `stream.online`
+    /// for someone else's channel.
+    NotAuthorized,
+}
+
+impl CostReason {
+    pub fn cost(&self) -> u64 {
+        match self {
+            Self::RequiresAuthorization | Self::AppLevel | Self::Authorized(_) => 0,
+            Self::NotAuthorized => 1,
+        }
+    }
+}
+
+impl fmt::Display for CostReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RequiresAuthorization => {
+                write!(f, "costs 0: the type requires user authorization")
+            }
+            Self::AppLevel => write!(f, "costs 0: the condition names no user"),
+            Self::Authorized(user_id) => write!(f, "costs 0: user {user_id} authorized the app"),
+            Self::NotAuthorized => {
+                write!(f, "costs 1: no user in the condition authorized the app")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CostEstimate {
+    pub kind: SubscriptionType,
+    pub cost: u64,
+    pub reason: CostReason,
+}
+
+/// Whether a batch of subscriptions fits in the remaining budget.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Forecast {
+    /// One estimate per request, in order.
+    pub estimates: Vec<CostEstimate>,
+    /// Cost the batch adds.
+    pub cost: u64,
+    pub total_cost: u64,
+    pub max_total_cost: Option<u64>,
+}
+
+impl Forecast {
+    /// `true` if creating the batch stays within `max_total_cost`, or the
+    /// limit is not known yet.
+    pub fn fits(&self) -> bool {
+        self.over_by() == 0
+    }
+
+    /// Cost above `max_total_cost` the batch would need.
+    pub fn over_by(&self) -> u64 {
+        self.max_total_cost.map_or(0, |max_total_cost| {
+            (self.total_cost + self.cost).saturating_sub(max_total_cost)
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    total_cost: u64,
+    max_total_cost: Option<u64>,
+    authorized: HashSet<String>,
+}
+
+/// Tracks `total_cost` and `max_total_cost` across requests and predicts the
+/// cost of new subscriptions.
+///
+/// A subscription is free when its type requires user authorization, when its
+/// condition names no user, or when a user in its condition has authorized the
+/// app; otherwise it costs 1. Twitch doesn't list who authorized the app, so
+/// those users have to be registered with [`CostBudget::authorize`].
+///
+/// Clones share the same budget, so one can be handed to
+/// [`Subscriptions`](super::Subscriptions) and [`Reconciler`](super::Reconciler)
+/// to keep it updated from their responses.
+#[derive(Clone, Debug, Default)]
+pub struct CostBudget {
+    state: Arc<Mutex<State>>,
+}
+
+impl CostBudget {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks `user_id` as having authorized the app.
+    pub fn authorize(&self, user_id: impl Into<String>) {
+        self.state.lock().unwrap().authorized.insert(user_id.into());
+    }
+
+    /// Updates the cost from a Create or Get EventSub Subscriptions response.
+    pub fn record(&self, response: &EventSubscriptionsResponse) {
+        let mut state = self.state.lock().unwrap();
+        state.total_cost = response.total_cost;
+        state.max_total_cost = Some(response.max_total_cost);
+    }
+
+    /// Frees the cost of a deleted subscription.
+    pub fn release(&self, cost: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.total_cost = state.total_cost.saturating_sub(cost);
+    }
+
+    pub fn total_cost(&self) -> u64 {
+        self.state.lock().unwrap().total_cost
+    }
+
+    /// `None` until a response was recorded.
+    pub fn max_total_cost(&self) -> Option<u64> {
+        self.state.lock().unwrap().max_total_cost
+    }
+
+    /// `None` until a response was recorded.
+    pub fn remaining(&self) -> Option<u64> {
+        let state = self.state.lock().unwrap();
+        state
+            .max_total_cost
+            .map(|max_total_cost| max_total_cost.saturating_sub(state.total_cost))
+    }
+
+    pub fn estimate(&self, request: &CreateEventSubRequest<Condition>) -> CostEstimate {
+        let state = self.state.lock().unwrap();
+        estimate(&state.authorized, request)
+    }
+
+    /// Predicts whether creating every request in `batch` fits in the budget.
+    pub fn forecast(&self, batch: &[CreateEventSubRequest<Condition>]) -> Forecast {
+        let state = self.state.lock().unwrap();
+        let estimates: Vec<_> = batch
+            .iter()
+            .map(|request| estimate(&state.authorized, request))
+            .collect();
+
+        Forecast {
+            cost: estimates.iter().map(|estimate| estimate.cost).sum(),
+            estimates,
+            total_cost: state.total_cost,
+            max_total_cost: state.max_total_cost,
+        }
+    }
+}
+
+fn estimate(
+    authorized: &HashSet<String>,
+    request: &CreateEventSubRequest<Condition>,
+) -> CostEstimate {
+    let users = request.condition.user_ids();
+    let reason = if request.kind.requires_user_authorization() {
+        CostReason::RequiresAuthorization
+    } else if users.is_empty() {
+        CostReason::AppLevel
+    } else {
+        users
+            .into_iter()
+            .find(|user_id| authorized.contains(*user_id))
+            .map_or(CostReason::NotAuthorized, |user_id| {
+                CostReason::Authorized(user_id.to_string())
+            })
+    };
+
+    CostEstimate {
+        kind: request.kind.clone(),
+        cost: reason.cost(),
+        reason,
+    }
+}
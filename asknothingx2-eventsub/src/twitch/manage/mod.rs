@@ -0,0 +1,9 @@
+//! Managing the subscriptions of an application through the Helix API.
+
+mod budget;
+mod reconcile;
+mod subscriptions;
+
+pub use budget::{CostBudget, CostEstimate, CostReason, Forecast};
+pub use reconcile::{Change, ChangeFailure, Plan, Reconciled, Reconciler, Stale, StaleReason};
+pub use subscriptions::Subscriptions;
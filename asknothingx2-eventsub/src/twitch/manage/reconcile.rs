@@ -0,0 +1,270 @@
+use std::{collections::HashMap, fmt};
+
+use futures_util::TryStreamExt;
+use serde_json::Value;
+
+use crate::twitch::{
+    error::RequestError,
+    fetch,
+    request::{CreateEventSubRequest, GetEventRequest},
+    send,
+    types::{Condition, Status, Subscription, SubscriptionId, Transport},
+    EventSubAPI,
+};
+
+use super::{CostBudget, Subscriptions};
+
+/// Why an existing subscription is deleted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StaleReason {
+    /// No desired subscription has its type, version, condition and transport.
+    NotDesired,
+    /// Another existing subscription already satisfies the same desired one.
+    Duplicate,
+    /// Twitch stopped sending events, e.g. [`Status::AuthorizationRevoked`] or
+    /// [`Status::NotificationFailuresExceeded`]. Recreated when still desired.
+    Failed(Status),
+}
+
+#[derive(Clone, Debug)]
+pub struct Stale {
+    pub subscription: Subscription,
+    pub reason: StaleReason,
+}
+
+/// Changes that bring the existing subscriptions to the desired set.
+#[derive(Clone, Debug, Default)]
+pub struct Plan {
+    pub create: Vec<CreateEventSubRequest<Condition>>,
+    pub delete: Vec<Stale>,
+    /// Existing subscriptions that already match a desired one.
+    pub keep: Vec<Subscription>,
+}
+
+impl Plan {
+    /// `true` when the existing subscriptions already match the desired set.
+    pub fn is_empty(&self) -> bool {
+        self.create.is_empty() && self.delete.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub enum Change {
+    Create(CreateEventSubRequest<Condition>),
+    Delete(Stale),
+}
+
+/// A change that Twitch rejected; the remaining changes are still applied.
+#[derive(Debug)]
+pub struct ChangeFailure {
+    pub change: Change,
+    pub error: RequestError,
+}
+
+/// Result of [`Reconciler::apply`].
+#[derive(Debug, Default)]
+pub struct Reconciled {
+    pub created: Vec<Subscription>,
+    pub deleted: Vec<SubscriptionId>,
+    pub kept: Vec<Subscription>,
+    pub failures: Vec<ChangeFailure>,
+}
+
+/// Syncs the subscriptions on Twitch with a desired set.
+///
+/// Subscriptions are matched by type, version, condition and transport. The
+/// webhook secret is not returned by Twitch and is ignored when matching.
+///
+/// <https://dev.twitch.tv/docs/eventsub/manage-subscriptions/>
+pub struct Reconciler<'a, A: ?Sized> {
+    api: &'a A,
+    desired: Vec<CreateEventSubRequest<Condition>>,
+    filter: Option<GetEventRequest>,
+    budget: Option<CostBudget>,
+}
+
+impl<'a, A: EventSubAPI + ?Sized> Reconciler<'a, A> {
+    pub fn new(api: &'a A, desired: Vec<CreateEventSubRequest<Condition>>) -> Self {
+        Self {
+            api,
+            desired,
+            filter: None,
+            budget: None,
+        }
+    }
+
+    /// Reconciles only the subscriptions that `filter` lists; others are left alone.
+    pub fn filter(mut self, filter: GetEventRequest) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Records the cost of every listed, created and deleted subscription in
+    /// `budget`.
+    pub fn budget(mut self, budget: CostBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Lists the existing subscriptions and computes the changes without
+    /// applying them.
+    pub async fn plan(&self) -> Result<Plan, RequestError> {
+        let mut subscriptions = Subscriptions::new(self.api, self.filter.clone());
+        if let Some(budget) = &self.budget {
+            subscriptions = subscriptions.budget(budget.clone());
+        }
+        let existing: Vec<Subscription> = subscriptions.try_collect().await?;
+
+        Ok(diff(&self.desired, existing))
+    }
+
+    /// Computes a [`Plan`] and applies it, deleting before creating so that
+    /// deleted subscriptions no longer count against the cost.
+    pub async fn apply(&self) -> Result<Reconciled, RequestError> {
+        let plan = self.plan().await?;
+        Ok(self.execute(plan).await)
+    }
+
+    /// Applies a previously computed `plan`.
+    pub async fn execute(&self, plan: Plan) -> Reconciled {
+        let mut reconciled = Reconciled {
+            kept: plan.keep,
+            ..Default::default()
+        };
+
+        for stale in plan.delete {
+            let id = stale.subscription.id.clone();
+            match send(self.api.delete_eventsub(id.clone())).await {
+                Ok(_) => {
+                    if let Some(budget) = &self.budget {
+                        budget.release(stale.subscription.cost);
+                    }
+                    reconciled.deleted.push(id);
+                }
+                Err(error) => reconciled.failures.push(ChangeFailure {
+                    change: Change::Delete(stale),
+                    error,
+                }),
+            }
+        }
+
+        for request in plan.create {
+            match fetch(self.api.create_eventsub(request.clone())).await {
+                Ok(response) => {
+                    if let Some(budget) = &self.budget {
+                        budget.record(&response);
+                    }
+                    reconciled.created.extend(response.data);
+                }
+                Err(error) => reconciled.failures.push(ChangeFailure {
+                    change: Change::Create(request),
+                    error,
+                }),
+            }
+        }
+
+        for failure in &reconciled.failures {
+            tracing::warn!("failed to reconcile subscription: {}", failure.error);
+        }
+        reconciled
+    }
+}
+
+impl<A: ?Sized> fmt::Debug for Reconciler<'_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reconciler")
+            .field("desired", &self.desired)
+            .field("filter", &self.filter)
+            .field("budget", &self.budget)
+            .finish_non_exhaustive()
+    }
+}
+
+fn diff(desired: &[CreateEventSubRequest<Condition>], existing: Vec<Subscription>) -> Plan {
+    let mut wanted: HashMap<Value, Option<&CreateEventSubRequest<Condition>>> = HashMap::new();
+    for request in desired {
+        wanted
+            .entry(key(
+                request.kind.as_str(),
+                &request.version,
+                &request.condition,
+                &request.transport,
+            ))
+            .or_insert(Some(request));
+    }
+
+    let mut plan = Plan::default();
+    for subscription in existing {
+        let key = key(
+            subscription.kind.as_str(),
+            &subscription.version,
+            &subscription.condition,
+            &subscription.transport,
+        );
+
+        let reason = if !is_active(&subscription.status) {
+            StaleReason::Failed(subscription.status.clone())
+        } else {
+            match wanted.get_mut(&key).map(Option::take) {
+                None => StaleReason::NotDesired,
+                Some(Some(_)) => {
+                    plan.keep.push(subscription);
+                    continue;
+                }
+                Some(None) => StaleReason::Duplicate,
+            }
+        };
+        plan.delete.push(Stale {
+            subscription,
+            reason,
+        });
+    }
+
+    // Keep the desired order for requests that are still missing.
+    plan.create = desired
+        .iter()
+        .filter(|request| {
+            let key = key(
+                request.kind.as_str(),
+                &request.version,
+                &request.condition,
+                &request.transport,
+            );
+            wanted.get_mut(&key).and_then(Option::take).is_some()
+        })
+        .cloned()
+        .collect();
+    plan
+}
+
+/// Enabled subscriptions and webhooks still waiting for the challenge.
+fn is_active(status: &Status) -> bool {
+    matches!(
+        status,
+        Status::Enabled | Status::WebhookCallbackVerificationPending
+    )
+}
+
+fn key(kind: &str, version: &str, condition: &Condition, transport: &Transport) -> Value {
+    let mut transport = compact(serde_json::to_value(transport).unwrap_or_default());
+    if let Some(transport) = transport.as_object_mut() {
+        transport.remove("secret");
+        transport.remove("connected_at");
+        transport.remove("disconnected_at");
+    }
+
+    serde_json::json!({
+        "type": kind,
+        "version": version,
+        "condition": compact(serde_json::to_value(condition).unwrap_or_default()),
+        "transport": transport,
+    })
+}
+
+/// Drops `""` and `null` fields; Twitch sends the fields a subscription doesn't use as `""`.
+fn compact(mut value: Value) -> Value {
+    if let Some(object) = value.as_object_mut() {
+        object.retain(|_, field| !field.is_null() && field.as_str() != Some(""));
+    }
+    value
+}
@@ -0,0 +1,147 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::{future::BoxFuture, ready, FutureExt, Stream};
+use twitch_highway::types::PaginationQuery;
+
+use crate::twitch::{
+    error::RequestError, fetch, request::GetEventRequest, response::EventSubscriptionsResponse,
+    types::Subscription, EventSubAPI,
+};
+
+use super::CostBudget;
+
+type Page = BoxFuture<'static, Result<EventSubscriptionsResponse, RequestError>>;
+
+/// Every subscription matching a [`GetEventRequest`], fetched page by page.
+///
+/// The next page is requested with the previous `pagination.cursor` once the
+/// buffered subscriptions are consumed. The stream ends after the last page or
+/// the first error.
+///
+/// <https://dev.twitch.tv/docs/api/reference/#get-eventsub-subscriptions>
+pub struct Subscriptions<'a, A: ?Sized> {
+    api: &'a A,
+    opts: Option<GetEventRequest>,
+    first: Option<u64>,
+    cursor: Option<String>,
+    buffer: VecDeque<Subscription>,
+    pending: Option<Page>,
+    total_cost: Option<u64>,
+    max_total_cost: Option<u64>,
+    budget: Option<CostBudget>,
+    done: bool,
+}
+
+impl<'a, A: EventSubAPI + ?Sized> Subscriptions<'a, A> {
+    /// `opts` filters by status, type or user id; Twitch accepts only one of them.
+    pub fn new(api: &'a A, opts: Option<GetEventRequest>) -> Self {
+        Self {
+            api,
+            opts,
+            first: None,
+            cursor: None,
+            buffer: VecDeque::new(),
+            pending: None,
+            total_cost: None,
+            max_total_cost: None,
+            budget: None,
+            done: false,
+        }
+    }
+
+    /// Maximum number of subscriptions per page (1-100).
+    pub fn first(mut self, first: u64) -> Self {
+        self.first = Some(first);
+        self
+    }
+
+    /// Records the cost of every page in `budget`.
+    pub fn budget(mut self, budget: CostBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// `total_cost` of the first page; `None` until it has been received.
+    pub fn total_cost(&self) -> Option<u64> {
+        self.total_cost
+    }
+
+    /// `max_total_cost` of the first page; `None` until it has been received.
+    pub fn max_total_cost(&self) -> Option<u64> {
+        self.max_total_cost
+    }
+
+    fn request_page(&self) -> Page {
+        let mut pagination = PaginationQuery::new();
+        if let Some(first) = self.first {
+            pagination = pagination.first(first);
+        }
+        if let Some(cursor) = &self.cursor {
+            pagination = pagination.after(cursor);
+        }
+
+        fetch(self.api.get_eventsub(self.opts.clone(), Some(pagination))).boxed()
+    }
+}
+
+impl<A: EventSubAPI + ?Sized> Stream for Subscriptions<'_, A> {
+    type Item = Result<Subscription, RequestError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(subscription) = this.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(subscription)));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            let pending = match &mut this.pending {
+                Some(pending) => pending,
+                None => this.pending.insert(this.request_page()),
+            };
+            let result = ready!(pending.as_mut().poll(cx));
+            this.pending = None;
+
+            match result {
+                Ok(page) => {
+                    if let Some(budget) = &this.budget {
+                        budget.record(&page);
+                    }
+                    if this.total_cost.is_none() {
+                        this.total_cost = Some(page.total_cost);
+                        this.max_total_cost = Some(page.max_total_cost);
+                    }
+                    this.cursor = page.pagination.map(|p| p.cursor);
+                    // An empty page with a cursor would otherwise never end.
+                    this.done = this.cursor.is_none() || page.data.is_empty();
+                    this.buffer.extend(page.data);
+                }
+                Err(e) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+impl<A: ?Sized> fmt::Debug for Subscriptions<'_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscriptions")
+            .field("opts", &self.opts)
+            .field("cursor", &self.cursor)
+            .field("buffered", &self.buffer.len())
+            .field("total_cost", &self.total_cost)
+            .field("max_total_cost", &self.max_total_cost)
+            .field("budget", &self.budget)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,13 +1,27 @@
 use asknothingx2_util::api::Method;
+#[cfg(any(feature = "twitch-router", feature = "twitch-manage"))]
+use error::RequestError;
+#[cfg(feature = "twitch-conduit")]
+use request::{
+    CreateConduitRequest, GetConduitShardsRequest, UpdateConduitRequest, UpdateConduitShardsRequest,
+};
 use request::{CreateEventSubRequest, GetEventRequest};
 use response::EventSubscriptionsResponse;
+#[cfg(feature = "twitch-conduit")]
+use response::{ConduitShardsResponse, ConduitsResponse, UpdateConduitShardsResponse};
+#[cfg(any(feature = "twitch-router", feature = "twitch-manage"))]
+use serde::de::DeserializeOwned;
+#[cfg(any(feature = "twitch-router", feature = "twitch-manage"))]
+use twitch_highway::request::IntoRequestBody;
 use twitch_highway::{
     base::TwitchAPIBase,
     request::{EmptyBody, EndpointType, TwitchAPIRequest},
     types::PaginationQuery,
     TwitchAPI,
 };
-use types::{Condition, SubscriptionId};
+#[cfg(feature = "twitch-conduit")]
+use types::ConduitId;
+use types::SubscriptionId;
 
 pub mod error;
 pub mod events;
@@ -19,6 +33,17 @@ pub mod types;
 #[cfg(feature = "twitch-websocket")]
 pub mod websocket_message;
 
+#[cfg(feature = "twitch-webhook")]
+pub mod webhook;
+
+#[cfg(feature = "twitch-router")]
+pub mod router;
+
+#[cfg(feature = "twitch-manage")]
+pub mod manage;
+
+pub use types::{Condition, Transport, TransportMethod};
+
 pub trait EventSubAPI: TwitchAPIBase {
     fn create_eventsub(
         &self,
@@ -33,6 +58,38 @@ pub trait EventSubAPI: TwitchAPIBase {
         opts: Option<GetEventRequest>,
         pagination: Option<PaginationQuery>,
     ) -> TwitchAPIRequest<EmptyBody, EventSubscriptionsResponse>;
+    /// <https://dev.twitch.tv/docs/api/reference/#get-conduits>
+    #[cfg(feature = "twitch-conduit")]
+    fn get_conduits(&self) -> TwitchAPIRequest<EmptyBody, ConduitsResponse>;
+    /// <https://dev.twitch.tv/docs/api/reference/#create-conduits>
+    #[cfg(feature = "twitch-conduit")]
+    fn create_conduit(
+        &self,
+        shard_count: u64,
+    ) -> TwitchAPIRequest<CreateConduitRequest, ConduitsResponse>;
+    /// <https://dev.twitch.tv/docs/api/reference/#update-conduits>
+    #[cfg(feature = "twitch-conduit")]
+    fn update_conduit(
+        &self,
+        conduit_id: ConduitId,
+        shard_count: u64,
+    ) -> TwitchAPIRequest<UpdateConduitRequest, ConduitsResponse>;
+    /// <https://dev.twitch.tv/docs/api/reference/#delete-conduit>
+    #[cfg(feature = "twitch-conduit")]
+    fn delete_conduit(&self, conduit_id: ConduitId) -> TwitchAPIRequest<EmptyBody, EmptyBody>;
+    /// <https://dev.twitch.tv/docs/api/reference/#get-conduit-shards>
+    #[cfg(feature = "twitch-conduit")]
+    fn get_conduit_shards(
+        &self,
+        request: GetConduitShardsRequest,
+        pagination: Option<PaginationQuery>,
+    ) -> TwitchAPIRequest<EmptyBody, ConduitShardsResponse>;
+    /// <https://dev.twitch.tv/docs/api/reference/#update-conduit-shards>
+    #[cfg(feature = "twitch-conduit")]
+    fn update_conduit_shards(
+        &self,
+        request: UpdateConduitShardsRequest,
+    ) -> TwitchAPIRequest<UpdateConduitShardsRequest, UpdateConduitShardsResponse>;
 }
 
 impl EventSubAPI for TwitchAPI {
@@ -88,4 +145,147 @@ impl EventSubAPI for TwitchAPI {
             EmptyBody,
         )
     }
+    #[cfg(feature = "twitch-conduit")]
+    fn get_conduits(&self) -> TwitchAPIRequest<EmptyBody, ConduitsResponse> {
+        let mut url = self.build_url();
+        url.path(["eventsub", "conduits"]);
+
+        // twitch_highway has no endpoint type for Get Conduits; the conduit
+        // endpoints share the same app access token requirement.
+        TwitchAPIRequest::new(
+            EndpointType::UpdateConduits,
+            url.build(),
+            Method::GET,
+            self.build_headers().build(),
+            EmptyBody,
+        )
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn create_conduit(
+        &self,
+        shard_count: u64,
+    ) -> TwitchAPIRequest<CreateConduitRequest, ConduitsResponse> {
+        let mut url = self.build_url();
+        url.path(["eventsub", "conduits"]);
+
+        let mut headers = self.build_headers();
+        headers.json();
+
+        TwitchAPIRequest::new(
+            EndpointType::CreateConduits,
+            url.build(),
+            Method::POST,
+            headers.build(),
+            CreateConduitRequest { shard_count },
+        )
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn update_conduit(
+        &self,
+        conduit_id: ConduitId,
+        shard_count: u64,
+    ) -> TwitchAPIRequest<UpdateConduitRequest, ConduitsResponse> {
+        let mut url = self.build_url();
+        url.path(["eventsub", "conduits"]);
+
+        let mut headers = self.build_headers();
+        headers.json();
+
+        TwitchAPIRequest::new(
+            EndpointType::UpdateConduits,
+            url.build(),
+            Method::PATCH,
+            headers.build(),
+            UpdateConduitRequest {
+                id: conduit_id,
+                shard_count,
+            },
+        )
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn delete_conduit(&self, conduit_id: ConduitId) -> TwitchAPIRequest<EmptyBody, EmptyBody> {
+        let mut url = self.build_url();
+        url.path(["eventsub", "conduits"]).query("id", conduit_id);
+
+        TwitchAPIRequest::new(
+            EndpointType::DeleteConduit,
+            url.build(),
+            Method::DELETE,
+            self.build_headers().build(),
+            EmptyBody,
+        )
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn get_conduit_shards(
+        &self,
+        request: GetConduitShardsRequest,
+        pagination: Option<PaginationQuery>,
+    ) -> TwitchAPIRequest<EmptyBody, ConduitShardsResponse> {
+        let mut url = self.build_url();
+        url.path(["eventsub", "conduits", "shards"])
+            .query_pairs(request)
+            .query_opt_pairs(pagination);
+
+        TwitchAPIRequest::new(
+            EndpointType::GetConduitShards,
+            url.build(),
+            Method::GET,
+            self.build_headers().build(),
+            EmptyBody,
+        )
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn update_conduit_shards(
+        &self,
+        request: UpdateConduitShardsRequest,
+    ) -> TwitchAPIRequest<UpdateConduitShardsRequest, UpdateConduitShardsResponse> {
+        let mut url = self.build_url();
+        url.path(["eventsub", "conduits", "shards"]);
+
+        let mut headers = self.build_headers();
+        headers.json();
+
+        TwitchAPIRequest::new(
+            EndpointType::UpdateConduitShards,
+            url.build(),
+            Method::PATCH,
+            headers.build(),
+            request,
+        )
+    }
+}
+
+/// Sends `request`, turning non-2xx responses into [`RequestError::Api`]
+/// and a body that can't be decoded into [`RequestError::Decode`].
+#[cfg(any(feature = "twitch-router", feature = "twitch-manage"))]
+pub(crate) async fn fetch<B, D>(request: TwitchAPIRequest<B, D>) -> Result<D, RequestError>
+where
+    B: IntoRequestBody,
+    D: DeserializeOwned,
+{
+    let body = send(request).await?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+/// Sends `request` and returns its body, turning non-2xx responses into
+/// [`RequestError::Api`].
+#[cfg(any(feature = "twitch-router", feature = "twitch-manage"))]
+pub(crate) async fn send<B, D>(request: TwitchAPIRequest<B, D>) -> Result<String, RequestError>
+where
+    B: IntoRequestBody,
+    D: DeserializeOwned,
+{
+    let response = request.request().await?;
+    if response.is_success() {
+        return Ok(response.text());
+    }
+
+    let body: serde_json::Value = serde_json::from_str(&response.text()).unwrap_or_default();
+    Err(RequestError::Api {
+        status: response.status(),
+        message: body["message"]
+            .as_str()
+            .map(str::to_string)
+            .unwrap_or_else(|| response.text()),
+    })
 }
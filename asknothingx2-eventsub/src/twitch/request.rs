@@ -5,9 +5,11 @@ use twitch_highway::{
     types::UserId,
 };
 
-use super::types::{Status, SubscriptionType, Transport};
+#[cfg(feature = "twitch-conduit")]
+use super::types::{ConduitId, ShardId};
+use super::types::{Status, SubscriptionCondition, SubscriptionType, Transport};
 
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct GetEventRequest {
     pub status: Option<Status>,
     #[serde(rename = "type")]
@@ -27,7 +29,7 @@ impl IntoQueryPairs for GetEventRequest {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct CreateEventSubRequest<Condition> {
     #[serde(rename = "type")]
     pub kind: SubscriptionType,
@@ -56,7 +58,7 @@ where
     }
 }
 
-impl<'de, Condition: serde::Deserialize<'de>> serde::Deserialize<'de>
+impl<'de, Condition: SubscriptionCondition> serde::Deserialize<'de>
     for CreateEventSubRequest<Condition>
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -64,11 +66,11 @@ impl<'de, Condition: serde::Deserialize<'de>> serde::Deserialize<'de>
         D: serde::Deserializer<'de>,
     {
         #[derive(serde::Deserialize)]
-        struct Helper<Condition> {
+        struct Helper {
             #[serde(rename = "type")]
             kind: SubscriptionType,
             version: String,
-            condition: Condition,
+            condition: serde_json::Value,
             transport: Transport,
         }
 
@@ -80,14 +82,111 @@ impl<'de, Condition: serde::Deserialize<'de>> serde::Deserialize<'de>
                 SubscriptionType::AutomodMessageUpdateV2
             }
             (SubscriptionType::ChannelModerate, "2") => SubscriptionType::ChannelModerateV2,
+            (SubscriptionType::Unknown { kind, .. }, version) => SubscriptionType::Unknown {
+                kind,
+                version: version.to_string(),
+            },
             (kind, _) => kind,
         };
 
+        let condition =
+            Condition::from_value(&kind, helper.condition).map_err(serde::de::Error::custom)?;
+
         Ok(CreateEventSubRequest {
             kind,
             version: helper.version,
-            condition: helper.condition,
+            condition,
             transport: helper.transport,
         })
     }
 }
+
+/// <https://dev.twitch.tv/docs/api/reference/#create-conduits>
+#[cfg(feature = "twitch-conduit")]
+#[derive(Debug, Serialize)]
+pub struct CreateConduitRequest {
+    /// The number of shards to create for this conduit.
+    pub shard_count: u64,
+}
+
+#[cfg(feature = "twitch-conduit")]
+impl IntoRequestBody for CreateConduitRequest {
+    fn as_body(&self) -> Option<String> {
+        Some(serde_json::to_string(self).unwrap())
+    }
+}
+
+/// <https://dev.twitch.tv/docs/api/reference/#update-conduits>
+#[cfg(feature = "twitch-conduit")]
+#[derive(Debug, Serialize)]
+pub struct UpdateConduitRequest {
+    /// Conduit ID.
+    pub id: ConduitId,
+    /// The new number of shards for this conduit.
+    pub shard_count: u64,
+}
+
+#[cfg(feature = "twitch-conduit")]
+impl IntoRequestBody for UpdateConduitRequest {
+    fn as_body(&self) -> Option<String> {
+        Some(serde_json::to_string(self).unwrap())
+    }
+}
+
+/// <https://dev.twitch.tv/docs/api/reference/#get-conduit-shards>
+#[cfg(feature = "twitch-conduit")]
+#[derive(Debug, Serialize)]
+pub struct GetConduitShardsRequest {
+    /// Conduit ID.
+    pub conduit_id: ConduitId,
+    /// Status to filter by.
+    pub status: Option<Status>,
+}
+
+#[cfg(feature = "twitch-conduit")]
+impl IntoQueryPairs for GetConduitShardsRequest {
+    fn into_query_pairs(self) -> Vec<(&'static str, String)> {
+        let mut params = QueryParams::new();
+        params
+            .push_opt("conduit_id", Some(self.conduit_id))
+            .push_opt("status", self.status);
+
+        params.build()
+    }
+}
+
+/// <https://dev.twitch.tv/docs/api/reference/#update-conduit-shards>
+#[cfg(feature = "twitch-conduit")]
+#[derive(Debug, Serialize)]
+pub struct UpdateConduitShardsRequest {
+    /// Conduit ID.
+    pub conduit_id: ConduitId,
+    /// List of shards to update.
+    pub shards: Vec<ShardUpdate>,
+}
+
+#[cfg(feature = "twitch-conduit")]
+impl IntoRequestBody for UpdateConduitShardsRequest {
+    fn as_body(&self) -> Option<String> {
+        Some(serde_json::to_string(self).unwrap())
+    }
+}
+
+#[cfg(feature = "twitch-conduit")]
+#[derive(Clone, Debug, Serialize)]
+pub struct ShardUpdate {
+    /// Shard ID.
+    pub id: ShardId,
+    /// The transport details that you want Twitch to use when sending you notifications.
+    pub transport: Transport,
+}
+
+#[cfg(feature = "twitch-conduit")]
+impl ShardUpdate {
+    pub fn new(id: impl Into<ShardId>, transport: Transport) -> Self {
+        Self {
+            id: id.into(),
+            transport,
+        }
+    }
+}
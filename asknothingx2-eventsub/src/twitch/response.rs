@@ -5,6 +5,8 @@ use twitch_highway::types::Pagination;
 pub use twitch_highway::types::Images;
 
 use super::types::Subscription;
+#[cfg(feature = "twitch-conduit")]
+use super::types::{Conduit, Shard, ShardError};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EventSubscriptionsResponse {
@@ -18,3 +20,34 @@ pub struct EventSubscriptionsResponse {
     )]
     pub pagination: Option<Pagination>,
 }
+
+/// Get Conduits, Create Conduits and Update Conduits.
+#[cfg(feature = "twitch-conduit")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConduitsResponse {
+    pub data: Vec<Conduit>,
+}
+
+/// <https://dev.twitch.tv/docs/api/reference/#get-conduit-shards>
+#[cfg(feature = "twitch-conduit")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConduitShardsResponse {
+    pub data: Vec<Shard>,
+    #[serde(
+        default,
+        serialize_with = "serialize_none_as_empty_object",
+        deserialize_with = "deserialize_empty_object_as_none"
+    )]
+    pub pagination: Option<Pagination>,
+}
+
+/// <https://dev.twitch.tv/docs/api/reference/#update-conduit-shards>
+#[cfg(feature = "twitch-conduit")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateConduitShardsResponse {
+    /// The shards that were updated.
+    pub data: Vec<Shard>,
+    /// The shards that could not be updated.
+    #[serde(default)]
+    pub errors: Vec<ShardError>,
+}
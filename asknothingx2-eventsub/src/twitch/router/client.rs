@@ -0,0 +1,348 @@
+use std::{sync::Arc, time::Duration};
+
+use futures_util::{Stream, StreamExt};
+use tokio::{
+    net::TcpStream,
+    time::{self, Instant},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use url::Url;
+
+use crate::twitch::{
+    error::ClientError,
+    request::CreateEventSubRequest,
+    types::{Condition, SessionId},
+    websocket_message::{CloseAction, CloseReason, Reconnect, Session, WebSocketMessage},
+    EventSubAPI,
+};
+
+use super::{
+    limits::CostUsage,
+    subscriber::{Subscriber, SubscriptionFailure},
+};
+
+const TWITCH_EVENTSUB_WEBSOCKET: &str = "wss://eventsub.wss.twitch.tv/ws";
+/// Time allowed for the Welcome message before any keepalive timeout is known.
+const WELCOME_TIMEOUT: Duration = Duration::from_secs(10);
+/// Slack added on top of `keepalive_timeout_seconds` before the connection is considered dead.
+const KEEPALIVE_GRACE: Duration = Duration::from_secs(5);
+/// Time the server allows for creating a subscription after the Welcome message.
+const SUBSCRIBE_WINDOW: Duration = Duration::from_secs(10);
+/// Delay before the first reconnect attempt; doubled on each further attempt.
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+/// Longest delay between reconnect attempts.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// <https://dev.twitch.tv/docs/eventsub/handling-websocket-events>
+///
+/// Connects lazily on the first call to [`WebSocketClient::next_message`].
+/// Reconnect messages are handled internally: the client connects to the
+/// `reconnect_url`, waits for the new Welcome and then drops the old connection.
+/// That Welcome is not yielded because the session and its subscriptions carry over.
+///
+/// When the connection is lost the client follows [`ClientError::action`]:
+/// [`CloseAction::Reconnect`] opens a new connection with exponential backoff,
+/// anything else is returned to the caller.
+///
+/// Subscriptions registered with [`WebSocketClient::subscriptions`] are created
+/// again every time a new session starts, before its Welcome is yielded.
+/// They are sent concurrently and must succeed within 10 seconds of the Welcome,
+/// otherwise the server closes the connection; see [`WebSocketClient::subscribe_deadline`].
+#[derive(Debug)]
+pub struct WebSocketClient {
+    url: Url,
+    keepalive_timeout_seconds: Option<u64>,
+    auto_reconnect: bool,
+    max_reconnect_attempts: u32,
+    subscriber: Option<Subscriber>,
+    failed_subscriptions: Vec<SubscriptionFailure>,
+    stream: Option<WsStream>,
+    session: Option<Session>,
+    subscribe_deadline: Option<Instant>,
+    keepalive: Duration,
+    reconnect_attempts: u32,
+}
+
+impl WebSocketClient {
+    pub fn new() -> Self {
+        Self {
+            url: Url::parse(TWITCH_EVENTSUB_WEBSOCKET).unwrap(),
+            keepalive_timeout_seconds: None,
+            auto_reconnect: true,
+            max_reconnect_attempts: 5,
+            subscriber: None,
+            failed_subscriptions: Vec::new(),
+            stream: None,
+            session: None,
+            subscribe_deadline: None,
+            keepalive: WELCOME_TIMEOUT,
+            reconnect_attempts: 0,
+        }
+    }
+
+    pub fn url(mut self, url: Url) -> Self {
+        self.url = url;
+        self
+    }
+
+    /// The keepalive timeout requested when connecting.
+    /// Twitch accepts values from 10 to 600 seconds.
+    pub fn keepalive_timeout_seconds(mut self, seconds: u64) -> Self {
+        self.keepalive_timeout_seconds = Some(seconds);
+        self
+    }
+
+    /// Reconnect automatically when the connection is lost. Defaults to `true`.
+    pub fn auto_reconnect(mut self, auto_reconnect: bool) -> Self {
+        self.auto_reconnect = auto_reconnect;
+        self
+    }
+
+    /// Consecutive failed attempts before the error is returned. Defaults to 5.
+    pub fn max_reconnect_attempts(mut self, attempts: u32) -> Self {
+        self.max_reconnect_attempts = attempts;
+        self
+    }
+
+    /// Subscriptions to create on every new session.
+    /// The transport of each request is replaced with the new session ID.
+    ///
+    /// With subscriptions configured, a close for an unused connection reconnects as well.
+    /// Templates beyond the per-session limit are rejected without calling the API;
+    /// use [`WebSocketPool`](super::WebSocketPool) to spread them over several sessions.
+    pub fn subscriptions<A>(
+        mut self,
+        api: A,
+        requests: impl IntoIterator<Item = CreateEventSubRequest<Condition>>,
+    ) -> Self
+    where
+        A: EventSubAPI + Send + Sync + 'static,
+    {
+        self.subscriber = Some(Subscriber::new(
+            Arc::new(api),
+            requests.into_iter().collect(),
+            Default::default(),
+        ));
+        self
+    }
+
+    pub(crate) fn subscriber(mut self, subscriber: Subscriber) -> Self {
+        self.subscriber = Some(subscriber);
+        self
+    }
+
+    /// The subscription cost as last reported by Twitch.
+    pub fn cost_usage(&self) -> Option<CostUsage> {
+        self.subscriber.as_ref().map(Subscriber::cost_usage)
+    }
+
+    /// Subscriptions that could not be created for the current session.
+    pub fn failed_subscriptions(&self) -> &[SubscriptionFailure] {
+        &self.failed_subscriptions
+    }
+
+    pub fn session(&self) -> Option<&Session> {
+        self.session.as_ref()
+    }
+
+    pub fn session_id(&self) -> Option<&SessionId> {
+        self.session.as_ref().map(|session| &session.id)
+    }
+
+    /// When the server closes the current session if nothing has been subscribed.
+    /// Callers that subscribe on their own must finish before this instant.
+    pub fn subscribe_deadline(&self) -> Option<Instant> {
+        self.subscribe_deadline
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Waits for the next message, connecting first if needed.
+    ///
+    /// Keepalive messages are returned as well so callers can observe them.
+    /// A message that fails to parse is returned as [`ClientError::Message`]
+    /// and leaves the connection open; any other error drops it.
+    ///
+    /// After an automatic reconnect the next message is the Welcome of a new session,
+    /// which has no subscriptions.
+    pub async fn next_message(&mut self) -> Result<WebSocketMessage, ClientError> {
+        loop {
+            let e = match self.read_next().await {
+                Ok(message) => {
+                    self.reconnect_attempts = 0;
+                    return Ok(message);
+                }
+                Err(e @ ClientError::Message(_)) => return Err(e),
+                Err(e) => e,
+            };
+
+            self.stream = None;
+            if !self.should_reconnect(&e) {
+                return Err(e);
+            }
+
+            self.reconnect_attempts += 1;
+            let delay = RECONNECT_BACKOFF
+                .saturating_mul(2u32.saturating_pow(self.reconnect_attempts - 1))
+                .min(MAX_RECONNECT_DELAY);
+            tracing::warn!(
+                "{e}; reconnecting in {delay:?} (attempt {}/{})",
+                self.reconnect_attempts,
+                self.max_reconnect_attempts
+            );
+            time::sleep(delay).await;
+        }
+    }
+
+    /// Closes the current connection, if any.
+    pub async fn close(&mut self) -> Result<(), ClientError> {
+        if let Some(mut stream) = self.stream.take() {
+            stream.close(None).await?;
+        }
+        self.session = None;
+        self.subscribe_deadline = None;
+        self.keepalive = WELCOME_TIMEOUT;
+        Ok(())
+    }
+
+    /// The stream ends after the first error.
+    pub fn into_stream(self) -> impl Stream<Item = Result<WebSocketMessage, ClientError>> {
+        futures_util::stream::unfold(Some(self), |client| async move {
+            let mut client = client?;
+            match client.next_message().await {
+                Ok(message) => Some((Ok(message), Some(client))),
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+
+    async fn read_next(&mut self) -> Result<WebSocketMessage, ClientError> {
+        let stream = match self.stream.as_mut() {
+            Some(stream) => stream,
+            None => {
+                let url = self.connect_url();
+                self.session = None;
+                self.subscribe_deadline = None;
+                self.keepalive = WELCOME_TIMEOUT;
+                self.stream.insert(connect(url.as_str()).await?)
+            }
+        };
+
+        let message = match read(stream, self.keepalive).await {
+            Err(ClientError::Closed(CloseReason::ConnectionUnused)) => {
+                return Err(ClientError::SubscribeWindowExpired(SUBSCRIBE_WINDOW))
+            }
+            result => result?,
+        };
+
+        match message {
+            WebSocketMessage::Welcome(welcome) => {
+                let deadline = Instant::now() + SUBSCRIBE_WINDOW;
+                self.subscribe_deadline = Some(deadline);
+                self.set_session(welcome.payload.session.clone());
+                if let Some(subscriber) = &self.subscriber {
+                    self.failed_subscriptions = subscriber
+                        .subscribe(&welcome.payload.session.id, deadline)
+                        .await;
+                }
+                Ok(WebSocketMessage::Welcome(welcome))
+            }
+            WebSocketMessage::Reconnect(reconnect) => {
+                self.reconnect(&reconnect).await?;
+                Ok(WebSocketMessage::Reconnect(reconnect))
+            }
+            message => Ok(message),
+        }
+    }
+
+    async fn reconnect(&mut self, reconnect: &Reconnect) -> Result<(), ClientError> {
+        let url = reconnect
+            .payload
+            .session
+            .reconnect_url
+            .as_deref()
+            .ok_or(ClientError::MissingReconnectUrl)?;
+
+        tracing::debug!("reconnecting to {url}");
+
+        let mut stream = connect(url).await?;
+        match read(&mut stream, WELCOME_TIMEOUT).await? {
+            WebSocketMessage::Welcome(welcome) => self.set_session(welcome.payload.session),
+            message => {
+                return Err(ClientError::UnexpectedMessage(
+                    message.message_type().to_string(),
+                ))
+            }
+        }
+
+        if let Some(mut old) = self.stream.replace(stream) {
+            let _ = old.close(None).await;
+        }
+
+        Ok(())
+    }
+
+    fn should_reconnect(&self, e: &ClientError) -> bool {
+        let action = match e.action() {
+            CloseAction::Resubscribe if self.subscriber.is_some() => CloseAction::Reconnect,
+            action => action,
+        };
+
+        self.auto_reconnect
+            && action == CloseAction::Reconnect
+            && self.reconnect_attempts < self.max_reconnect_attempts
+    }
+
+    fn set_session(&mut self, session: Session) {
+        if let Some(seconds) = session.keepalive_timeout_seconds {
+            self.keepalive = Duration::from_secs(seconds) + KEEPALIVE_GRACE;
+        }
+        self.session = Some(session);
+    }
+
+    fn connect_url(&self) -> Url {
+        let mut url = self.url.clone();
+        if let Some(seconds) = self.keepalive_timeout_seconds {
+            url.query_pairs_mut()
+                .append_pair("keepalive_timeout_seconds", &seconds.to_string());
+        }
+        url
+    }
+}
+
+impl Default for WebSocketClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn connect(url: &str) -> Result<WsStream, ClientError> {
+    let (stream, _) = connect_async(url).await?;
+    Ok(stream)
+}
+
+/// Pings are answered by tungstenite while reading.
+async fn read(stream: &mut WsStream, timeout: Duration) -> Result<WebSocketMessage, ClientError> {
+    loop {
+        let message = match time::timeout(timeout, stream.next()).await {
+            Ok(Some(message)) => message?,
+            Ok(None) => return Err(ClientError::Disconnected),
+            Err(_) => return Err(ClientError::KeepaliveTimeout(timeout)),
+        };
+
+        match message {
+            Message::Text(text) => return Ok(text.as_str().parse()?),
+            Message::Close(Some(frame)) => {
+                return Err(ClientError::Closed(CloseReason::from_code(
+                    frame.code.into(),
+                )))
+            }
+            Message::Close(None) => return Err(ClientError::Disconnected),
+            _ => continue,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use serde::de::DeserializeOwned;
+
+use crate::twitch::{
+    types::{Subscription, SubscriptionType},
+    websocket_message::{MetaData, RawNotification},
+};
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+pub type HandlerResult = Result<(), BoxError>;
+
+pub(crate) type HandlerFuture = Pin<Box<dyn Future<Output = HandlerResult> + Send>>;
+pub(crate) type BoxedHandler<T> = Arc<dyn Fn(T) -> HandlerFuture + Send + Sync>;
+
+pub(crate) fn boxed<T, H, Fut>(handler: H) -> BoxedHandler<T>
+where
+    H: Fn(T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = HandlerResult> + Send + 'static,
+{
+    Arc::new(move |arg| Box::pin(handler(arg)))
+}
+
+/// A notification handed to a route handler.
+///
+/// The event is kept as JSON; decode it with [`EventContext::event`].
+#[derive(Debug)]
+pub struct EventContext {
+    pub metadata: MetaData,
+    pub subscription: Subscription,
+    pub event: serde_json::Value,
+}
+
+impl EventContext {
+    pub fn kind(&self) -> &SubscriptionType {
+        &self.subscription.kind
+    }
+
+    pub fn broadcaster_id(&self) -> Option<&str> {
+        self.subscription.condition.broadcaster_user_id()
+    }
+
+    pub fn event<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(&self.event)
+    }
+}
+
+impl From<RawNotification> for EventContext {
+    fn from(notification: RawNotification) -> Self {
+        Self {
+            metadata: notification.metadata,
+            subscription: notification.payload.subscription,
+            event: notification.payload.event,
+        }
+    }
+}
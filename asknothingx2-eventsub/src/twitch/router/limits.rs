@@ -0,0 +1,47 @@
+//! <https://dev.twitch.tv/docs/eventsub/manage-subscriptions/#subscription-limits>
+use std::sync::{Arc, Mutex};
+
+use crate::twitch::{request::CreateEventSubRequest, types::Condition};
+
+/// Enabled subscriptions allowed on a single WebSocket session.
+pub const MAX_SESSION_SUBSCRIPTIONS: usize = 300;
+/// WebSocket connections allowed per client ID and user.
+pub const MAX_CONNECTIONS: usize = 3;
+
+/// The cost of the enabled subscriptions as last reported by Twitch.
+///
+/// Subscriptions the user has authorized cost nothing; others cost 1.
+/// `max_total_cost` is only known after a subscription was created; from then
+/// on requests whose predicted cost doesn't fit are rejected without calling
+/// the API.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CostUsage {
+    pub total_cost: u64,
+    /// `None` until the first subscription was created.
+    pub max_total_cost: Option<u64>,
+}
+
+impl CostUsage {
+    pub fn is_exhausted(&self) -> bool {
+        self.max_total_cost
+            .is_some_and(|max_total_cost| self.total_cost >= max_total_cost)
+    }
+
+    /// `None` until the first subscription was created.
+    pub fn remaining(&self) -> Option<u64> {
+        self.max_total_cost
+            .map(|max_total_cost| max_total_cost.saturating_sub(self.total_cost))
+    }
+}
+
+/// The cost creating `request` adds. Twitch doesn't say which users authorized
+/// the app, so a type that doesn't require authorization is assumed to cost 1
+/// unless its condition names no user.
+pub(crate) fn predicted_cost(request: &CreateEventSubRequest<Condition>) -> u64 {
+    let free =
+        request.kind.requires_user_authorization() || request.condition.user_ids().is_empty();
+    u64::from(!free)
+}
+
+/// Shared by every session of a client ID.
+pub(crate) type SharedCost = Arc<Mutex<CostUsage>>;
@@ -0,0 +1,247 @@
+//! Routes EventSub WebSocket messages to async handlers.
+//!
+//! ```rust,ignore
+//! use asknothingx2_eventsub::twitch::{
+//!     events::stream::StreamOnlineEvent,
+//!     router::{Router, WebSocketClient},
+//!     types::SubscriptionType,
+//! };
+//!
+//! let router = Router::new()
+//!     .route(SubscriptionType::StreamOnline, |ctx| async move {
+//!         let event: StreamOnlineEvent = ctx.event()?;
+//!         println!("{event:?}");
+//!         Ok(())
+//!     })
+//!     .on_welcome(|session| async move {
+//!         println!("session {}", session.id);
+//!         Ok(())
+//!     });
+//!
+//! router.run(WebSocketClient::new()).await?;
+//! ```
+use std::{any::Any, collections::HashMap, future::Future, sync::Arc};
+
+use twitch_highway::types::BroadcasterId;
+
+use super::{
+    error::{ClientError, HandlerError},
+    types::{Subscription, SubscriptionType},
+    websocket_message::{Session, WebSocketMessage},
+};
+
+mod client;
+mod handler;
+pub mod limits;
+mod pool;
+mod subscriber;
+#[cfg(feature = "twitch-conduit")]
+mod supervisor;
+
+pub use client::WebSocketClient;
+pub use handler::{BoxError, EventContext, HandlerResult};
+pub use pool::WebSocketPool;
+pub use subscriber::SubscriptionFailure;
+#[cfg(feature = "twitch-conduit")]
+pub use supervisor::{ConduitShards, ShardSupervisor};
+
+use handler::{boxed, BoxedHandler};
+
+type ErrorHook = Arc<dyn Fn(&HandlerError) + Send + Sync>;
+
+/// Handlers are awaited one message at a time, in the order messages arrive.
+/// Each handler runs in its own task, so a panic or an error is reported to
+/// [`Router::on_error`] and the session keeps going.
+#[derive(Default)]
+pub struct Router {
+    routes: HashMap<(SubscriptionType, Option<BroadcasterId>), BoxedHandler<EventContext>>,
+    fallback: Option<BoxedHandler<EventContext>>,
+    revocation: Option<BoxedHandler<Subscription>>,
+    welcome: Option<BoxedHandler<Session>>,
+    reconnect: Option<BoxedHandler<Session>>,
+    error: Option<ErrorHook>,
+}
+
+impl Router {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handles every notification of `kind`.
+    pub fn route<H, Fut>(mut self, kind: SubscriptionType, handler: H) -> Self
+    where
+        H: Fn(EventContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.routes.insert((kind, None), boxed(handler));
+        self
+    }
+
+    /// Handles notifications of `kind` whose condition targets `broadcaster_id`.
+    /// Takes precedence over [`Router::route`] for the same type.
+    pub fn route_broadcaster<H, Fut>(
+        mut self,
+        kind: SubscriptionType,
+        broadcaster_id: BroadcasterId,
+        handler: H,
+    ) -> Self
+    where
+        H: Fn(EventContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.routes
+            .insert((kind, Some(broadcaster_id)), boxed(handler));
+        self
+    }
+
+    /// Handles notifications that no route matched.
+    pub fn fallback<H, Fut>(mut self, handler: H) -> Self
+    where
+        H: Fn(EventContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.fallback = Some(boxed(handler));
+        self
+    }
+
+    pub fn on_revocation<H, Fut>(mut self, handler: H) -> Self
+    where
+        H: Fn(Subscription) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.revocation = Some(boxed(handler));
+        self
+    }
+
+    /// Called when a new session is established.
+    pub fn on_welcome<H, Fut>(mut self, handler: H) -> Self
+    where
+        H: Fn(Session) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.welcome = Some(boxed(handler));
+        self
+    }
+
+    /// Called with the new session once the client has moved to the
+    /// `reconnect_url` and received its Welcome.
+    pub fn on_reconnect<H, Fut>(mut self, handler: H) -> Self
+    where
+        H: Fn(Session) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.reconnect = Some(boxed(handler));
+        self
+    }
+
+    pub fn on_error<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&HandlerError) + Send + Sync + 'static,
+    {
+        self.error = Some(Arc::new(hook));
+        self
+    }
+
+    /// Runs until the connection fails. Messages that fail to parse are skipped.
+    pub async fn run(&self, mut client: WebSocketClient) -> Result<(), ClientError> {
+        loop {
+            match client.next_message().await {
+                Ok(WebSocketMessage::Reconnect(_)) => {
+                    if let Some(session) = client.session() {
+                        self.reconnected(session.clone()).await;
+                    }
+                }
+                Ok(message) => self.dispatch(message).await,
+                Err(ClientError::Message(e)) => tracing::warn!("skipping message: {e}"),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// A Reconnect message only carries the old session, so it is not passed
+    /// to [`Router::on_reconnect`]; call [`Router::reconnected`] with the new one.
+    pub async fn dispatch(&self, message: WebSocketMessage) {
+        match message {
+            WebSocketMessage::Notification(notification) => {
+                let ctx = EventContext::from(notification);
+                let kind = ctx.kind().to_string();
+                match self.find(&ctx) {
+                    Some(handler) => self.call(kind, handler, ctx).await,
+                    None => tracing::debug!("no handler for {kind}"),
+                }
+            }
+            WebSocketMessage::Revocation(revocation) => {
+                let subscription = revocation.payload.subscription;
+                tracing::warn!(
+                    "subscription {} ({}) revoked: {}",
+                    subscription.id,
+                    subscription.kind,
+                    subscription.status
+                );
+                if let Some(handler) = &self.revocation {
+                    self.call("revocation".to_string(), handler, subscription)
+                        .await;
+                }
+            }
+            WebSocketMessage::Welcome(welcome) => {
+                if let Some(handler) = &self.welcome {
+                    self.call("welcome".to_string(), handler, welcome.payload.session)
+                        .await;
+                }
+            }
+            WebSocketMessage::Reconnect(_) | WebSocketMessage::Keepalive(_) => {}
+        }
+    }
+
+    /// Calls the [`Router::on_reconnect`] handler with the session that
+    /// replaced the old one. [`Router::run`] does this after the new Welcome.
+    pub async fn reconnected(&self, session: Session) {
+        if let Some(handler) = &self.reconnect {
+            self.call("reconnect".to_string(), handler, session).await;
+        }
+    }
+
+    fn find(&self, ctx: &EventContext) -> Option<&BoxedHandler<EventContext>> {
+        let kind = ctx.kind().clone();
+        ctx.broadcaster_id()
+            .and_then(|id| {
+                self.routes
+                    .get(&(kind.clone(), Some(BroadcasterId::new(id))))
+            })
+            .or_else(|| self.routes.get(&(kind, None)))
+            .or(self.fallback.as_ref())
+    }
+
+    async fn call<T: Send + 'static>(&self, kind: String, handler: &BoxedHandler<T>, arg: T) {
+        let handler = handler.clone();
+        let error = match tokio::spawn(async move { handler(arg).await }).await {
+            Ok(Ok(())) => return,
+            Ok(Err(source)) => HandlerError::Failed { kind, source },
+            Err(e) => match e.try_into_panic() {
+                Ok(panic) => HandlerError::Panicked {
+                    kind,
+                    message: panic_message(panic),
+                },
+                Err(e) => HandlerError::Failed {
+                    kind,
+                    source: Box::new(e),
+                },
+            },
+        };
+
+        tracing::error!("{error}");
+        if let Some(hook) = &self.error {
+            hook(&error);
+        }
+    }
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(message) => *message,
+        Err(panic) => match panic.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use futures_util::{stream, Stream, StreamExt};
+
+use crate::twitch::{
+    error::{ClientError, LimitError},
+    request::CreateEventSubRequest,
+    types::Condition,
+    websocket_message::WebSocketMessage,
+    EventSubAPI,
+};
+
+use super::{
+    limits::{predicted_cost, CostUsage, SharedCost, MAX_CONNECTIONS, MAX_SESSION_SUBSCRIPTIONS},
+    subscriber::Subscriber,
+    WebSocketClient,
+};
+
+/// Spreads subscriptions over up to [`MAX_CONNECTIONS`] WebSocket sessions.
+///
+/// A session is filled up to [`MAX_SESSION_SUBSCRIPTIONS`] before the next one is opened.
+/// Every session shares the cost reported by Twitch.
+///
+/// ```rust,ignore
+/// let mut pool = WebSocketPool::new(api);
+/// for request in requests {
+///     pool.subscribe(request)?;
+/// }
+///
+/// let mut messages = pool.into_stream();
+/// while let Some((session, message)) = messages.next().await {
+///     println!("session {session}: {message:?}");
+/// }
+/// ```
+pub struct WebSocketPool {
+    api: Arc<dyn EventSubAPI + Send + Sync>,
+    sessions: Vec<Vec<CreateEventSubRequest<Condition>>>,
+    /// Predicted cost of every assigned request.
+    predicted: u64,
+    cost: SharedCost,
+}
+
+impl WebSocketPool {
+    pub fn new<A>(api: A) -> Self
+    where
+        A: EventSubAPI + Send + Sync + 'static,
+    {
+        Self {
+            api: Arc::new(api),
+            sessions: Vec::new(),
+            predicted: 0,
+            cost: SharedCost::default(),
+        }
+    }
+
+    /// Assigns `request` to the first session with room and returns its index.
+    /// Fails if the predicted cost of the assigned requests no longer fits in
+    /// `max_total_cost`.
+    pub fn subscribe(
+        &mut self,
+        request: CreateEventSubRequest<Condition>,
+    ) -> Result<usize, LimitError> {
+        let usage = self.cost_usage();
+        let cost = self.predicted + predicted_cost(&request);
+        if let Some(max_total_cost) = usage
+            .max_total_cost
+            .filter(|_| usage.remaining() < Some(cost))
+        {
+            return Err(LimitError::CostExceeded {
+                total_cost: usage.total_cost,
+                max_total_cost,
+            });
+        }
+
+        let index = match self
+            .sessions
+            .iter()
+            .position(|requests| requests.len() < MAX_SESSION_SUBSCRIPTIONS)
+        {
+            Some(index) => index,
+            None if self.sessions.len() < MAX_CONNECTIONS => {
+                self.sessions.push(Vec::new());
+                self.sessions.len() - 1
+            }
+            None => return Err(LimitError::TooManyConnections(MAX_CONNECTIONS)),
+        };
+
+        self.predicted = cost;
+        self.sessions[index].push(request);
+        Ok(index)
+    }
+
+    /// The number of subscriptions assigned to each session.
+    pub fn session_sizes(&self) -> Vec<usize> {
+        self.sessions.iter().map(Vec::len).collect()
+    }
+
+    pub fn cost_usage(&self) -> CostUsage {
+        *self.cost.lock().unwrap()
+    }
+
+    pub fn into_clients(self) -> Vec<WebSocketClient> {
+        self.into_clients_with(WebSocketClient::new)
+    }
+
+    /// Builds each session's client from `client`, e.g. to set the keepalive timeout.
+    pub fn into_clients_with<F>(self, mut client: F) -> Vec<WebSocketClient>
+    where
+        F: FnMut() -> WebSocketClient,
+    {
+        self.sessions
+            .into_iter()
+            .map(|requests| {
+                client().subscriber(Subscriber::new(
+                    self.api.clone(),
+                    requests,
+                    self.cost.clone(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Messages of every session, tagged with the session index.
+    /// A session's stream ends after its first error.
+    pub fn into_stream(self) -> impl Stream<Item = (usize, Result<WebSocketMessage, ClientError>)> {
+        stream::select_all(
+            self.into_clients()
+                .into_iter()
+                .enumerate()
+                .map(|(index, client)| {
+                    client
+                        .into_stream()
+                        .map(move |message| (index, message))
+                        .boxed()
+                }),
+        )
+    }
+}
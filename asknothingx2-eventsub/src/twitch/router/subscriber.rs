@@ -0,0 +1,135 @@
+use std::{fmt, sync::Arc};
+
+use futures_util::future::join_all;
+use tokio::time::{self, Instant};
+
+use crate::twitch::{
+    error::{LimitError, SubscribeError},
+    fetch,
+    request::CreateEventSubRequest,
+    response::EventSubscriptionsResponse,
+    types::{Condition, SessionId, Transport},
+    EventSubAPI,
+};
+
+use super::limits::{predicted_cost, CostUsage, SharedCost, MAX_SESSION_SUBSCRIPTIONS};
+
+/// A subscription that could not be created for a new session.
+#[derive(Debug)]
+pub struct SubscriptionFailure {
+    pub request: CreateEventSubRequest<Condition>,
+    pub error: SubscribeError,
+}
+
+/// Re-creates the desired subscriptions whenever a new session starts.
+#[derive(Clone)]
+pub(crate) struct Subscriber {
+    api: Arc<dyn EventSubAPI + Send + Sync>,
+    requests: Vec<CreateEventSubRequest<Condition>>,
+    cost: SharedCost,
+}
+
+impl Subscriber {
+    pub fn new(
+        api: Arc<dyn EventSubAPI + Send + Sync>,
+        requests: Vec<CreateEventSubRequest<Condition>>,
+        cost: SharedCost,
+    ) -> Self {
+        Self {
+            api,
+            requests,
+            cost,
+        }
+    }
+
+    pub fn cost_usage(&self) -> CostUsage {
+        *self.cost.lock().unwrap()
+    }
+
+    /// Creates every template concurrently with its transport replaced by `session_id`.
+    /// Requests still pending at `deadline` fail with [`SubscribeError::WindowExpired`].
+    ///
+    /// Templates beyond [`MAX_SESSION_SUBSCRIPTIONS`], or whose predicted cost no
+    /// longer fits in `max_total_cost`, fail with [`SubscribeError::Limit`]
+    /// without calling the API.
+    pub async fn subscribe(
+        &self,
+        session_id: &SessionId,
+        deadline: Instant,
+    ) -> Vec<SubscriptionFailure> {
+        let mut failures = Vec::new();
+        let usage = self.cost_usage();
+        let mut remaining = usage.remaining().unwrap_or(u64::MAX);
+        let mut requests = Vec::new();
+
+        for (index, template) in self.requests.iter().enumerate() {
+            let cost = predicted_cost(template);
+            let limit = if index >= MAX_SESSION_SUBSCRIPTIONS {
+                LimitError::SessionFull(MAX_SESSION_SUBSCRIPTIONS)
+            } else if let Some(max_total_cost) = usage.max_total_cost.filter(|_| remaining < cost) {
+                LimitError::CostExceeded {
+                    total_cost: usage.total_cost,
+                    max_total_cost,
+                }
+            } else {
+                remaining -= cost;
+                requests.push(template);
+                continue;
+            };
+            failures.push(SubscriptionFailure {
+                request: template.clone(),
+                error: limit.into(),
+            });
+        }
+
+        let results = join_all(requests.into_iter().map(|template| async move {
+            let mut request = template.clone();
+            request.transport = Transport::websocket(session_id.clone());
+
+            let result = time::timeout_at(deadline, create(self.api.as_ref(), request.clone()))
+                .await
+                .unwrap_or(Err(SubscribeError::WindowExpired));
+            (request, result)
+        }))
+        .await;
+
+        for (request, result) in results {
+            match result {
+                Ok(response) => {
+                    let mut cost = self.cost.lock().unwrap();
+                    cost.total_cost = response.total_cost;
+                    cost.max_total_cost = Some(response.max_total_cost);
+                }
+                Err(error) => failures.push(SubscriptionFailure { request, error }),
+            }
+        }
+
+        for failure in &failures {
+            tracing::warn!(
+                "failed to subscribe to {}: {}",
+                failure.request.kind,
+                failure.error
+            );
+        }
+        if !self.requests.is_empty() && failures.len() == self.requests.len() {
+            tracing::warn!("no subscription was created; the server will close the connection");
+        }
+        failures
+    }
+}
+
+impl fmt::Debug for Subscriber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscriber")
+            .field("requests", &self.requests)
+            .field("cost", &self.cost)
+            .finish_non_exhaustive()
+    }
+}
+
+async fn create(
+    api: &(dyn EventSubAPI + Send + Sync),
+    request: CreateEventSubRequest<Condition>,
+) -> Result<EventSubscriptionsResponse, SubscribeError> {
+    Ok(fetch(api.create_eventsub(request)).await?)
+}
@@ -0,0 +1,365 @@
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_util::Stream;
+use tokio::{sync::mpsc, task::JoinHandle, time};
+
+use crate::twitch::{
+    error::{ClientError, SubscribeError},
+    events::conduit_shard::ConduitShardDisabledEvent,
+    fetch,
+    request::{ShardUpdate, UpdateConduitShardsRequest},
+    send,
+    types::{ConduitId, SessionId, ShardId, Transport},
+    websocket_message::WebSocketMessage,
+    EventSubAPI,
+};
+
+use super::WebSocketClient;
+
+/// Delay before a shard whose session ended for good is started again.
+const RESTART_DELAY: Duration = Duration::from_secs(1);
+/// Longest delay between restarts of a shard that keeps failing to attach.
+const MAX_RESTART_DELAY: Duration = Duration::from_secs(60);
+
+type Api = Arc<dyn EventSubAPI + Send + Sync>;
+type ClientFactory = Arc<dyn Fn() -> WebSocketClient + Send + Sync>;
+
+/// Keeps every shard of a conduit bound to a live WebSocket session.
+///
+/// Each shard `0..shard_count` gets its own [`WebSocketClient`]. Whenever a client
+/// starts a new session the shard is pointed at it with Update Conduit Shards, and
+/// a client whose session ended for good is replaced by a new one. A shard that
+/// can't be attached is restarted too, backing off up to a minute between tries.
+///
+/// `conduit.shard.disabled` is only delivered to webhooks; pass those events to
+/// [`ConduitShards::shard_disabled`] to have the shard re-attached.
+///
+/// ```rust,ignore
+/// let mut shards = ShardSupervisor::new(api, conduit_id, 4).start().await?;
+/// while let Some((shard_id, message)) = shards.next().await {
+///     router.dispatch(message).await;
+/// }
+/// ```
+pub struct ShardSupervisor {
+    api: Api,
+    conduit_id: ConduitId,
+    shard_count: usize,
+    client: ClientFactory,
+}
+
+impl ShardSupervisor {
+    pub fn new<A>(api: A, conduit_id: ConduitId, shard_count: usize) -> Self
+    where
+        A: EventSubAPI + Send + Sync + 'static,
+    {
+        Self {
+            api: Arc::new(api),
+            conduit_id,
+            shard_count,
+            client: Arc::new(WebSocketClient::new),
+        }
+    }
+
+    /// Builds each shard's client, e.g. to set the keepalive timeout.
+    pub fn client<F>(mut self, client: F) -> Self
+    where
+        F: Fn() -> WebSocketClient + Send + Sync + 'static,
+    {
+        self.client = Arc::new(client);
+        self
+    }
+
+    /// Resizes the conduit to `shard_count` and connects every shard.
+    pub async fn start(self) -> Result<ConduitShards, SubscribeError> {
+        send(
+            self.api
+                .update_conduit(self.conduit_id.clone(), self.shard_count as u64),
+        )
+        .await?;
+
+        let (messages_tx, messages) = mpsc::unbounded_channel();
+        let (commands, commands_rx) = mpsc::unbounded_channel();
+
+        let mut supervisor = Supervisor {
+            api: self.api,
+            conduit_id: self.conduit_id,
+            client: self.client,
+            shards: HashMap::new(),
+            sessions: Arc::default(),
+            messages: messages_tx,
+        };
+        supervisor.scale_to(self.shard_count);
+
+        let task = tokio::spawn(supervisor.run(commands_rx));
+
+        Ok(ConduitShards {
+            messages,
+            commands,
+            task,
+        })
+    }
+}
+
+/// Messages from every shard, tagged with the shard ID.
+/// Dropping it closes all sessions.
+#[derive(Debug)]
+pub struct ConduitShards {
+    messages: mpsc::UnboundedReceiver<(ShardId, WebSocketMessage)>,
+    commands: mpsc::UnboundedSender<Command>,
+    task: JoinHandle<()>,
+}
+
+impl ConduitShards {
+    /// Re-attaches the shard named in a `conduit.shard.disabled` event.
+    /// Events for other conduits are ignored.
+    pub fn shard_disabled(&self, event: &ConduitShardDisabledEvent) {
+        let _ = self.commands.send(Command::Disabled {
+            conduit_id: ConduitId::new(event.conduit_id.as_str()),
+            shard_id: ShardId::new(event.shard_id.as_str()),
+        });
+    }
+
+    /// Changes the number of shards with Update Conduits, then starts or stops sessions.
+    pub fn scale(&self, shard_count: usize) {
+        let _ = self.commands.send(Command::Scale(shard_count));
+    }
+}
+
+impl Stream for ConduitShards {
+    type Item = (ShardId, WebSocketMessage);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.poll_recv(cx)
+    }
+}
+
+impl Drop for ConduitShards {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[derive(Debug)]
+enum Command {
+    Disabled {
+        conduit_id: ConduitId,
+        shard_id: ShardId,
+    },
+    Scale(usize),
+}
+
+struct Supervisor {
+    api: Api,
+    conduit_id: ConduitId,
+    client: ClientFactory,
+    shards: HashMap<usize, JoinHandle<()>>,
+    /// The session each shard is currently attached to.
+    sessions: Arc<Mutex<HashMap<usize, SessionId>>>,
+    messages: mpsc::UnboundedSender<(ShardId, WebSocketMessage)>,
+}
+
+impl Supervisor {
+    async fn run(mut self, mut commands: mpsc::UnboundedReceiver<Command>) {
+        while let Some(command) = commands.recv().await {
+            match command {
+                Command::Disabled {
+                    conduit_id,
+                    shard_id,
+                } if conduit_id == self.conduit_id => self.reattach(&shard_id).await,
+                Command::Disabled { .. } => {}
+                Command::Scale(shard_count) => {
+                    let request = self
+                        .api
+                        .update_conduit(self.conduit_id.clone(), shard_count as u64);
+                    match send(request).await {
+                        Ok(_) => self.scale_to(shard_count),
+                        Err(e) => tracing::error!("failed to scale conduit to {shard_count}: {e}"),
+                    }
+                }
+            }
+        }
+    }
+
+    async fn reattach(&mut self, shard_id: &ShardId) {
+        let Ok(index) = shard_id.as_str().parse::<usize>() else {
+            tracing::warn!("disabled shard {shard_id} is not a shard index");
+            return;
+        };
+        if !self.shards.contains_key(&index) {
+            return;
+        }
+
+        let session_id = self.sessions.lock().unwrap().get(&index).cloned();
+        let attached = match session_id {
+            Some(session_id) => attach(&self.api, &self.conduit_id, index, session_id)
+                .await
+                .is_ok(),
+            None => false,
+        };
+
+        if !attached {
+            tracing::warn!("restarting disabled shard {shard_id}");
+            self.stop(index);
+            self.spawn(index);
+        }
+    }
+
+    fn scale_to(&mut self, shard_count: usize) {
+        let removed: Vec<usize> = self
+            .shards
+            .keys()
+            .filter(|index| **index >= shard_count)
+            .copied()
+            .collect();
+        for index in removed {
+            self.stop(index);
+        }
+
+        for index in 0..shard_count {
+            if !self.shards.contains_key(&index) {
+                self.spawn(index);
+            }
+        }
+    }
+
+    /// Aborts the shard's task. An aborted task can't clean up after itself,
+    /// so its session is forgotten here.
+    fn stop(&mut self, index: usize) {
+        if let Some(task) = self.shards.remove(&index) {
+            task.abort();
+        }
+        self.sessions.lock().unwrap().remove(&index);
+    }
+
+    fn spawn(&mut self, index: usize) {
+        let shard = Shard {
+            index,
+            api: self.api.clone(),
+            conduit_id: self.conduit_id.clone(),
+            sessions: self.sessions.clone(),
+            messages: self.messages.clone(),
+        };
+        let client = self.client.clone();
+
+        let task = tokio::spawn(async move {
+            // A session that ended for good is replaced with a new client.
+            let mut delay = RESTART_DELAY;
+            loop {
+                match shard.run(client()).await {
+                    Ok(()) => return,
+                    Err(ShardFailure::Attach(e)) => {
+                        tracing::error!(
+                            "shard {index} was not attached, restarting in {delay:?}: {e}"
+                        );
+                        time::sleep(delay).await;
+                        delay = (delay * 2).min(MAX_RESTART_DELAY);
+                    }
+                    Err(ShardFailure::Client(e)) => {
+                        tracing::warn!("shard {index} stopped, restarting: {e}");
+                        time::sleep(RESTART_DELAY).await;
+                        delay = RESTART_DELAY;
+                    }
+                }
+            }
+        });
+        self.shards.insert(index, task);
+    }
+}
+
+impl Drop for Supervisor {
+    fn drop(&mut self) {
+        for task in self.shards.values() {
+            task.abort();
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ShardFailure {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error(transparent)]
+    Attach(#[from] SubscribeError),
+}
+
+struct Shard {
+    index: usize,
+    api: Api,
+    conduit_id: ConduitId,
+    sessions: Arc<Mutex<HashMap<usize, SessionId>>>,
+    messages: mpsc::UnboundedSender<(ShardId, WebSocketMessage)>,
+}
+
+impl Shard {
+    /// Runs until the session ends for good or can't be attached,
+    /// or `Ok` once nobody reads the messages.
+    async fn run(&self, mut client: WebSocketClient) -> Result<(), ShardFailure> {
+        let shard_id = ShardId::new(self.index.to_string());
+        let result = loop {
+            let message = match client.next_message().await {
+                Ok(message) => message,
+                Err(ClientError::Message(e)) => {
+                    tracing::warn!("shard {shard_id}: skipping message: {e}");
+                    continue;
+                }
+                Err(e) => break Err(e.into()),
+            };
+
+            if let WebSocketMessage::Welcome(welcome) = &message {
+                let session_id = welcome.payload.session.id.clone();
+                self.sessions
+                    .lock()
+                    .unwrap()
+                    .insert(self.index, session_id.clone());
+                if let Err(e) = attach(&self.api, &self.conduit_id, self.index, session_id).await {
+                    break Err(e.into());
+                }
+            }
+
+            if self.messages.send((shard_id.clone(), message)).is_err() {
+                break Ok(());
+            }
+        };
+
+        self.sessions.lock().unwrap().remove(&self.index);
+        result
+    }
+}
+
+async fn attach(
+    api: &Api,
+    conduit_id: &ConduitId,
+    index: usize,
+    session_id: SessionId,
+) -> Result<(), SubscribeError> {
+    let request = api.update_conduit_shards(UpdateConduitShardsRequest {
+        conduit_id: conduit_id.clone(),
+        shards: vec![ShardUpdate::new(
+            index.to_string(),
+            Transport::websocket(session_id),
+        )],
+    });
+
+    // The response is what says whether the shard was updated, so it has to decode.
+    let result = match fetch(request).await {
+        Ok(response) => match response.errors.into_iter().next() {
+            Some(error) => Err(SubscribeError::ShardRejected {
+                code: error.code,
+                message: error.message,
+            }),
+            None => Ok(()),
+        },
+        Err(e) => Err(e.into()),
+    };
+
+    if let Err(e) = &result {
+        tracing::error!("failed to attach shard {index}: {e}");
+    }
+    result
+}
@@ -0,0 +1,99 @@
+use crate::twitch::{
+    events::automod::{
+        MessageHoldEvent, MessageHoldV2Event, MessageUpdateEvent, MessageUpdateV2Event,
+        SettingsUpdateEvent, TermsUpdateEvent,
+    },
+    types::BroadcasterModeratorCondition,
+};
+
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#automodmessagehold>
+    AutomodMessageHoldRequest,
+    AutomodMessageHold,
+    BroadcasterModeratorCondition {
+        broadcaster_user_id,
+        moderator_user_id
+    }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#automodmessagehold-v2>
+    AutomodMessageHoldV2Request,
+    AutomodMessageHoldV2,
+    BroadcasterModeratorCondition {
+        broadcaster_user_id,
+        moderator_user_id
+    }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#automodmessageupdate>
+    AutomodMessageUpdateRequest,
+    AutomodMessageUpdate,
+    BroadcasterModeratorCondition {
+        broadcaster_user_id,
+        moderator_user_id
+    }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#automodmessageupdate-v2>
+    AutomodMessageUpdateV2Request,
+    AutomodMessageUpdateV2,
+    BroadcasterModeratorCondition {
+        broadcaster_user_id,
+        moderator_user_id
+    }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#automodsettingsupdate>
+    AutomodSettingsUpdateRequest,
+    AutomodSettingsUpdate,
+    BroadcasterModeratorCondition {
+        broadcaster_user_id,
+        moderator_user_id
+    }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#automodtermsupdate>
+    AutomodTermsUpdateRequest,
+    AutomodTermsUpdate,
+    BroadcasterModeratorCondition {
+        broadcaster_user_id,
+        moderator_user_id
+    }
+);
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-message-hold-event>
+    AutomodMessageHoldPayload,
+    MessageHoldEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-message-hold-v2-event>
+    AutomodMessageHoldV2Payload,
+    MessageHoldV2Event,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-message-update-event>
+    AutomodMessageUpdatePayload,
+    MessageUpdateEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-message-update-v2-event>
+    AutomodMessageUpdateV2Payload,
+    MessageUpdateV2Event,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-settings-update-event>
+    AutomodSettingsUpdatePayload,
+    SettingsUpdateEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#automod-terms-update-event>
+    AutomodTermsUpdatePayload,
+    TermsUpdateEvent,
+    BroadcasterModeratorCondition
+);
@@ -0,0 +1,8 @@
+use crate::twitch::{events::ad_break::AdBreakBeginEvent, types::AdBreakBeginCondition};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-ad-break-begin-event>
+    ChannelAdBreakBeginPayload,
+    AdBreakBeginEvent,
+    AdBreakBeginCondition
+);
@@ -0,0 +1,57 @@
+use crate::twitch::{
+    events::chat::{
+        ChatClearEvent, ChatClearUserMessagesEvent, ChatMessageDeleteEvent, ChatMessageEvent,
+        ChatNotificationEvent, ChatSettingsUpdateEvent, ChatUserMessageHoldEvent,
+        ChatUserMessageUpdateEvent,
+    },
+    types::BroadcasterUserCondition,
+};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-clear-event>
+    ChannelChatClearPayload,
+    ChatClearEvent,
+    BroadcasterUserCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-clear-user-messages-event>
+    ChannelChatClearUserMessagesPayload,
+    ChatClearUserMessagesEvent,
+    BroadcasterUserCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-message-event>
+    ChannelChatMessagePayload,
+    ChatMessageEvent,
+    BroadcasterUserCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-message-delete-event>
+    ChannelChatMessageDeletePayload,
+    ChatMessageDeleteEvent,
+    BroadcasterUserCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-notification-event>
+    ChannelChatNotificationPayload,
+    ChatNotificationEvent,
+    BroadcasterUserCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-settings-update-event>
+    ChannelChatSettingsUpdatePayload,
+    ChatSettingsUpdateEvent,
+    BroadcasterUserCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-user-message-hold-event>
+    ChannelChatUserMessageHoldPayload,
+    ChatUserMessageHoldEvent,
+    BroadcasterUserCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-chat-user-message-update-event>
+    ChannelChatUserMessageUpdatePayload,
+    ChatUserMessageUpdateEvent,
+    BroadcasterUserCondition
+);
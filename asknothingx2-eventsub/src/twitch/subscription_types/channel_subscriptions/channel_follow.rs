@@ -0,0 +1,18 @@
+use crate::twitch::events::channel::ChannelFollowEvent;
+
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelfollow>
+    ChannelFollowRequest,
+    ChannelFollow,
+    BroadcasterModeratorCondition {
+        broadcaster_user_id,
+        moderator_user_id
+    }
+);
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-follow-event>
+    ChannelFollowPayload,
+    ChannelFollowEvent,
+    crate::twitch::types::BroadcasterModeratorCondition
+);
@@ -0,0 +1,32 @@
+use crate::twitch::{
+    events::guest_star::{
+        GuestStarGuestUpdateEvent, GuestStarSessionBeginEvent, GuestStarSessionEndEvent,
+        GuestStarSettingsUpdateEvent,
+    },
+    types::BroadcasterModeratorCondition,
+};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-guest-star-session-begin-event>
+    ChannelGuestStarSessionBeginPayload,
+    GuestStarSessionBeginEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-guest-star-session-end-event>
+    ChannelGuestStarSessionEndPayload,
+    GuestStarSessionEndEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-guest-star-guest-update-event>
+    ChannelGuestStarGuestUpdatePayload,
+    GuestStarGuestUpdateEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-guest-star-settings-update-event>
+    ChannelGuestStarSettingsUpdatePayload,
+    GuestStarSettingsUpdateEvent,
+    BroadcasterModeratorCondition
+);
@@ -0,0 +1,81 @@
+use crate::twitch::{
+    events::moderation::{
+        ChannelBanEvent, ChannelModerateEvent, ChannelUnbanEvent, ModeratorEvent,
+        UnbanRequestCreateEvent, UnbanRequestResolveEvent, VipEvent, WarningAcknowledgeEvent,
+        WarningSendEvent,
+    },
+    types::{BroadcasterCondition, BroadcasterModeratorCondition},
+};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-ban-event>
+    ChannelBanPayload,
+    ChannelBanEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-unban-event>
+    ChannelUnbanPayload,
+    ChannelUnbanEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-unban-request-create-event>
+    ChannelUnbanRequestCreatePayload,
+    UnbanRequestCreateEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-unban-request-resolve-event>
+    ChannelUnbanRequestResolvePayload,
+    UnbanRequestResolveEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-moderate-event>
+    ChannelModeratePayload,
+    ChannelModerateEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-moderate-event-v2>
+    ChannelModerateV2Payload,
+    ChannelModerateEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-moderator-add-event>
+    ChannelModeratorAddPayload,
+    ModeratorEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-moderator-remove-event>
+    ChannelModeratorRemovePayload,
+    ModeratorEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-vip-add-event>
+    ChannelVIPAddPayload,
+    VipEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-vip-remove-event>
+    ChannelVIPRemovePayload,
+    VipEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-warning-acknowledge-event>
+    ChannelWarningAcknowledgementPayload,
+    WarningAcknowledgeEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-warning-send-event>
+    ChannelWarningSendPayload,
+    WarningSendEvent,
+    BroadcasterModeratorCondition
+);
@@ -0,0 +1,43 @@
+use crate::twitch::{
+    events::channel_points::{
+        AutomaticRewardRedemptionEvent, CustomRewardEvent, CustomRewardRedemptionEvent,
+    },
+    types::RewardCondition,
+};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-points-automatic-reward-redemption-add-event>
+    ChannelPointsAutomaticRewardRedemptionPayload,
+    AutomaticRewardRedemptionEvent,
+    RewardCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-points-custom-reward-add-event>
+    ChannelPointsCustomRewardAddPayload,
+    CustomRewardEvent,
+    RewardCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-points-custom-reward-update-event>
+    ChannelPointsCustomRewardUpdatePayload,
+    CustomRewardEvent,
+    RewardCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-points-custom-reward-remove-event>
+    ChannelPointsCustomRewardRemovePayload,
+    CustomRewardEvent,
+    RewardCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-points-custom-reward-redemption-add-event>
+    ChannelPointsCustomRewardRedemptionAddPayload,
+    CustomRewardRedemptionEvent,
+    RewardCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-points-custom-reward-redemption-update-event>
+    ChannelPointsCustomRewardRedemptionUpdatePayload,
+    CustomRewardRedemptionEvent,
+    RewardCondition
+);
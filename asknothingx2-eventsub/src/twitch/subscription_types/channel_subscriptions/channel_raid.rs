@@ -0,0 +1,41 @@
+use crate::twitch::{
+    events::channel::ChannelRaidEvent,
+    types::{BroadcasterUserId, ChannelRaidCondition, Transport},
+};
+
+new_request!(
+    /// Twitch requires exactly one of the broadcasters, so there is a
+    /// constructor for each.
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelraid>
+    ChannelRaidRequest,
+    ChannelRaid,
+    ChannelRaidCondition { .. }
+);
+
+impl ChannelRaidRequest {
+    /// Notifies when `from_broadcaster_user_id` raids another channel.
+    pub fn from_broadcaster(
+        from_broadcaster_user_id: BroadcasterUserId,
+        transport: Transport,
+    ) -> Self {
+        Self::new(
+            ChannelRaidCondition::default().from_broadcaster_user_id(from_broadcaster_user_id),
+            transport,
+        )
+    }
+
+    /// Notifies when another channel raids `to_broadcaster_user_id`.
+    pub fn to_broadcaster(to_broadcaster_user_id: BroadcasterUserId, transport: Transport) -> Self {
+        Self::new(
+            ChannelRaidCondition::default().to_broadcaster_user_id(to_broadcaster_user_id),
+            transport,
+        )
+    }
+}
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-raid-event>
+    ChannelRaidPayload,
+    ChannelRaidEvent,
+    crate::twitch::types::ChannelRaidCondition
+);
@@ -0,0 +1,23 @@
+use crate::twitch::{
+    events::shared_chat::{SharedChatSessionEndEvent, SharedChatSessionEvent},
+    types::BroadcasterCondition,
+};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-shared-chat-begin-event>
+    ChannelSharedChatSessionBeginPayload,
+    SharedChatSessionEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-shared-chat-update-event>
+    ChannelSharedChatSessionUpdatePayload,
+    SharedChatSessionEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-shared-chat-end-event>
+    ChannelSharedChatSessionEndPayload,
+    SharedChatSessionEndEvent,
+    BroadcasterCondition
+);
@@ -0,0 +1,17 @@
+use crate::twitch::{
+    events::shoutout::{ShoutoutCreateEvent, ShoutoutReceivedEvent},
+    types::BroadcasterModeratorCondition,
+};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-shoutout-create-event>
+    ShoutoutCreatePayload,
+    ShoutoutCreateEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-shoutout-receive-event>
+    ShoutoutReceivedPayload,
+    ShoutoutReceivedEvent,
+    BroadcasterModeratorCondition
+);
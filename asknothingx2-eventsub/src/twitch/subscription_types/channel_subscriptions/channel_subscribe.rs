@@ -0,0 +1,38 @@
+use crate::twitch::{
+    events::{
+        cheer::CheerEvent,
+        subscribe::{SubscribeEvent, SubscriptionGiftEvent, SubscriptionMessageEvent},
+    },
+    types::BroadcasterCondition,
+};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-subscribe-event>
+    ChannelSubscribePayload,
+    SubscribeEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-subscription-end-event>
+    ChannelSubscriptionEndPayload,
+    SubscribeEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-subscription-gift-event>
+    ChannelSubscriptionGiftPayload,
+    SubscriptionGiftEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-subscription-message-event>
+    ChannelSubscriptionMessagePayload,
+    SubscriptionMessageEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-cheer-event>
+    ChannelCheerPayload,
+    CheerEvent,
+    BroadcasterCondition
+);
@@ -0,0 +1,17 @@
+use crate::twitch::{
+    events::suspicious_user::{SuspiciousUserMessageEvent, SuspiciousUserUpdateEvent},
+    types::BroadcasterModeratorCondition,
+};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-suspicious-user-message-event>
+    ChannelSuspiciousUserMessagePayload,
+    SuspiciousUserMessageEvent,
+    BroadcasterModeratorCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-suspicious-user-update-event>
+    ChannelSuspiciousUserUpdatePayload,
+    SuspiciousUserUpdateEvent,
+    BroadcasterModeratorCondition
+);
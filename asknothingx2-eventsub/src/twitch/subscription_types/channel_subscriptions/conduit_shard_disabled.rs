@@ -0,0 +1,22 @@
+use crate::twitch::events::conduit_shard::ConduitShardDisabledEvent;
+
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#conduitsharddisabled>
+    webhook ConduitShardDisabledRequest,
+    ConduitShardDisabled,
+    ConduitShardDisabledCondition { client_id }
+);
+
+impl ConduitShardDisabledRequest {
+    pub fn set_conduit_id(mut self, conduit_id: impl Into<String>) -> Self {
+        self.0.condition.conduit_id = Some(conduit_id.into());
+        self
+    }
+}
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#conduit-shard-disabled-event>
+    ConduitShardDisabledPayload,
+    ConduitShardDisabledEvent,
+    crate::twitch::types::ConduitShardDisabledCondition
+);
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "twitch-webhook")]
+use crate::twitch::types::{OrganizationId, SubscriptionType, Transport};
+use crate::twitch::{
+    events::drop_entitlement::DropEntitlementGrantEvent,
+    request::CreateEventSubRequest,
+    subscription_types::request::IntoSubscriptionRequest,
+    types::{CampaignId, CategoryId, DropEntitlementGrantCondition, Subscription},
+};
+
+/// Drop entitlements are only delivered in batches over webhooks.
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#dropentitlementgrant>
+#[derive(Clone, Debug, Serialize)]
+pub struct DropEntitlementGrantRequest {
+    #[serde(flatten)]
+    pub request: CreateEventSubRequest<DropEntitlementGrantCondition>,
+    is_batching_enabled: String,
+}
+
+impl DropEntitlementGrantRequest {
+    #[cfg(feature = "twitch-webhook")]
+    pub fn webhook(
+        organization_id: OrganizationId,
+        callback: impl Into<String>,
+        secret: Option<&str>,
+    ) -> Self {
+        Self {
+            request: CreateEventSubRequest::new(
+                SubscriptionType::DropEntitlementGrant,
+                DropEntitlementGrantCondition::new(organization_id),
+                Transport::webhook(callback, secret.map(Into::into)),
+            ),
+            is_batching_enabled: "true".to_string(),
+        }
+    }
+
+    pub fn set_category_id(mut self, category_id: CategoryId) -> Self {
+        self.request.condition.category_id = Some(category_id.into());
+        self
+    }
+
+    pub fn set_campaign_id(mut self, campaign_id: CampaignId) -> Self {
+        self.request.condition.campaign_id = Some(campaign_id.into());
+        self
+    }
+}
+
+impl IntoSubscriptionRequest for DropEntitlementGrantRequest {}
+
+/// Unlike other notifications, the payload holds a batch of `events`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DropEntitlementGrantPayload {
+    pub subscription: Subscription<DropEntitlementGrantCondition>,
+    pub events: Vec<DropEntitlementGrantEvent>,
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#drop-entitlement-grant-event>
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DropEntitlementGrantPayloadNotification {
+    pub payload: DropEntitlementGrantPayload,
+}
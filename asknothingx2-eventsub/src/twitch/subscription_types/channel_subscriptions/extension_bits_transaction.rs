@@ -0,0 +1,15 @@
+use crate::twitch::events::bits_transaction::BitsTransactionEvent;
+
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#extensionbits_transactioncreate>
+    webhook ExtensionBitsTransactionRequest,
+    ExtensionBitsTransactionCreate,
+    ExtensionBitsTransactionCondition { extension_client_id }
+);
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#extension-bits-transaction-create-event>
+    ExtensionBitsTransactionPayload,
+    BitsTransactionEvent,
+    crate::twitch::types::ExtensionBitsTransactionCondition
+);
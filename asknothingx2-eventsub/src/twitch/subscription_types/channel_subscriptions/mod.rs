@@ -0,0 +1,462 @@
+mod channel_ad_break;
+mod channel_chat;
+mod channel_follow;
+mod channel_guest_star;
+mod channel_moderation;
+mod channel_points;
+mod channel_raid;
+mod channel_shared_chat;
+mod channel_shoutout;
+mod channel_subscribe;
+mod channel_suspicious_user;
+mod conduit_shard_disabled;
+pub mod drop_entitlement_grant;
+mod extension_bits_transaction;
+mod poll_prediction;
+
+pub use channel_ad_break::ChannelAdBreakBeginPayload;
+pub use channel_chat::{
+    ChannelChatClearPayload, ChannelChatClearUserMessagesPayload, ChannelChatMessageDeletePayload,
+    ChannelChatMessagePayload, ChannelChatNotificationPayload, ChannelChatSettingsUpdatePayload,
+    ChannelChatUserMessageHoldPayload, ChannelChatUserMessageUpdatePayload,
+};
+pub use channel_follow::{ChannelFollowPayload, ChannelFollowRequest};
+pub use channel_guest_star::{
+    ChannelGuestStarGuestUpdatePayload, ChannelGuestStarSessionBeginPayload,
+    ChannelGuestStarSessionEndPayload, ChannelGuestStarSettingsUpdatePayload,
+};
+pub use channel_moderation::{
+    ChannelBanPayload, ChannelModeratePayload, ChannelModerateV2Payload,
+    ChannelModeratorAddPayload, ChannelModeratorRemovePayload, ChannelUnbanPayload,
+    ChannelUnbanRequestCreatePayload, ChannelUnbanRequestResolvePayload, ChannelVIPAddPayload,
+    ChannelVIPRemovePayload, ChannelWarningAcknowledgementPayload, ChannelWarningSendPayload,
+};
+pub use channel_points::{
+    ChannelPointsAutomaticRewardRedemptionPayload, ChannelPointsCustomRewardAddPayload,
+    ChannelPointsCustomRewardRedemptionAddPayload,
+    ChannelPointsCustomRewardRedemptionUpdatePayload, ChannelPointsCustomRewardRemovePayload,
+    ChannelPointsCustomRewardUpdatePayload,
+};
+pub use channel_raid::{ChannelRaidPayload, ChannelRaidRequest};
+pub use channel_shared_chat::{
+    ChannelSharedChatSessionBeginPayload, ChannelSharedChatSessionEndPayload,
+    ChannelSharedChatSessionUpdatePayload,
+};
+pub use channel_shoutout::{ShoutoutCreatePayload, ShoutoutReceivedPayload};
+pub use channel_subscribe::{
+    ChannelCheerPayload, ChannelSubscribePayload, ChannelSubscriptionEndPayload,
+    ChannelSubscriptionGiftPayload, ChannelSubscriptionMessagePayload,
+};
+pub use channel_suspicious_user::{
+    ChannelSuspiciousUserMessagePayload, ChannelSuspiciousUserUpdatePayload,
+};
+pub use conduit_shard_disabled::{ConduitShardDisabledPayload, ConduitShardDisabledRequest};
+pub use drop_entitlement_grant::DropEntitlementGrantRequest;
+pub use extension_bits_transaction::{
+    ExtensionBitsTransactionPayload, ExtensionBitsTransactionRequest,
+};
+pub use poll_prediction::{
+    ChannelPollBeginPayload, ChannelPollEndPayload, ChannelPollProgressPayload,
+    ChannelPredictionBeginPayload, ChannelPredictionEndPayload, ChannelPredictionLockPayload,
+    ChannelPredictionProgressPayload,
+};
+
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelupdate>
+    ChannelUpdateRequest,
+    ChannelUpdate,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelad_breakbegin>
+    ChannelAdBreakBeginRequest,
+    ChannelAdBreakBegin,
+    AdBreakBeginCondition { broadcaster_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatclear>
+    ChannelChatClearRequest,
+    ChannelChatClear,
+    BroadcasterUserCondition { broadcaster_user_id, user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatclear_user_messages>
+    ChannelChatClearUserMessagesRequest,
+    ChannelChatClearUserMessages,
+    BroadcasterUserCondition { broadcaster_user_id, user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatmessage>
+    ChannelChatMessageRequest,
+    ChannelChatMessage,
+    BroadcasterUserCondition { broadcaster_user_id, user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatmessage_delete>
+    ChannelChatMessageDeleteRequest,
+    ChannelChatMessageDelete,
+    BroadcasterUserCondition { broadcaster_user_id, user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatnotification>
+    ChannelChatNotificationRequest,
+    ChannelChatNotification,
+    BroadcasterUserCondition { broadcaster_user_id, user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchat_settingsupdate>
+    ChannelChatSettingsUpdateRequest,
+    ChannelChatSettingsUpdate,
+    BroadcasterUserCondition { broadcaster_user_id, user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatuser_message_hold>
+    ChannelChatUserMessageHoldRequest,
+    ChannelChatUserMessageHold,
+    BroadcasterUserCondition { broadcaster_user_id, user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatuser_message_update>
+    ChannelChatUserMessageUpdateRequest,
+    ChannelChatUserMessageUpdate,
+    BroadcasterUserCondition { broadcaster_user_id, user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshared_chatbegin>
+    ChannelSharedChatSessionBeginRequest,
+    ChannelSharedChatSessionBegin,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshared_chatupdate>
+    ChannelSharedChatSessionUpdateRequest,
+    ChannelSharedChatSessionUpdate,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshared_chatend>
+    ChannelSharedChatSessionEndRequest,
+    ChannelSharedChatSessionEnd,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelsubscribe>
+    ChannelSubscribeRequest,
+    ChannelSubscribe,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelsubscriptionend>
+    ChannelSubscriptionEndRequest,
+    ChannelSubscriptionEnd,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelsubscriptiongift>
+    ChannelSubscriptionGiftRequest,
+    ChannelSubscriptionGift,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelsubscriptionmessage>
+    ChannelSubscriptionMessageRequest,
+    ChannelSubscriptionMessage,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelcheer>
+    ChannelCheerRequest,
+    ChannelCheer,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelban>
+    ChannelBanRequest,
+    ChannelBan,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelunban>
+    ChannelUnbanRequest,
+    ChannelUnban,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelunban_requestcreate>
+    ChannelUnbanRequestCreateRequest,
+    ChannelUnbanRequestCreate,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelunban_requestresolve>
+    ChannelUnbanRequestResolveRequest,
+    ChannelUnbanRequestResolve,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelmoderate>
+    ChannelModerateRequest,
+    ChannelModerate,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelmoderate-v2>
+    ChannelModerateV2Request,
+    ChannelModerateV2,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelmoderatoradd>
+    ChannelModeratorAddRequest,
+    ChannelModeratorAdd,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelmoderatorremove>
+    ChannelModeratorRemoveRequest,
+    ChannelModeratorRemove,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelguest_star_sessionbegin>
+    ChannelGuestStarSessionBeginRequest,
+    ChannelGuestStarSessionBegin,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelguest_star_sessionend>
+    ChannelGuestStarSessionEndRequest,
+    ChannelGuestStarSessionEnd,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelguest_star_guestupdate>
+    ChannelGuestStarGuestUpdateRequest,
+    ChannelGuestStarGuestUpdate,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelguest_star_settingsupdate>
+    ChannelGuestStarSettingsUpdateRequest,
+    ChannelGuestStarSettingsUpdate,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchannel_points_automatic_reward_redemptionadd>
+    ChannelPointsAutomaticRewardRedemptionRequest,
+    ChannelPointsAutomaticRewardRedemption,
+    RewardCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchannel_points_custom_rewardadd>
+    ChannelPointsCustomRewardAddRequest,
+    ChannelPointsCustomRewardAdd,
+    RewardCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchannel_points_custom_rewardupdate>
+    ChannelPointsCustomRewardUpdateRequest,
+    ChannelPointsCustomRewardUpdate,
+    RewardCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchannel_points_custom_rewardremove>
+    ChannelPointsCustomRewardRemoveRequest,
+    ChannelPointsCustomRewardRemove,
+    RewardCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchannel_points_custom_reward_redemptionadd>
+    ChannelPointsCustomRewardRedemptionAddRequest,
+    ChannelPointsCustomRewardRedemptionAdd,
+    RewardCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchannel_points_custom_reward_redemptionupdate>
+    ChannelPointsCustomRewardRedemptionUpdateRequest,
+    ChannelPointsCustomRewardRedemptionUpdate,
+    RewardCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelpollbegin>
+    ChannelPollBeginRequest,
+    ChannelPollBegin,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelpollprogress>
+    ChannelPollProgressRequest,
+    ChannelPollProgress,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelpollend>
+    ChannelPollEndRequest,
+    ChannelPollEnd,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelpredictionbegin>
+    ChannelPredictionBeginRequest,
+    ChannelPredictionBegin,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelpredictionprogress>
+    ChannelPredictionProgressRequest,
+    ChannelPredictionProgress,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelpredictionlock>
+    ChannelPredictionLockRequest,
+    ChannelPredictionLock,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelpredictionend>
+    ChannelPredictionEndRequest,
+    ChannelPredictionEnd,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelsuspicious_usermessage>
+    ChannelSuspiciousUserMessageRequest,
+    ChannelSuspiciousUserMessage,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelsuspicious_userupdate>
+    ChannelSuspiciousUserUpdateRequest,
+    ChannelSuspiciousUserUpdate,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelvipadd>
+    ChannelVIPAddRequest,
+    ChannelVIPAdd,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelvipremove>
+    ChannelVIPRemoveRequest,
+    ChannelVIPRemove,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelwarningacknowledge>
+    ChannelWarningAcknowledgementRequest,
+    ChannelWarningAcknowledgement,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelwarningsend>
+    ChannelWarningSendRequest,
+    ChannelWarningSend,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelcharity_campaigndonate>
+    CharityDonationRequest,
+    CharityDonation,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelcharity_campaignstart>
+    CharityCampaignStartRequest,
+    CharityCampaignStart,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelcharity_campaignprogress>
+    CharityCampaignProgressRequest,
+    CharityCampaignProgress,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelcharity_campaignstop>
+    CharityCampaignStopRequest,
+    CharityCampaignStop,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelgoalbegin>
+    GoalBeginRequest,
+    GoalBegin,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelgoalprogress>
+    GoalProgressRequest,
+    GoalProgress,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelgoalend>
+    GoalEndRequest,
+    GoalEnd,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelhype_trainbegin>
+    HypeTrainBeginRequest,
+    HypeTrainBegin,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelhype_trainprogress>
+    HypeTrainProgressRequest,
+    HypeTrainProgress,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelhype_trainend>
+    HypeTrainEndRequest,
+    HypeTrainEnd,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshield_modebegin>
+    ShieldModeBeginRequest,
+    ShieldModeBegin,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshield_modeend>
+    ShieldModeEndRequest,
+    ShieldModeEnd,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshoutoutcreate>
+    ShoutoutCreateRequest,
+    ShoutoutCreate,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelshoutoutreceive>
+    ShoutoutReceivedRequest,
+    ShoutoutReceived,
+    BroadcasterModeratorCondition { broadcaster_user_id, moderator_user_id }
+);
+
+macro_rules! reward_id {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                /// Only notifies for the reward with this ID.
+                pub fn set_reward_id(mut self, reward_id: impl Into<String>) -> Self {
+                    self.0.condition.reward_id = Some(reward_id.into());
+                    self
+                }
+            }
+        )+
+    };
+}
+
+reward_id!(
+    ChannelPointsCustomRewardUpdateRequest,
+    ChannelPointsCustomRewardRemoveRequest,
+    ChannelPointsCustomRewardRedemptionAddRequest,
+    ChannelPointsCustomRewardRedemptionUpdateRequest
+);
@@ -0,0 +1,50 @@
+use crate::twitch::{
+    events::{
+        poll::{PollEndEvent, PollEvent},
+        prediction::{PredictionEndEvent, PredictionEvent, PredictionLockEvent},
+    },
+    types::BroadcasterCondition,
+};
+
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-poll-begin-event>
+    ChannelPollBeginPayload,
+    PollEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-poll-progress-event>
+    ChannelPollProgressPayload,
+    PollEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-poll-end-event>
+    ChannelPollEndPayload,
+    PollEndEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-prediction-begin-event>
+    ChannelPredictionBeginPayload,
+    PredictionEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-prediction-progress-event>
+    ChannelPredictionProgressPayload,
+    PredictionEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-prediction-lock-event>
+    ChannelPredictionLockPayload,
+    PredictionLockEvent,
+    BroadcasterCondition
+);
+new_payload!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-prediction-end-event>
+    ChannelPredictionEndPayload,
+    PredictionEndEvent,
+    BroadcasterCondition
+);
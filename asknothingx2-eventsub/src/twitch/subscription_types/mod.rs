@@ -1 +1,161 @@
+//! A request type per [`SubscriptionType`] whose constructors take exactly the
+//! condition fields that type requires.
+//! <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/>
+pub use crate::twitch::types::SubscriptionType;
+
+/// The ID type a request constructor takes for a condition field, so that
+/// e.g. a moderator can't be passed where the broadcaster is expected.
+#[allow(unused_macros)]
+macro_rules! condition_id {
+    (broadcaster_user_id) => {
+        $crate::twitch::types::BroadcasterUserId
+    };
+    (broadcaster_id) => {
+        $crate::twitch::types::BroadcasterUserId
+    };
+    (moderator_user_id) => {
+        $crate::twitch::types::ModeratorUserId
+    };
+    (user_id) => {
+        $crate::twitch::types::UserId
+    };
+    (client_id) => {
+        $crate::twitch::types::ClientId
+    };
+    (extension_client_id) => {
+        $crate::twitch::types::ExtensionClientId
+    };
+}
+
+/// `webhook` before the name limits a type to webhook constructors, for types
+/// Twitch only delivers over webhooks. `{ .. }` instead of the required fields
+/// leaves the constructors to the type.
+macro_rules! new_request {
+    (
+        $(#[$meta:meta])*
+        webhook $name:ident, $kind:ident, $condition:ident { $($require:ident),* $(,)? }
+    ) => {
+        new_request!(@request $(#[$meta])* $name, $kind, $condition);
+        new_request!(@webhook $name, $condition { $($require),* });
+    };
+    (
+        $(#[$meta:meta])*
+        $name:ident, $kind:ident, $condition:ident { .. }
+    ) => {
+        new_request!(@request $(#[$meta])* $name, $kind, $condition);
+    };
+    (
+        $(#[$meta:meta])*
+        $name:ident, $kind:ident, $condition:ident { $($require:ident),* $(,)? }
+    ) => {
+        new_request!(@request $(#[$meta])* $name, $kind, $condition);
+        new_request!(@webhook $name, $condition { $($require),* });
+
+        impl $name {
+            #[cfg(feature = "twitch-websocket")]
+            pub fn websocket(
+                $($require: condition_id!($require),)*
+                session_id: $crate::twitch::types::SessionId,
+            ) -> Self {
+                Self::new(
+                    $crate::twitch::types::$condition::new($($require),*),
+                    $crate::twitch::types::Transport::websocket(session_id),
+                )
+            }
+
+            #[cfg(feature = "twitch-conduit")]
+            pub fn conduit(
+                $($require: condition_id!($require),)*
+                conduit_id: $crate::twitch::types::ConduitId,
+            ) -> Self {
+                Self::new(
+                    $crate::twitch::types::$condition::new($($require),*),
+                    $crate::twitch::types::Transport::conduit(conduit_id),
+                )
+            }
+        }
+    };
+    (@webhook $name:ident, $condition:ident { $($require:ident),* }) => {
+        impl $name {
+            #[cfg(feature = "twitch-webhook")]
+            pub fn webhook(
+                $($require: condition_id!($require),)*
+                callback: impl Into<String>,
+                secret: Option<&str>,
+            ) -> Self {
+                Self::new(
+                    $crate::twitch::types::$condition::new($($require),*),
+                    $crate::twitch::types::Transport::webhook(callback, secret.map(Into::into)),
+                )
+            }
+        }
+    };
+    (@request $(#[$meta:meta])* $name:ident, $kind:ident, $condition:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, serde::Serialize)]
+        pub struct $name(
+            pub $crate::twitch::request::CreateEventSubRequest<$crate::twitch::types::$condition>,
+        );
+
+        impl $name {
+            #[allow(dead_code)]
+            fn new(
+                condition: $crate::twitch::types::$condition,
+                transport: $crate::twitch::types::Transport,
+            ) -> Self {
+                Self($crate::twitch::request::CreateEventSubRequest::new(
+                    $crate::twitch::types::SubscriptionType::$kind,
+                    condition,
+                    transport,
+                ))
+            }
+        }
+
+        impl From<$name>
+            for $crate::twitch::request::CreateEventSubRequest<$crate::twitch::types::$condition>
+        {
+            fn from(request: $name) -> Self {
+                request.0
+            }
+        }
+
+        impl From<$name>
+            for $crate::twitch::request::CreateEventSubRequest<$crate::twitch::types::Condition>
+        {
+            fn from(request: $name) -> Self {
+                let request = request.0;
+                Self {
+                    kind: request.kind,
+                    version: request.version,
+                    condition: request.condition.into(),
+                    transport: request.transport,
+                }
+            }
+        }
+
+        impl $crate::twitch::subscription_types::request::IntoSubscriptionRequest for $name {}
+    };
+}
+
+macro_rules! new_payload {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $event:ty $(, $condition:ty)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        #[serde(transparent)]
+        pub struct $name {
+            pub payload: $crate::twitch::types::payloads::SubscriptionEventPayload<
+                $event
+                $(, $condition)?
+            >,
+        }
+    };
+}
+
+pub mod automod;
+pub mod channel_subscriptions;
+pub mod request;
+pub mod stream;
 pub mod user;
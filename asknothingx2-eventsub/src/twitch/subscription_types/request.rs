@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+/// A typed Create EventSub Subscription request.
+/// <https://dev.twitch.tv/docs/api/reference/#create-eventsub-subscription>
+pub trait IntoSubscriptionRequest: Serialize {
+    /// The JSON request body.
+    fn into_body(self) -> String
+    where
+        Self: Sized,
+    {
+        serde_json::to_string(&self).unwrap()
+    }
+}
@@ -0,0 +1,12 @@
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#streamonline>
+    StreamOnlineRequest,
+    StreamOnline,
+    BroadcasterCondition { broadcaster_user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#streamoffline>
+    StreamOfflineRequest,
+    StreamOffline,
+    BroadcasterCondition { broadcaster_user_id }
+);
@@ -1 +1,26 @@
 pub mod authorization_grant_event;
+
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#userauthorizationgrant>
+    UserAuthorizationGrantRequest,
+    UserAuthorizationGrant,
+    UserAuthorizationCondition { client_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#userauthorizationrevoke>
+    UserAuthorizationRevokeRequest,
+    UserAuthorizationRevoke,
+    UserAuthorizationCondition { client_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#userupdate>
+    UserUpdateRequest,
+    UserUpdate,
+    UserCondition { user_id }
+);
+new_request!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#userwhispermessage>
+    WhisperReceivedRequest,
+    WhisperReceived,
+    UserCondition { user_id }
+);
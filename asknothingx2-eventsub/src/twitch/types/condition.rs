@@ -1,82 +1,383 @@
-use asknothingx2_util::oauth::ClientId;
-use serde::{Deserialize, Serialize};
-use twitch_highway::types::{BroadcasterId, ModeratorId, RewardId, UserId};
+//! <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#conditions>
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
-use super::{
-    new_types::{CampaignId, CategoryId, OrganizationId},
-    ConduitId, ExtensionClientId,
-};
+use super::SubscriptionType;
+
+/// Twitch sends optional fields a subscription doesn't use as `""`, so `""`
+/// decodes as `None`, like a missing or `null` field.
+fn empty_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.filter(|value| !value.is_empty()))
+}
 
 macro_rules! condition {
     (
-    $(#[$attr:meta])*
-    $name:ident {
-    $(
-        $(#[$type_attr:meta])*
-        $vis:vis $field:ident: $type:ty
-    ),* $(,)?
-    }
-) => {
-    $(#[$attr])*
-    pub struct $name {
-    $(
-        #[serde(skip_serializing_if = "Option::is_none")]
-        $(#[$type_attr])*
-        $vis $field: Option<$type>
-    ),*
-    }
-
-    impl $name {
-        pub fn new() -> Self {
-            Self::default()
+        $(#[$attr:meta])*
+        $name:ident => $variant:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field:ident
+            ),* $(,)?
+        }
+        $(optional {
+            $(
+                $(#[$optional_attr:meta])*
+                $optional:ident
+            ),* $(,)?
+        })?
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct $name {
+            $(
+                $(#[$field_attr])*
+                pub $field: String,
+            )*
+            $($(
+                $(#[$optional_attr])*
+                #[serde(
+                    default,
+                    deserialize_with = "empty_as_none",
+                    skip_serializing_if = "Option::is_none"
+                )]
+                pub $optional: Option<String>,
+            )*)?
         }
 
-        $(
-            $(#[$type_attr])*
-            pub fn $field(mut self, value: $type) -> Self {
-                self.$field = Some(value);
-                self
+        impl $name {
+            pub fn new($($field: impl Into<String>),*) -> Self {
+                Self {
+                    $($field: $field.into(),)*
+                    $($($optional: None,)*)?
+                }
             }
-        )*
-    }
-};
+
+            $($(
+                $(#[$optional_attr])*
+                pub fn $optional(mut self, value: impl Into<String>) -> Self {
+                    self.$optional = Some(value.into());
+                    self
+                }
+            )*)?
+        }
+
+        impl From<$name> for Condition {
+            fn from(condition: $name) -> Self {
+                Self::$variant(condition)
+            }
+        }
+    };
 }
+
+condition!(
+    /// e.g. `stream.online`, `channel.subscribe`, `channel.poll.begin`
+    BroadcasterCondition => Broadcaster {
+        broadcaster_user_id
+    }
+);
+
+condition!(
+    /// e.g. `channel.follow`, `channel.moderate`, `automod.message.hold`
+    BroadcasterModeratorCondition => BroadcasterModerator {
+        broadcaster_user_id,
+        moderator_user_id,
+    }
+);
+
+condition!(
+    /// `channel.chat.*` and `channel.chat_settings.update`; `user_id` is the
+    /// user reading chat.
+    BroadcasterUserCondition => BroadcasterUser {
+        broadcaster_user_id,
+        user_id,
+    }
+);
+
+condition!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-ad-break-begin-condition>
+    AdBreakBeginCondition => AdBreakBegin {
+        broadcaster_id
+    }
+);
+
+condition!(
+    /// Channel points rewards and redemptions.
+    RewardCondition => Reward {
+        broadcaster_user_id
+    }
+    optional {
+        /// Only notifies for this reward.
+        reward_id
+    }
+);
+
+condition!(
+    /// Set either `from_broadcaster_user_id` or `to_broadcaster_user_id`.
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-raid-condition>
+    #[derive(Default)]
+    ChannelRaidCondition => ChannelRaid {}
+    optional {
+        /// Notifies when this broadcaster raids another channel.
+        from_broadcaster_user_id,
+        /// Notifies when another channel raids this broadcaster.
+        to_broadcaster_user_id,
+    }
+);
+
+condition!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#conduit-shard-disabled-condition>
+    ConduitShardDisabledCondition => ConduitShardDisabled {
+        /// Your application’s client id.
+        client_id
+    }
+    optional {
+        /// Only notifies for shards of this conduit.
+        conduit_id
+    }
+);
+
 condition!(
-    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#conditions>
-    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
-    Condition {
-        pub broadcaster_user_id: BroadcasterId,
-        pub moderator_user_id: ModeratorId,
-        pub broadcaster_id: BroadcasterId,
-        pub user_id: UserId,
-        pub client_id: ClientId,
-
-        /// Channel Raid Condition
-        #[cfg(feature ="twitch-raid")]
-        pub from_broadcaster_user_id: BroadcasterId,
-        /// Channel Raid Condition
-        #[cfg(feature ="twitch-raid")]
-        pub to_broadcaster_user_id: BroadcasterId,
-
-        #[cfg(feature ="twitch-reward")]
-        pub reward_id: RewardId,
-
-        /// Conduit Shard Disabled Condition
-        #[cfg(feature ="twitch-conduit-shard")]
-        pub conduit_id: ConduitId,
-
-        /// Drop Entitlement Grant Condition
-        #[cfg(feature ="twitch-drop-entitlement")]
-        pub organization_id: OrganizationId,
-        /// Drop Entitlement Grant Condition
-        #[cfg(feature ="twitch-drop-entitlement")]
-        pub category_id: CategoryId,
-        /// Drop Entitlement Grant Condition
-        #[cfg(feature ="twitch-drop-entitlement")]
-        pub campaign_id: CampaignId,
-
-        /// Extension Bits Transaction Create Condition
-        #[cfg(feature="twitch-bits-transaction")]
-        pub extension_client_id: ExtensionClientId,
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#drop-entitlement-grant-condition>
+    DropEntitlementGrantCondition => DropEntitlementGrant {
+        /// The organization ID of the organization that owns the game on the developer portal.
+        organization_id
+    }
+    optional {
+        /// The category (or game) ID of the game for which entitlement notifications will be received.
+        category_id,
+        /// The campaign ID for a specific campaign for which entitlement notifications will be received.
+        campaign_id,
+    }
+);
+
+condition!(
+    /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#extension-bits-transaction-create-condition>
+    ExtensionBitsTransactionCondition => ExtensionBitsTransaction {
+        extension_client_id
+    }
+);
+
+condition!(
+    /// `user.authorization.grant` and `user.authorization.revoke`
+    UserAuthorizationCondition => UserAuthorization {
+        /// Your application’s client id.
+        client_id
+    }
+);
+
+condition!(
+    /// `user.update` and `user.whisper.message`
+    UserCondition => User {
+        user_id
     }
 );
+
+/// The condition of any subscription type.
+///
+/// Twitch doesn't tag conditions, so [`Subscription`](super::Subscription)
+/// picks the variant from its `type` with [`Condition::from_value`].
+/// The per-type structs reject missing or `null` required fields and fields
+/// they don't have, so a condition that doesn't fit stays [`Condition::Unknown`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Condition {
+    Broadcaster(BroadcasterCondition),
+    BroadcasterModerator(BroadcasterModeratorCondition),
+    BroadcasterUser(BroadcasterUserCondition),
+    AdBreakBegin(AdBreakBeginCondition),
+    Reward(RewardCondition),
+    ChannelRaid(ChannelRaidCondition),
+    ConduitShardDisabled(ConduitShardDisabledCondition),
+    DropEntitlementGrant(DropEntitlementGrantCondition),
+    ExtensionBitsTransaction(ExtensionBitsTransactionCondition),
+    UserAuthorization(UserAuthorizationCondition),
+    User(UserCondition),
+    /// The condition of a [`SubscriptionType::Unknown`], or one that doesn't
+    /// fit its type's family, kept as sent.
+    Unknown(Value),
+}
+
+impl Condition {
+    /// Decodes `value` as the condition of `kind`. A condition that doesn't fit
+    /// the family of `kind` is kept as [`Condition::Unknown`] rather than
+    /// failing the whole subscription.
+    pub fn from_value(kind: &SubscriptionType, value: Value) -> Self {
+        Self::typed(kind, value.clone()).unwrap_or(Self::Unknown(value))
+    }
+
+    fn typed(kind: &SubscriptionType, value: Value) -> Result<Self, serde_json::Error> {
+        use SubscriptionType::*;
+
+        Ok(match kind {
+            ChannelUpdate
+            | ChannelSharedChatSessionBegin
+            | ChannelSharedChatSessionUpdate
+            | ChannelSharedChatSessionEnd
+            | ChannelSubscribe
+            | ChannelSubscriptionEnd
+            | ChannelSubscriptionGift
+            | ChannelSubscriptionMessage
+            | ChannelCheer
+            | ChannelBan
+            | ChannelUnban
+            | ChannelModeratorAdd
+            | ChannelModeratorRemove
+            | ChannelPollBegin
+            | ChannelPollProgress
+            | ChannelPollEnd
+            | ChannelPredictionBegin
+            | ChannelPredictionProgress
+            | ChannelPredictionLock
+            | ChannelPredictionEnd
+            | ChannelVIPAdd
+            | ChannelVIPRemove
+            | CharityDonation
+            | CharityCampaignStart
+            | CharityCampaignProgress
+            | CharityCampaignStop
+            | GoalBegin
+            | GoalProgress
+            | GoalEnd
+            | HypeTrainBegin
+            | HypeTrainProgress
+            | HypeTrainEnd
+            | StreamOnline
+            | StreamOffline => Self::Broadcaster(serde_json::from_value(value)?),
+            AutomodMessageHold
+            | AutomodMessageHoldV2
+            | AutomodMessageUpdate
+            | AutomodMessageUpdateV2
+            | AutomodSettingsUpdate
+            | AutomodTermsUpdate
+            | ChannelFollow
+            | ChannelUnbanRequestCreate
+            | ChannelUnbanRequestResolve
+            | ChannelModerate
+            | ChannelModerateV2
+            | ChannelGuestStarSessionBegin
+            | ChannelGuestStarSessionEnd
+            | ChannelGuestStarGuestUpdate
+            | ChannelGuestStarSettingsUpdate
+            | ChannelSuspiciousUserMessage
+            | ChannelSuspiciousUserUpdate
+            | ChannelWarningAcknowledgement
+            | ChannelWarningSend
+            | ShieldModeBegin
+            | ShieldModeEnd
+            | ShoutoutCreate
+            | ShoutoutReceived => Self::BroadcasterModerator(serde_json::from_value(value)?),
+            ChannelChatClear
+            | ChannelChatClearUserMessages
+            | ChannelChatMessage
+            | ChannelChatMessageDelete
+            | ChannelChatNotification
+            | ChannelChatSettingsUpdate
+            | ChannelChatUserMessageHold
+            | ChannelChatUserMessageUpdate => Self::BroadcasterUser(serde_json::from_value(value)?),
+            ChannelAdBreakBegin => Self::AdBreakBegin(serde_json::from_value(value)?),
+            ChannelPointsAutomaticRewardRedemption
+            | ChannelPointsCustomRewardAdd
+            | ChannelPointsCustomRewardUpdate
+            | ChannelPointsCustomRewardRemove
+            | ChannelPointsCustomRewardRedemptionAdd
+            | ChannelPointsCustomRewardRedemptionUpdate => {
+                Self::Reward(serde_json::from_value(value)?)
+            }
+            ChannelRaid => Self::ChannelRaid(serde_json::from_value(value)?),
+            ConduitShardDisabled => Self::ConduitShardDisabled(serde_json::from_value(value)?),
+            DropEntitlementGrant => Self::DropEntitlementGrant(serde_json::from_value(value)?),
+            ExtensionBitsTransactionCreate => {
+                Self::ExtensionBitsTransaction(serde_json::from_value(value)?)
+            }
+            UserAuthorizationGrant | UserAuthorizationRevoke => {
+                Self::UserAuthorization(serde_json::from_value(value)?)
+            }
+            UserUpdate | WhisperReceived => Self::User(serde_json::from_value(value)?),
+            Unknown { .. } => Self::Unknown(value),
+        })
+    }
+
+    /// The broadcaster whose channel the subscription is for.
+    /// For `channel.raid`, the raided broadcaster if set, otherwise the raider.
+    pub fn broadcaster_user_id(&self) -> Option<&str> {
+        match self {
+            Self::Broadcaster(condition) => Some(&condition.broadcaster_user_id),
+            Self::BroadcasterModerator(condition) => Some(&condition.broadcaster_user_id),
+            Self::BroadcasterUser(condition) => Some(&condition.broadcaster_user_id),
+            Self::AdBreakBegin(condition) => Some(&condition.broadcaster_id),
+            Self::Reward(condition) => Some(&condition.broadcaster_user_id),
+            Self::ChannelRaid(condition) => condition
+                .to_broadcaster_user_id
+                .as_deref()
+                .or(condition.from_broadcaster_user_id.as_deref()),
+            Self::ConduitShardDisabled(_)
+            | Self::DropEntitlementGrant(_)
+            | Self::ExtensionBitsTransaction(_)
+            | Self::UserAuthorization(_)
+            | Self::User(_) => None,
+            Self::Unknown(condition) => {
+                condition.get("broadcaster_user_id").and_then(Value::as_str)
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "twitch-router", feature = "twitch-manage"))]
+impl Condition {
+    /// The users the condition names; Twitch sends unused fields as `""`.
+    pub(crate) fn user_ids(&self) -> Vec<&str> {
+        let mut users = match self {
+            Self::Broadcaster(c) => vec![c.broadcaster_user_id.as_str()],
+            Self::BroadcasterModerator(c) => {
+                vec![c.broadcaster_user_id.as_str(), c.moderator_user_id.as_str()]
+            }
+            Self::BroadcasterUser(c) => vec![c.broadcaster_user_id.as_str(), c.user_id.as_str()],
+            Self::AdBreakBegin(c) => vec![c.broadcaster_id.as_str()],
+            Self::Reward(c) => vec![c.broadcaster_user_id.as_str()],
+            Self::ChannelRaid(c) => [
+                c.from_broadcaster_user_id.as_deref(),
+                c.to_broadcaster_user_id.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+            Self::User(c) => vec![c.user_id.as_str()],
+            Self::ConduitShardDisabled(_)
+            | Self::DropEntitlementGrant(_)
+            | Self::ExtensionBitsTransaction(_)
+            | Self::UserAuthorization(_) => Vec::new(),
+            Self::Unknown(c) => c
+                .as_object()
+                .into_iter()
+                .flatten()
+                .filter(|(key, _)| key.ends_with("user_id"))
+                .filter_map(|(_, value)| value.as_str())
+                .collect(),
+        };
+        users.retain(|user| !user.is_empty());
+        users
+    }
+}
+
+/// A condition [`Subscription`](super::Subscription) can decode once its type
+/// is known. Implemented for [`Condition`] and every type that is
+/// `Deserialize`, such as the per-type condition structs.
+pub trait SubscriptionCondition: Sized {
+    fn from_value(kind: &SubscriptionType, value: Value) -> Result<Self, serde_json::Error>;
+}
+
+impl SubscriptionCondition for Condition {
+    fn from_value(kind: &SubscriptionType, value: Value) -> Result<Self, serde_json::Error> {
+        Ok(Condition::from_value(kind, value))
+    }
+}
+
+impl<T: DeserializeOwned> SubscriptionCondition for T {
+    fn from_value(_: &SubscriptionType, value: Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value)
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ConduitId, ShardId, Status, Transport};
+
+/// <https://dev.twitch.tv/docs/eventsub/handling-conduit-events>
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Conduit {
+    pub id: ConduitId,
+    pub shard_count: u64,
+}
+
+/// <https://dev.twitch.tv/docs/api/reference/#get-conduit-shards>
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Shard {
+    /// Shard ID.
+    pub id: ShardId,
+    /// The shard status. The subscriber receives events only for enabled shards.
+    pub status: Status,
+    pub transport: Transport,
+}
+
+/// A shard that Update Conduit Shards could not update.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShardError {
+    /// Shard ID.
+    pub id: ShardId,
+    /// The error that occurred while updating the shard.
+    pub message: String,
+    /// Error codes used to represent a specific error condition while attempting to update shards.
+    pub code: String,
+}
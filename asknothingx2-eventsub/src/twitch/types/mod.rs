@@ -2,6 +2,7 @@
 mod new_types;
 pub mod objects;
 pub mod payloads;
+pub mod timestamp;
 
 mod condition;
 mod status;
@@ -9,10 +10,15 @@ mod subscription;
 mod subscription_type;
 mod transport;
 
-pub use condition::Condition;
+pub use condition::{
+    AdBreakBeginCondition, BroadcasterCondition, BroadcasterModeratorCondition,
+    BroadcasterUserCondition, ChannelRaidCondition, Condition, ConduitShardDisabledCondition,
+    DropEntitlementGrantCondition, ExtensionBitsTransactionCondition, RewardCondition,
+    SubscriptionCondition, UserAuthorizationCondition, UserCondition,
+};
 pub use new_types::{
-    BroadcasterUserId, ConduitId, ExtensionClientId, MessageId, ModeratorUserId, SessionId,
-    SubscriptionId,
+    BroadcasterUserId, CampaignId, CategoryId, ClientId, ConduitId, ExtensionClientId, MessageId,
+    ModeratorUserId, OrganizationId, SessionId, ShardId, SubscriptionId, UserId,
 };
 pub use status::Status;
 pub use subscription::Subscription;
@@ -21,3 +27,8 @@ pub use transport::{Transport, TransportMethod};
 
 #[cfg(feature = "twitch-webhook")]
 pub mod secret;
+
+#[cfg(feature = "twitch-conduit")]
+mod conduit;
+#[cfg(feature = "twitch-conduit")]
+pub use conduit::{Conduit, Shard, ShardError};
@@ -45,6 +45,24 @@ macro_rules! new_type {
             }
         }
 
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                &self.0 == other
+            }
+        }
+
         impl serde::Serialize for $name {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
@@ -91,8 +109,11 @@ macro_rules! new_type {
 
 new_type!(SessionId);
 new_type!(ConduitId);
+new_type!(ShardId);
 new_type!(BroadcasterUserId);
 new_type!(ModeratorUserId);
+new_type!(UserId);
+new_type!(ClientId);
 new_type!(ExtensionClientId);
 new_type!(SubscriptionId);
 new_type!(OrganizationId);
@@ -2,6 +2,8 @@ use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 use twitch_highway::types::{RewardId, UserId};
 
+use super::timestamp::rfc3339_nanos;
+
 /// NOTE: Bits voting is not supported.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BitsVoting {
@@ -22,16 +24,27 @@ pub struct Choices {
     pub id: String,
     pub title: String,
     /// Not used; will be set to 0.
+    #[serde(default)]
     pub bits_votes: u8,
+    /// Not sent in `channel.poll.begin`.
+    #[serde(default)]
     pub channel_points_votes: u64,
+    #[serde(default)]
     pub votes: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Emotes {
-    begin: u64,
-    end: u64,
-    id: String,
+    pub begin: u64,
+    pub end: u64,
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Image {
+    pub url_1x: String,
+    pub url_2x: String,
+    pub url_4x: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -73,11 +86,22 @@ pub struct Message {
 pub struct Outcomes {
     pub id: String,
     pub title: String,
-    pub color: String,
+    pub color: OutcomeColor,
+    /// Not sent in `channel.prediction.begin`.
+    #[serde(default)]
     pub users: u64,
+    #[serde(default)]
     pub channel_points: u64,
+    #[serde(default)]
     pub top_predictors: Vec<TopPredictor>,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutcomeColor {
+    Blue,
+    Pink,
+}
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TopPredictor {
     pub user_id: UserId,
@@ -87,6 +111,19 @@ pub struct TopPredictor {
     pub channel_points_used: u64,
 }
 
+/// A subscription tier. Twitch sends tiers as `1000`, `2000` and `3000`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Tier {
+    #[serde(rename = "1000")]
+    Tier1,
+    #[serde(rename = "2000")]
+    Tier2,
+    #[serde(rename = "3000")]
+    Tier3,
+    #[serde(rename = "Prime", alias = "prime")]
+    Prime,
+}
+
 /// <https://dev.twitch.tv/docs/eventsub/eventsub-reference/#product>
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Product {
@@ -105,43 +142,34 @@ pub struct Reward {
     pub prompt: String,
 }
 
+/// The reward of `channel.channel_points_automatic_reward_redemption.add`.
 #[derive(Debug, Serialize, Deserialize)]
-pub struct ShieldMode {
-    pub broadcaster_user_id: String,
-    pub broadcaster_user_login: String,
-    pub broadcaster_user_name: String,
-    pub moderator_user_id: String,
-    pub moderator_user_login: String,
-    pub moderator_user_name: String,
-    pub started_at: DateTime<FixedOffset>,
-    pub ended_at: DateTime<FixedOffset>,
+pub struct AutomaticReward {
+    /// e.g. `single_message_bypass_sub_mode`, `send_highlighted_message`,
+    /// `random_sub_emote_unlock`, `chosen_sub_emote_unlock` or `chosen_modified_sub_emote_unlock`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub cost: u64,
+    /// Set for the emote unlock rewards.
+    pub unlocked_emote: Option<UnlockedEmote>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct ShoutoutCreate {
-    pub broadcaster_user_id: String,
-    pub broadcaster_user_login: String,
-    pub broadcaster_user_name: String,
-    pub to_broadcaster_user_id: String,
-    pub to_broadcaster_user_login: String,
-    pub to_broadcaster_user_name: String,
-    pub moderator_user_id: String,
-    pub moderator_user_login: String,
-    pub moderator_user_name: String,
-    pub viewer_count: u64,
-    pub started_at: DateTime<FixedOffset>,
-    pub cooldown_ends_at: DateTime<FixedOffset>,
-    pub target_cooldown_ends_at: DateTime<FixedOffset>,
+pub struct UnlockedEmote {
+    pub id: String,
+    pub name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct ShoutoutReceived {
+pub struct ShieldMode {
     pub broadcaster_user_id: String,
     pub broadcaster_user_login: String,
     pub broadcaster_user_name: String,
-    pub from_broadcaster_user_id: String,
-    pub from_broadcaster_user_login: String,
-    pub from_broadcaster_user_name: String,
-    pub viewer_count: u64,
+    pub moderator_user_id: String,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    #[serde(with = "rfc3339_nanos")]
     pub started_at: DateTime<FixedOffset>,
+    #[serde(with = "rfc3339_nanos")]
+    pub ended_at: DateTime<FixedOffset>,
 }
@@ -2,18 +2,20 @@ use serde::{Deserialize, Serialize};
 
 use crate::twitch::subscription_types::user::authorization_grant_event::AuthorizationGrantEvent;
 
-use super::subscription::Subscription;
+use super::{subscription::Subscription, Condition, SubscriptionCondition};
 
-pub type EventPayload<E> = SubscriptionEventPayload<E>;
+pub type EventPayload<E, C = Condition> = SubscriptionEventPayload<E, C>;
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct SubscriptionPayload {
-    pub subscription: Subscription,
+#[serde(bound(deserialize = "C: SubscriptionCondition"))]
+pub struct SubscriptionPayload<C = Condition> {
+    pub subscription: Subscription<C>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct SubscriptionEventPayload<Event> {
-    pub subscription: Subscription,
+#[serde(bound(deserialize = "Event: Deserialize<'de>, C: SubscriptionCondition"))]
+pub struct SubscriptionEventPayload<Event, C = Condition> {
+    pub subscription: Subscription<C>,
     pub event: Event,
 }
 
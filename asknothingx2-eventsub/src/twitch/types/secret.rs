@@ -1,17 +1,33 @@
 use asknothingx2_util::api::HeaderMap;
+use chrono::{DateTime, TimeDelta, Utc};
 use hmac::{Hmac, Mac};
 use rand::Rng;
 use sha2::Sha256;
-use std::fmt::{self, Write};
+use std::{
+    fmt::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
 
-use crate::twitch::error::HeaderVerificationError;
+use crate::twitch::error::{HeaderVerificationError, SecretError};
 
 const TWITCH_MESSAGE_ID: &str = "twitch-eventsub-message-id";
 const TWITCH_MESSAGE_TIMESTAMP: &str = "twitch-eventsub-message-timestamp";
 const TWITCH_MESSAGE_SIGNATURE: &str = "twitch-eventsub-message-signature";
 
-pub struct Secret(Vec<u8>);
+const MIN_SECRET_LEN: usize = 10;
+const MAX_SECRET_LEN: usize = 100;
+
+/// The secret Twitch signs webhook messages with.
+///
+/// It has to survive restarts: every subscription created with it is verified
+/// against it, so load it with [`Secret::from_env`] or [`Secret::from_file`]
+/// rather than generating a new one each time.
+#[derive(Clone)]
+pub struct Secret(String);
 impl Secret {
+    /// A random secret of 64 hex characters.
     pub fn new() -> Self {
         Self::new_random_bytes()
     }
@@ -19,14 +35,42 @@ impl Secret {
         let mut rng = rand::rng();
         let random_bytes: Vec<u8> = (0..32).map(|_| rng.random::<u8>()).collect();
 
-        Self(random_bytes)
+        Self(random_bytes.iter().fold(String::new(), |mut output, b| {
+            let _ = write!(output, "{b:02X}");
+            output
+        }))
+    }
+
+    /// Twitch requires 10 to 100 ASCII characters.
+    pub fn from_string(secret: impl Into<String>) -> Result<Self, SecretError> {
+        let secret = secret.into();
+        if !secret.is_ascii() {
+            return Err(SecretError::NonAscii);
+        }
+        if !(MIN_SECRET_LEN..=MAX_SECRET_LEN).contains(&secret.len()) {
+            return Err(SecretError::InvalidLength(secret.len()));
+        }
+
+        Ok(Self(secret))
+    }
+
+    pub fn from_env(key: &str) -> Result<Self, SecretError> {
+        Self::from_string(std::env::var(key)?)
+    }
+
+    /// Surrounding whitespace, such as a trailing newline, is ignored.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, SecretError> {
+        Self::from_string(std::fs::read_to_string(path)?.trim())
     }
 
+    /// The value to send as `transport.secret`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Same as [`Secret::as_str`]; kept for secrets generated with [`Secret::new`].
     pub fn hex_encode(&self) -> String {
-        self.0.iter().fold(String::new(), |mut output, b| {
-            let _ = write!(output, "{b:02X}");
-            output
-        })
+        self.0.clone()
     }
 
     pub fn verify_header(
@@ -94,8 +138,8 @@ impl Secret {
             String::from_utf8_lossy(body)
         );
 
-        let mut mac = HmacSha256::new_from_slice(self.hex_encode().as_bytes())
-            .expect("HMAC can take key of any size");
+        let mut mac =
+            HmacSha256::new_from_slice(self.0.as_bytes()).expect("HMAC can take key of any size");
         mac.update(message.as_bytes());
 
         mac.verify_slice(&signature_bytes)?;
@@ -104,6 +148,19 @@ impl Secret {
     }
 }
 
+impl Secret {
+    /// The `Twitch-Eventsub-Message-Signature` value Twitch would send for this message.
+    pub fn sign(&self, message_id: &str, timestamp: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.0.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(message_id.as_bytes());
+        mac.update(timestamp.as_bytes());
+        mac.update(body);
+
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
 impl fmt::Debug for Secret {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, concat!(stringify!(Secret), "([redacted])"))
@@ -115,3 +172,78 @@ impl Default for Secret {
         Self::new_random_bytes()
     }
 }
+
+impl FromStr for Secret {
+    type Err = SecretError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_string(s)
+    }
+}
+
+impl TryFrom<String> for Secret {
+    type Error = SecretError;
+
+    fn try_from(secret: String) -> Result<Self, Self::Error> {
+        Self::from_string(secret)
+    }
+}
+
+/// A secret that is being replaced.
+///
+/// After [`RotatingSecret::rotate`], messages signed with either secret are accepted
+/// until the window ends, giving time to recreate subscriptions with the new one.
+#[derive(Debug)]
+pub struct RotatingSecret {
+    current: Secret,
+    previous: Option<(Secret, DateTime<Utc>)>,
+}
+
+impl RotatingSecret {
+    pub fn new(current: Secret) -> Self {
+        Self {
+            current,
+            previous: None,
+        }
+    }
+
+    pub fn current(&self) -> &Secret {
+        &self.current
+    }
+
+    /// Makes `secret` current and keeps accepting the old one for `window`.
+    pub fn rotate(&mut self, secret: Secret, window: Duration) {
+        let until = TimeDelta::from_std(window)
+            .ok()
+            .and_then(|window| Utc::now().checked_add_signed(window))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        let previous = std::mem::replace(&mut self.current, secret);
+        self.previous = Some((previous, until));
+    }
+
+    /// Tries the current secret first, then the previous one while its window lasts.
+    pub fn verify_twitch_signature(
+        &self,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<(), HeaderVerificationError> {
+        let err = match self.current.verify_twitch_signature(headers, body) {
+            Ok(()) => return Ok(()),
+            Err(e @ HeaderVerificationError::SignatureVerification(_)) => e,
+            Err(e) => return Err(e),
+        };
+
+        match &self.previous {
+            Some((previous, until)) if Utc::now() < *until => {
+                previous.verify_twitch_signature(headers, body)
+            }
+            _ => Err(err),
+        }
+    }
+}
+
+impl From<Secret> for RotatingSecret {
+    fn from(secret: Secret) -> Self {
+        Self::new(secret)
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Status {
     Enabled,
     WebhookCallbackVerificationPending,
@@ -20,6 +21,8 @@ pub enum Status {
     WebsocketNetworkTimeout,
     WebsocketNetworkError,
     WebsocketFailedToReconnect,
+    /// A status this crate doesn't know yet, kept as sent so it round-trips.
+    Unknown(String),
 }
 
 impl Status {
@@ -43,6 +46,7 @@ impl Status {
             Self::WebsocketNetworkTimeout => "websocket_network_timeout",
             Self::WebsocketNetworkError => "websocket_network_error",
             Self::WebsocketFailedToReconnect => "websocket_failed_to_reconnect",
+            Self::Unknown(status) => status,
         }
     }
 }
@@ -53,6 +57,18 @@ impl AsRef<str> for Status {
     }
 }
 
+impl PartialEq<str> for Status {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Status {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
 impl std::fmt::Display for Status {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -99,7 +115,7 @@ impl<'de> Deserialize<'de> for Status {
             "websocket_network_timeout" => Ok(Self::WebsocketNetworkTimeout),
             "websocket_network_error" => Ok(Self::WebsocketNetworkError),
             "websocket_failed_to_reconnect" => Ok(Self::WebsocketFailedToReconnect),
-            _ => Err(serde::de::Error::custom(format!("unknown status: {}", s))),
+            _ => Ok(Self::Unknown(s)),
         }
     }
 }
@@ -1,22 +1,26 @@
 use chrono::{DateTime, FixedOffset};
 use serde::Serialize;
 
-use super::{new_types::SubscriptionId, Condition, Status, SubscriptionType, Transport};
+use super::{
+    new_types::SubscriptionId, timestamp::rfc3339_nanos, Condition, Status, SubscriptionCondition,
+    SubscriptionType, Transport,
+};
 
-#[derive(Debug, Serialize)]
-pub struct Subscription {
+#[derive(Clone, Debug, Serialize)]
+pub struct Subscription<C = Condition> {
     pub id: SubscriptionId,
     pub status: Status,
     #[serde(rename = "type")]
     pub kind: SubscriptionType,
     pub version: String,
-    pub condition: Condition,
+    pub condition: C,
+    #[serde(with = "rfc3339_nanos")]
     pub created_at: DateTime<FixedOffset>,
     pub transport: Transport,
     pub cost: u64,
 }
 
-impl<'de> serde::Deserialize<'de> for Subscription {
+impl<'de, C: SubscriptionCondition> serde::Deserialize<'de> for Subscription<C> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -28,7 +32,8 @@ impl<'de> serde::Deserialize<'de> for Subscription {
             #[serde(rename = "type")]
             kind: SubscriptionType,
             version: String,
-            condition: Condition,
+            condition: serde_json::Value,
+            #[serde(with = "rfc3339_nanos")]
             created_at: DateTime<FixedOffset>,
             transport: Transport,
             cost: u64,
@@ -58,15 +63,21 @@ impl<'de> serde::Deserialize<'de> for Subscription {
                     kind
                 }
             }
+            SubscriptionType::Unknown { kind, .. } => SubscriptionType::Unknown {
+                kind,
+                version: helper.version.clone(),
+            },
             _ => helper.kind,
         };
 
+        let condition = C::from_value(&kind, helper.condition).map_err(serde::de::Error::custom)?;
+
         Ok(Subscription {
             id: helper.id,
             status: helper.status,
             kind,
             version: helper.version,
-            condition: helper.condition,
+            condition,
             created_at: helper.created_at,
             transport: helper.transport,
             cost: helper.cost,
@@ -1,10 +1,11 @@
 use core::str;
 use std::fmt;
 
-use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/>
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum SubscriptionType {
     // Automod related
     AutomodMessageHold,
@@ -127,6 +128,13 @@ pub enum SubscriptionType {
 
     // Whispers
     WhisperReceived,
+
+    /// A type this crate doesn't know yet, kept as sent so it round-trips.
+    /// `version` is `"1"` until the surrounding subscription's version is known.
+    Unknown {
+        kind: String,
+        version: String,
+    },
 }
 
 impl SubscriptionType {
@@ -218,11 +226,13 @@ impl SubscriptionType {
             Self::UserAuthorizationRevoke => "user.authorization.revoke",
             Self::UserUpdate => "user.update",
             Self::WhisperReceived => "user.whisper.message",
+            Self::Unknown { kind, .. } => kind,
         }
     }
 
     pub fn version(&self) -> &str {
         match self {
+            Self::Unknown { version, .. } => version,
             Self::AutomodMessageHoldV2 | Self::AutomodMessageUpdateV2 => "2",
             Self::ChannelUpdate | Self::ChannelFollow => "2",
             Self::ChannelModerateV2 => "2",
@@ -233,6 +243,29 @@ impl SubscriptionType {
             _ => "1",
         }
     }
+
+    /// Whether Twitch only creates this type once a user authorized the app
+    /// with a scope. Such subscriptions cost nothing.
+    /// <https://dev.twitch.tv/docs/eventsub/manage-subscriptions/#subscription-limits>
+    pub fn requires_user_authorization(&self) -> bool {
+        !matches!(
+            self,
+            Self::ChannelUpdate
+                | Self::ChannelRaid
+                | Self::ChannelSharedChatSessionBegin
+                | Self::ChannelSharedChatSessionUpdate
+                | Self::ChannelSharedChatSessionEnd
+                | Self::ConduitShardDisabled
+                | Self::DropEntitlementGrant
+                | Self::ExtensionBitsTransactionCreate
+                | Self::StreamOnline
+                | Self::StreamOffline
+                | Self::UserAuthorizationGrant
+                | Self::UserAuthorizationRevoke
+                | Self::UserUpdate
+                | Self::Unknown { .. }
+        )
+    }
 }
 
 impl Serialize for SubscriptionType {
@@ -250,101 +283,10 @@ impl<'de> Deserialize<'de> for SubscriptionType {
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        match s.as_str() {
-            "automod.message.hold" => Ok(Self::AutomodMessageHold),
-            // "automod.message.hold" => Ok(Self::AutomodMessageHoldV2),
-            "automod.message.update" => Ok(Self::AutomodMessageUpdate),
-            // "automod.message.update" => Ok(Self::AutomodMessageUpdateV2),
-            "automod.settings.update" => Ok(Self::AutomodSettingsUpdate),
-            "automod.terms.update" => Ok(Self::AutomodTermsUpdate),
-            "channel.update" => Ok(Self::ChannelUpdate),
-            "channel.follow" => Ok(Self::ChannelFollow),
-            "channel.ad_break.begin" => Ok(Self::ChannelAdBreakBegin),
-            "channel.chat.clear" => Ok(Self::ChannelChatClear),
-            "channel.chat.clear_user_messages" => Ok(Self::ChannelChatClearUserMessages),
-            "channel.chat.message" => Ok(Self::ChannelChatMessage),
-            "channel.chat.message_delete" => Ok(Self::ChannelChatMessageDelete),
-            "channel.chat.notification" => Ok(Self::ChannelChatNotification),
-            "channel.chat_settings.update" => Ok(Self::ChannelChatSettingsUpdate),
-            "channel.chat.user_message_hold" => Ok(Self::ChannelChatUserMessageHold),
-            "channel.chat.user_message_update" => Ok(Self::ChannelChatUserMessageUpdate),
-            "channel.shared_chat.begin" => Ok(Self::ChannelSharedChatSessionBegin),
-            "channel.shared_chat.update" => Ok(Self::ChannelSharedChatSessionUpdate),
-            "channel.shared_chat.end" => Ok(Self::ChannelSharedChatSessionEnd),
-            "channel.subscribe" => Ok(Self::ChannelSubscribe),
-            "channel.subscription.end" => Ok(Self::ChannelSubscriptionEnd),
-            "channel.subscription.gift" => Ok(Self::ChannelSubscriptionGift),
-            "channel.subscription.message" => Ok(Self::ChannelSubscriptionMessage),
-            "channel.cheer" => Ok(Self::ChannelCheer),
-            "channel.raid" => Ok(Self::ChannelRaid),
-            "channel.ban" => Ok(Self::ChannelBan),
-            "channel.unban" => Ok(Self::ChannelUnban),
-            "channel.unban_request.create" => Ok(Self::ChannelUnbanRequestCreate),
-            "channel.unban_request.resolve" => Ok(Self::ChannelUnbanRequestResolve),
-            "channel.moderate" => Ok(Self::ChannelModerate),
-            "channel.moderator.add" => Ok(Self::ChannelModeratorAdd),
-            "channel.moderator.remove" => Ok(Self::ChannelModeratorRemove),
-            "channel.guest_star_session.begin" => Ok(Self::ChannelGuestStarSessionBegin),
-            "channel.guest_star_session.end" => Ok(Self::ChannelGuestStarSessionEnd),
-            "channel.guest_star_guest.update" => Ok(Self::ChannelGuestStarGuestUpdate),
-            "channel.guest_star_settings.update" => Ok(Self::ChannelGuestStarSettingsUpdate),
-            "channel.channel_points_automatic_reward_redemption.add" => {
-                Ok(Self::ChannelPointsAutomaticRewardRedemption)
-            }
-            "channel.channel_points_custom_reward.add" => Ok(Self::ChannelPointsCustomRewardAdd),
-            "channel.channel_points_custom_reward.update" => {
-                Ok(Self::ChannelPointsCustomRewardUpdate)
-            }
-            "channel.channel_points_custom_reward.remove" => {
-                Ok(Self::ChannelPointsCustomRewardRemove)
-            }
-            "channel.channel_points_custom_reward_redemption.add" => {
-                Ok(Self::ChannelPointsCustomRewardRedemptionAdd)
-            }
-            "channel.channel_points_custom_reward_redemption.update" => {
-                Ok(Self::ChannelPointsCustomRewardRedemptionUpdate)
-            }
-            "channel.poll.begin" => Ok(Self::ChannelPollBegin),
-            "channel.poll.progress" => Ok(Self::ChannelPollProgress),
-            "channel.poll.end" => Ok(Self::ChannelPollEnd),
-            "channel.prediction.begin" => Ok(Self::ChannelPredictionBegin),
-            "channel.prediction.progress" => Ok(Self::ChannelPredictionProgress),
-            "channel.prediction.lock" => Ok(Self::ChannelPredictionLock),
-            "channel.prediction.end" => Ok(Self::ChannelPredictionEnd),
-            "channel.suspicious_user.message" => Ok(Self::ChannelSuspiciousUserMessage),
-            "channel.suspicious_user.update" => Ok(Self::ChannelSuspiciousUserUpdate),
-            "channel.vip.add" => Ok(Self::ChannelVIPAdd),
-            "channel.vip.remove" => Ok(Self::ChannelVIPRemove),
-            "channel.warning.acknowledge" => Ok(Self::ChannelWarningAcknowledgement),
-            "channel.warning.send" => Ok(Self::ChannelWarningSend),
-            "channel.charity_campaign.donate" => Ok(Self::CharityDonation),
-            "channel.charity_campaign.start" => Ok(Self::CharityCampaignStart),
-            "channel.charity_campaign.progress" => Ok(Self::CharityCampaignProgress),
-            "channel.charity_campaign.stop" => Ok(Self::CharityCampaignStop),
-            "conduit.shard.disabled" => Ok(Self::ConduitShardDisabled),
-            "drop.entitlement.grant" => Ok(Self::DropEntitlementGrant),
-            "extension.bits_transaction.create" => Ok(Self::ExtensionBitsTransactionCreate),
-            "channel.goal.begin" => Ok(Self::GoalBegin),
-            "channel.goal.progress" => Ok(Self::GoalProgress),
-            "channel.goal.end" => Ok(Self::GoalEnd),
-            "channel.hype_train.begin" => Ok(Self::HypeTrainBegin),
-            "channel.hype_train.progress" => Ok(Self::HypeTrainProgress),
-            "channel.hype_train.end" => Ok(Self::HypeTrainEnd),
-            "channel.shield_mode.begin" => Ok(Self::ShieldModeBegin),
-            "channel.shield_mode.end" => Ok(Self::ShieldModeEnd),
-            "channel.shoutout.create" => Ok(Self::ShoutoutCreate),
-            "channel.shoutout.receive" => Ok(Self::ShoutoutReceived),
-            "stream.online" => Ok(Self::StreamOnline),
-            "stream.offline" => Ok(Self::StreamOffline),
-            "user.authorization.grant" => Ok(Self::UserAuthorizationGrant),
-            "user.authorization.revoke" => Ok(Self::UserAuthorizationRevoke),
-            "user.update" => Ok(Self::UserUpdate),
-            "user.whisper.message" => Ok(Self::WhisperReceived),
-            _ => Err(D::Error::custom(format!(
-                "Unknown subscription type: '{}'",
-                s
-            ))),
-        }
+        Ok(s.parse().unwrap_or(Self::Unknown {
+            kind: s,
+            version: "1".to_string(),
+        }))
     }
 }
 
@@ -360,6 +302,8 @@ impl From<SubscriptionType> for String {
     }
 }
 
+/// Fails for types this crate doesn't know; deserializing falls back to
+/// [`SubscriptionType::Unknown`] instead.
 impl str::FromStr for SubscriptionType {
     type Err = String;
 
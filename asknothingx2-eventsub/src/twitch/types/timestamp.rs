@@ -0,0 +1,56 @@
+//! Twitch timestamps, e.g. `2019-11-16T10:11:12.634234626Z`.
+
+/// Serde adapter for `DateTime<FixedOffset>` fields.
+///
+/// Twitch sends RFC 3339 in UTC with up to nanosecond precision. Deserializing
+/// accepts any RFC 3339 offset and precision; serializing writes nine
+/// fractional digits and `Z` for UTC, so timestamps round-trip in Twitch's
+/// format instead of chrono's default `+00:00`.
+///
+/// ```ignore
+/// #[serde(with = "rfc3339_nanos")]
+/// pub started_at: DateTime<FixedOffset>,
+/// #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
+/// pub ended_at: Option<DateTime<FixedOffset>>,
+/// ```
+pub mod rfc3339_nanos {
+    use std::borrow::Cow;
+
+    use chrono::{DateTime, FixedOffset, SecondsFormat};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    /// Formats `timestamp` the way Twitch does.
+    pub fn format(timestamp: &DateTime<FixedOffset>) -> String {
+        timestamp.to_rfc3339_opts(SecondsFormat::Nanos, true)
+    }
+
+    pub fn serialize<S>(timestamp: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format(timestamp))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Cow::<str>::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&value).map_err(D::Error::custom)
+    }
+
+    /// For `Option<DateTime<FixedOffset>>` with `serialize_with`; chrono
+    /// decodes the `Option` as is.
+    pub fn serialize_option<S>(
+        timestamp: &Option<DateTime<FixedOffset>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match timestamp {
+            Some(timestamp) => serializer.serialize_some(&format(timestamp)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
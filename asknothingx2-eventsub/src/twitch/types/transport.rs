@@ -1,8 +1,12 @@
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
-use url::Url;
 
-use super::{new_types::ConduitId, SessionId};
+use super::timestamp::rfc3339_nanos;
+
+#[cfg(feature = "twitch-conduit")]
+use super::new_types::ConduitId;
+#[cfg(feature = "twitch-websocket")]
+use super::SessionId;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -27,7 +31,7 @@ pub struct Transport {
     /// NOTE: Redirects are not followed.
     #[cfg(feature = "twitch-webhook")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub callback: Option<Url>,
+    pub callback: Option<String>,
     /// The secret used to verify the signature.
     /// The secret must be an ASCII string that’s a minimum of 10 characters long
     /// and a maximum of 100 characters long.
@@ -47,7 +51,7 @@ pub struct Transport {
     /// Specify this field only if method is set to websocket.
     #[cfg(feature = "twitch-websocket")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub session_id: Option<SessionId>,
+    pub session_id: Option<String>,
     /// An ID that identifies the conduit to send notifications to.
     /// When you create a conduit, the server returns the conduit ID.
     ///
@@ -65,7 +69,10 @@ pub struct Transport {
     /// <https://dev.twitch.tv/docs/api/reference/#get-eventsub-subscriptions>
     ///
     /// if the method field is set to websocket.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "rfc3339_nanos::serialize_option"
+    )]
     pub connected_at: Option<DateTime<FixedOffset>>,
     /// The UTC date and time that the WebSocket connection was lost.
     ///
@@ -74,7 +81,10 @@ pub struct Transport {
     /// <https://dev.twitch.tv/docs/api/reference/#get-eventsub-subscriptions>
     ///
     /// if the method field is set to websocket.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "rfc3339_nanos::serialize_option"
+    )]
     pub disconnected_at: Option<DateTime<FixedOffset>>,
 }
 
@@ -87,7 +97,7 @@ impl Transport {
             callback: None,
             #[cfg(feature = "twitch-webhook")]
             secret: None,
-            session_id: Some(session_id),
+            session_id: Some(session_id.into()),
             #[cfg(feature = "twitch-conduit")]
             conduit_id: None,
             connected_at: None,
@@ -96,11 +106,11 @@ impl Transport {
     }
 
     #[cfg(feature = "twitch-webhook")]
-    pub fn webhook<T: Into<String>>(callback: Url, secret: T) -> Self {
+    pub fn webhook(callback: impl Into<String>, secret: Option<String>) -> Self {
         Self {
             method: TransportMethod::Webhook,
-            callback: Some(callback),
-            secret: Some(secret.into()),
+            callback: Some(callback.into()),
+            secret,
             #[cfg(feature = "twitch-websocket")]
             session_id: None,
             #[cfg(feature = "twitch-conduit")]
@@ -109,4 +119,20 @@ impl Transport {
             disconnected_at: None,
         }
     }
+
+    #[cfg(feature = "twitch-conduit")]
+    pub fn conduit(conduit_id: ConduitId) -> Self {
+        Self {
+            method: TransportMethod::Conduit,
+            #[cfg(feature = "twitch-webhook")]
+            callback: None,
+            #[cfg(feature = "twitch-webhook")]
+            secret: None,
+            #[cfg(feature = "twitch-websocket")]
+            session_id: None,
+            conduit_id: Some(conduit_id),
+            connected_at: None,
+            disconnected_at: None,
+        }
+    }
 }
@@ -0,0 +1,241 @@
+//! Handles EventSub webhook callbacks independently of any HTTP framework.
+//!
+//! <https://dev.twitch.tv/docs/eventsub/handling-webhook-events>
+//!
+//! ```rust,ignore
+//! let handler = WebhookHandler::new(secret);
+//!
+//! // inside the callback route
+//! let handled = handler.handle(&request);
+//! if let Ok(WebhookEvent::Notification(notification)) = handled.event {
+//!     tokio::spawn(process(notification));
+//! }
+//! return handled.response;
+//! ```
+use std::sync::RwLock;
+
+use bytes::Bytes;
+use chrono::{DateTime, FixedOffset};
+use http::{header, Request, Response, StatusCode};
+use serde::Deserialize;
+
+use super::{
+    error::WebhookError,
+    types::{
+        payloads::{SubscriptionEventPayload, SubscriptionPayload},
+        secret::{RotatingSecret, Secret},
+        MessageId, Subscription,
+    },
+};
+
+mod replay;
+#[cfg(feature = "twitch-webhook-test")]
+mod sender;
+#[cfg(feature = "twitch-webhook-server")]
+mod server;
+mod signing;
+
+pub use replay::{MemoryStore, ReplayGuard, SeenMessageStore};
+#[cfg(feature = "twitch-webhook-test")]
+pub use sender::FakeSender;
+#[cfg(feature = "twitch-webhook-server")]
+pub use server::{WebhookEvents, WebhookServer};
+pub use signing::MessageSigner;
+
+const TWITCH_MESSAGE_ID: &str = "twitch-eventsub-message-id";
+const TWITCH_MESSAGE_TIMESTAMP: &str = "twitch-eventsub-message-timestamp";
+const TWITCH_MESSAGE_TYPE: &str = "twitch-eventsub-message-type";
+
+/// The `Twitch-Eventsub-Message-Type` header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebhookMessageType {
+    Notification,
+    WebhookCallbackVerification,
+    Revocation,
+}
+
+impl WebhookMessageType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Notification => "notification",
+            Self::WebhookCallbackVerification => "webhook_callback_verification",
+            Self::Revocation => "revocation",
+        }
+    }
+}
+
+impl std::str::FromStr for WebhookMessageType {
+    type Err = WebhookError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "notification" => Ok(Self::Notification),
+            "webhook_callback_verification" => Ok(Self::WebhookCallbackVerification),
+            "revocation" => Ok(Self::Revocation),
+            other => Err(WebhookError::InvalidMessageType(other.to_string())),
+        }
+    }
+}
+
+/// A notification delivered to the callback.
+/// The event is kept as JSON; decode it with [`WebhookNotification::event`].
+#[derive(Debug)]
+pub struct WebhookNotification {
+    pub message_id: MessageId,
+    pub message_timestamp: DateTime<FixedOffset>,
+    pub subscription: Subscription,
+    pub event: serde_json::Value,
+}
+
+impl WebhookNotification {
+    pub fn event<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(&self.event)
+    }
+}
+
+#[derive(Debug)]
+pub enum WebhookEvent {
+    /// Twitch is confirming the callback of a new subscription.
+    /// The challenge has already been echoed in the response.
+    Verification {
+        subscription: Subscription,
+        challenge: String,
+    },
+    Notification(WebhookNotification),
+    Revocation(Subscription),
+}
+
+/// The response to send back and what the request carried.
+///
+/// Twitch expects a 2xx response within a few seconds and otherwise retries
+/// the notification, so send `response` before doing any slow work with `event`.
+#[derive(Debug)]
+pub struct Handled {
+    pub response: Response<Bytes>,
+    pub event: Result<WebhookEvent, WebhookError>,
+}
+
+#[derive(Debug)]
+pub struct WebhookHandler {
+    secret: RwLock<RotatingSecret>,
+    replay: Option<ReplayGuard>,
+}
+
+impl WebhookHandler {
+    /// Guards against replays with the default [`ReplayGuard`].
+    pub fn new(secret: impl Into<RotatingSecret>) -> Self {
+        Self {
+            secret: RwLock::new(secret.into()),
+            replay: Some(ReplayGuard::new()),
+        }
+    }
+
+    /// See [`RotatingSecret::rotate`]. Takes `&self` so a handler shared with a
+    /// running [`WebhookServer`] can be rotated.
+    pub fn rotate_secret(&self, secret: Secret, window: std::time::Duration) {
+        self.secret.write().unwrap().rotate(secret, window);
+    }
+
+    pub fn replay_guard(mut self, guard: ReplayGuard) -> Self {
+        self.replay = Some(guard);
+        self
+    }
+
+    /// Accepts old and repeated messages.
+    pub fn without_replay_guard(mut self) -> Self {
+        self.replay = None;
+        self
+    }
+
+    /// Verifies the signature and decodes the request.
+    ///
+    /// - `403 Forbidden` when the signature is missing or wrong, or the message is too old
+    /// - `204 No Content` without an event for a message that was already processed
+    /// - `400 Bad Request` when the message type or body cannot be decoded
+    /// - `200 OK` with the challenge as plain text for a callback verification
+    /// - `204 No Content` for notifications and revocations
+    pub fn handle(&self, request: &Request<Bytes>) -> Handled {
+        let event = self.decode(request);
+        let response = match &event {
+            Ok(WebhookEvent::Verification { challenge, .. }) => Response::builder()
+                .status(StatusCode::OK)
+                .header(header::CONTENT_TYPE, "text/plain")
+                .body(Bytes::from(challenge.clone())),
+            Ok(_) => Response::builder()
+                .status(StatusCode::NO_CONTENT)
+                .body(Bytes::new()),
+            Err(e) => {
+                tracing::warn!("rejecting webhook request: {e}");
+                Response::builder().status(e.status()).body(Bytes::new())
+            }
+        }
+        .expect("static response parts are valid");
+
+        Handled { response, event }
+    }
+
+    fn decode(&self, request: &Request<Bytes>) -> Result<WebhookEvent, WebhookError> {
+        let headers = request.headers();
+        let body = request.body();
+        self.secret
+            .read()
+            .unwrap()
+            .verify_twitch_signature(headers, body)?;
+        if let Some(replay) = &self.replay {
+            replay.verify(headers)?;
+        }
+
+        let message_type: WebhookMessageType = header_str(request, TWITCH_MESSAGE_TYPE)?.parse()?;
+
+        match message_type {
+            WebhookMessageType::WebhookCallbackVerification => {
+                #[derive(Deserialize)]
+                struct Verification {
+                    challenge: String,
+                    subscription: Subscription,
+                }
+
+                let verification: Verification = serde_json::from_slice(body)?;
+                Ok(WebhookEvent::Verification {
+                    subscription: verification.subscription,
+                    challenge: verification.challenge,
+                })
+            }
+            WebhookMessageType::Notification => {
+                let payload: SubscriptionEventPayload<serde_json::Value> =
+                    serde_json::from_slice(body)?;
+                let message_timestamp = header_str(request, TWITCH_MESSAGE_TIMESTAMP)?;
+
+                Ok(WebhookEvent::Notification(WebhookNotification {
+                    message_id: MessageId::new(header_str(request, TWITCH_MESSAGE_ID)?),
+                    message_timestamp: DateTime::parse_from_rfc3339(message_timestamp).map_err(
+                        |_| WebhookError::InvalidHeader {
+                            name: TWITCH_MESSAGE_TIMESTAMP,
+                            value: message_timestamp.to_string(),
+                        },
+                    )?,
+                    subscription: payload.subscription,
+                    event: payload.event,
+                }))
+            }
+            WebhookMessageType::Revocation => {
+                let payload: SubscriptionPayload = serde_json::from_slice(body)?;
+                Ok(WebhookEvent::Revocation(payload.subscription))
+            }
+        }
+    }
+}
+
+fn header_str<'a>(
+    request: &'a Request<Bytes>,
+    name: &'static str,
+) -> Result<&'a str, WebhookError> {
+    let value = request
+        .headers()
+        .get(name)
+        .ok_or(WebhookError::MissingHeader(name))?;
+    value.to_str().map_err(|_| WebhookError::InvalidHeader {
+        name,
+        value: value.as_bytes().escape_ascii().to_string(),
+    })
+}
@@ -0,0 +1,132 @@
+use std::{collections::HashMap, fmt, sync::Mutex, time::Duration};
+
+use asknothingx2_util::api::HeaderMap;
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::twitch::{error::HeaderVerificationError, types::secret::Secret};
+
+/// Remembers message IDs that were already processed.
+pub trait SeenMessageStore: Send + Sync {
+    /// Records `message_id` until `expires_at`.
+    /// Returns `false` if it was already recorded.
+    fn insert(&self, message_id: &str, expires_at: DateTime<Utc>) -> bool;
+}
+
+/// Keeps message IDs in memory and drops them once they expire.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    seen: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SeenMessageStore for MemoryStore {
+    fn insert(&self, message_id: &str, expires_at: DateTime<Utc>) -> bool {
+        let mut seen = self.seen.lock().unwrap();
+        let now = Utc::now();
+        seen.retain(|_, expires_at| *expires_at > now);
+        seen.insert(message_id.to_string(), expires_at).is_none()
+    }
+}
+
+/// <https://dev.twitch.tv/docs/eventsub/handling-webhook-events/#guarding-against-replay-attacks>
+///
+/// Rejects messages older than `max_age`, timestamps further than `clock_skew`
+/// in the future, and message IDs the store has already seen.
+/// Run it after the signature was verified so forged requests never reach the store.
+pub struct ReplayGuard {
+    max_age: Duration,
+    clock_skew: Duration,
+    store: Box<dyn SeenMessageStore>,
+}
+
+impl ReplayGuard {
+    /// Ten minutes max age, one minute clock skew, in-memory store.
+    pub fn new() -> Self {
+        Self {
+            max_age: Duration::from_secs(10 * 60),
+            clock_skew: Duration::from_secs(60),
+            store: Box::new(MemoryStore::new()),
+        }
+    }
+
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    pub fn clock_skew(mut self, clock_skew: Duration) -> Self {
+        self.clock_skew = clock_skew;
+        self
+    }
+
+    pub fn store(mut self, store: impl SeenMessageStore + 'static) -> Self {
+        self.store = Box::new(store);
+        self
+    }
+
+    pub fn verify(&self, headers: &HeaderMap) -> Result<(), HeaderVerificationError> {
+        self.verify_at(headers, Utc::now())
+    }
+
+    pub fn verify_at(
+        &self,
+        headers: &HeaderMap,
+        now: DateTime<Utc>,
+    ) -> Result<(), HeaderVerificationError> {
+        let (message_id, timestamp, _) = Secret::verify_header(headers)?;
+
+        let sent_at = DateTime::parse_from_rfc3339(timestamp)
+            .map_err(|_| HeaderVerificationError::InvalidTimestamp(timestamp.to_string()))?
+            .with_timezone(&Utc);
+
+        let max_age = to_delta(self.max_age);
+        let age = now - sent_at;
+        if age > max_age {
+            return Err(HeaderVerificationError::MessageExpired {
+                age: age.to_std().unwrap_or_default(),
+                max_age: self.max_age,
+            });
+        }
+        if -age > to_delta(self.clock_skew) {
+            return Err(HeaderVerificationError::TimestampInFuture(
+                (-age).to_std().unwrap_or_default(),
+            ));
+        }
+
+        // Older copies are rejected by age, so the ID only has to be kept until then.
+        let expires_at = sent_at
+            .checked_add_signed(max_age)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        if !self.store.insert(message_id, expires_at) {
+            return Err(HeaderVerificationError::DuplicateMessage(
+                message_id.to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for ReplayGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ReplayGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReplayGuard")
+            .field("max_age", &self.max_age)
+            .field("clock_skew", &self.clock_skew)
+            .finish_non_exhaustive()
+    }
+}
+
+fn to_delta(duration: Duration) -> TimeDelta {
+    TimeDelta::from_std(duration).unwrap_or(TimeDelta::MAX)
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use http::{header, uri::PathAndQuery, Request, Response};
+use http_body_util::{BodyExt, Full};
+use hyper::client::conn::http1;
+use hyper_util::rt::TokioIo;
+use serde::Serialize;
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpStream,
+};
+use tokio_rustls::{
+    rustls::{pki_types::ServerName, ClientConfig},
+    TlsConnector,
+};
+use url::Url;
+
+use crate::twitch::{
+    error::SenderError,
+    types::{secret::Secret, Subscription},
+};
+
+use super::MessageSigner;
+
+/// POSTs signed messages to a local callback, like `twitch event trigger`.
+///
+/// ```rust,ignore
+/// let sender = FakeSender::new("http://localhost:8080/eventsub".parse()?, secret);
+/// let response = sender.notification(&subscription, &event).await?;
+/// assert_eq!(response.status(), StatusCode::NO_CONTENT);
+/// ```
+#[derive(Debug)]
+pub struct FakeSender {
+    url: Url,
+    signer: MessageSigner,
+    tls: Option<Arc<ClientConfig>>,
+}
+
+impl FakeSender {
+    pub fn new(url: Url, secret: Secret) -> Self {
+        Self {
+            url,
+            signer: MessageSigner::new(secret),
+            tls: None,
+        }
+    }
+
+    /// Required for `https` URLs, e.g. to trust a self-signed certificate.
+    pub fn tls_config(mut self, config: Arc<ClientConfig>) -> Self {
+        self.tls = Some(config);
+        self
+    }
+
+    pub fn signer(&self) -> &MessageSigner {
+        &self.signer
+    }
+
+    pub async fn verification(
+        &self,
+        subscription: &Subscription,
+        challenge: &str,
+    ) -> Result<Response<Bytes>, SenderError> {
+        self.send(self.signer.verification(subscription, challenge))
+            .await
+    }
+
+    pub async fn notification<E: Serialize>(
+        &self,
+        subscription: &Subscription,
+        event: &E,
+    ) -> Result<Response<Bytes>, SenderError> {
+        self.send(self.signer.notification(subscription, event))
+            .await
+    }
+
+    pub async fn revocation(
+        &self,
+        subscription: &Subscription,
+    ) -> Result<Response<Bytes>, SenderError> {
+        self.send(self.signer.revocation(subscription)).await
+    }
+
+    /// Sends `request` to the URL, keeping its headers and body.
+    pub async fn send(&self, mut request: Request<Bytes>) -> Result<Response<Bytes>, SenderError> {
+        let host = self
+            .url
+            .host_str()
+            .ok_or_else(|| SenderError::InvalidUrl(self.url.to_string()))?
+            .to_string();
+        let port = self
+            .url
+            .port_or_known_default()
+            .ok_or_else(|| SenderError::InvalidUrl(self.url.to_string()))?;
+
+        let path = match self.url.query() {
+            Some(query) => format!("{}?{query}", self.url.path()),
+            None => self.url.path().to_string(),
+        };
+        *request.uri_mut() = PathAndQuery::try_from(path)
+            .map_err(|_| SenderError::InvalidUrl(self.url.to_string()))?
+            .into();
+        // `port()` is only set when it isn't the scheme's default.
+        let authority = match self.url.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.clone(),
+        };
+        request.headers_mut().insert(
+            header::HOST,
+            authority
+                .parse()
+                .map_err(|_| SenderError::InvalidUrl(self.url.to_string()))?,
+        );
+
+        let tcp = TcpStream::connect((host.as_str(), port)).await?;
+        match self.url.scheme() {
+            "http" => send(tcp, request).await,
+            "https" => {
+                let config = self.tls.clone().ok_or(SenderError::MissingTlsConfig)?;
+                let name = ServerName::try_from(host)
+                    .map_err(|_| SenderError::InvalidUrl(self.url.to_string()))?;
+                let tls = TlsConnector::from(config).connect(name, tcp).await?;
+                send(tls, request).await
+            }
+            _ => Err(SenderError::InvalidUrl(self.url.to_string())),
+        }
+    }
+}
+
+async fn send<S>(stream: S, request: Request<Bytes>) -> Result<Response<Bytes>, SenderError>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let (mut sender, connection) = http1::handshake(TokioIo::new(stream)).await?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::debug!("fake sender connection error: {e}");
+        }
+    });
+
+    let response = sender.send_request(request.map(Full::new)).await?;
+    let (parts, body) = response.into_parts();
+    let body = body.collect().await?.to_bytes();
+
+    Ok(Response::from_parts(parts, body))
+}
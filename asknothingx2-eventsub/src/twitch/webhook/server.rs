@@ -0,0 +1,251 @@
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    path::Path,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use futures_util::Stream;
+use http::{Request, Response, StatusCode};
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::{body::Incoming, server::conn::http1, service::service_fn};
+use hyper_util::rt::TokioIo;
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpListener,
+    sync::mpsc,
+    task::JoinHandle,
+    time,
+};
+use tokio_rustls::{
+    rustls::{
+        crypto::ring,
+        pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
+        ServerConfig,
+    },
+    TlsAcceptor,
+};
+
+use crate::twitch::error::ServerError;
+
+use super::{WebhookEvent, WebhookHandler};
+
+/// Largest request body accepted; Twitch notifications are far smaller.
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+/// Pause after a failed accept, e.g. when out of file descriptors.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// An HTTPS server that feeds callback requests to a [`WebhookHandler`].
+///
+/// Twitch only delivers to HTTPS callbacks on port 443. Without TLS configured the
+/// server speaks plain HTTP, for use behind a reverse proxy that terminates TLS.
+///
+/// ```rust,ignore
+/// let mut events = WebhookServer::new(WebhookHandler::new(secret))
+///     .bind("0.0.0.0:443".parse()?)
+///     .path("/eventsub")
+///     .tls_files("cert.pem", "key.pem")?
+///     .start()
+///     .await?;
+///
+/// while let Some(event) = events.next().await {
+///     println!("{event:?}");
+/// }
+/// ```
+///
+/// Pass the handler in an [`Arc`] and keep a clone to rotate its secret while
+/// the server runs.
+#[derive(Debug)]
+pub struct WebhookServer {
+    handler: Arc<WebhookHandler>,
+    addr: SocketAddr,
+    path: String,
+    tls: Option<Arc<ServerConfig>>,
+}
+
+impl WebhookServer {
+    pub fn new(handler: impl Into<Arc<WebhookHandler>>) -> Self {
+        Self {
+            handler: handler.into(),
+            addr: SocketAddr::from(([0, 0, 0, 0], 443)),
+            path: "/".to_string(),
+            tls: None,
+        }
+    }
+
+    /// Defaults to `0.0.0.0:443`.
+    pub fn bind(mut self, addr: SocketAddr) -> Self {
+        self.addr = addr;
+        self
+    }
+
+    /// The callback path; other paths get `404 Not Found`. Defaults to `/`.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// PEM encoded certificate chain and private key.
+    pub fn tls(mut self, cert_chain: &[u8], private_key: &[u8]) -> Result<Self, ServerError> {
+        let certs = CertificateDer::pem_slice_iter(cert_chain).collect::<Result<Vec<_>, _>>()?;
+        let key = PrivateKeyDer::from_pem_slice(private_key)?;
+
+        let config = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()?
+            .with_no_client_auth()
+            .with_single_cert(certs, key)?;
+
+        self.tls = Some(Arc::new(config));
+        Ok(self)
+    }
+
+    pub fn tls_files(
+        self,
+        cert_chain: impl AsRef<Path>,
+        private_key: impl AsRef<Path>,
+    ) -> Result<Self, ServerError> {
+        let cert_chain = std::fs::read(cert_chain)?;
+        let private_key = std::fs::read(private_key)?;
+        self.tls(&cert_chain, &private_key)
+    }
+
+    pub fn tls_config(mut self, config: Arc<ServerConfig>) -> Self {
+        self.tls = Some(config);
+        self
+    }
+
+    /// Binds the address and serves in the background until the stream is dropped.
+    pub async fn start(self) -> Result<WebhookEvents, ServerError> {
+        let listener = TcpListener::bind(self.addr).await?;
+        let local_addr = listener.local_addr()?;
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let service = Arc::new(Service {
+            handler: self.handler,
+            path: self.path,
+            tx,
+        });
+        let acceptor = self.tls.map(TlsAcceptor::from);
+
+        let task = tokio::spawn(async move {
+            loop {
+                let (tcp, peer) = match listener.accept().await {
+                    Ok(accepted) => accepted,
+                    Err(e) => {
+                        tracing::warn!("failed to accept connection: {e}");
+                        time::sleep(ACCEPT_BACKOFF).await;
+                        continue;
+                    }
+                };
+
+                let service = service.clone();
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    match acceptor {
+                        Some(acceptor) => match acceptor.accept(tcp).await {
+                            Ok(tls) => serve(tls, service).await,
+                            Err(e) => tracing::debug!("TLS handshake with {peer} failed: {e}"),
+                        },
+                        None => serve(tcp, service).await,
+                    }
+                });
+            }
+        });
+
+        Ok(WebhookEvents {
+            local_addr,
+            rx,
+            task,
+        })
+    }
+}
+
+/// Events delivered to the server, in arrival order.
+/// Dropping it shuts the server down.
+#[derive(Debug)]
+pub struct WebhookEvents {
+    local_addr: SocketAddr,
+    rx: mpsc::UnboundedReceiver<WebhookEvent>,
+    task: JoinHandle<()>,
+}
+
+impl WebhookEvents {
+    /// The bound address, useful when binding to port 0.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Stream for WebhookEvents {
+    type Item = WebhookEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl Drop for WebhookEvents {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+struct Service {
+    handler: Arc<WebhookHandler>,
+    path: String,
+    tx: mpsc::UnboundedSender<WebhookEvent>,
+}
+
+impl Service {
+    async fn call(&self, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        if request.uri().path() != self.path {
+            return status(StatusCode::NOT_FOUND);
+        }
+        if request.method() != http::Method::POST {
+            return status(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        let (parts, body) = request.into_parts();
+        let body = match Limited::new(body, MAX_BODY_SIZE).collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(e) if e.is::<http_body_util::LengthLimitError>() => {
+                return status(StatusCode::PAYLOAD_TOO_LARGE)
+            }
+            Err(_) => return status(StatusCode::BAD_REQUEST),
+        };
+
+        let handled = self.handler.handle(&Request::from_parts(parts, body));
+        // Unbounded so a slow consumer never delays the response past Twitch's deadline.
+        if let Ok(event) = handled.event {
+            let _ = self.tx.send(event);
+        }
+        handled.response.map(Full::new)
+    }
+}
+
+async fn serve<S>(stream: S, service: Arc<Service>)
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let service = service_fn(move |request| {
+        let service = service.clone();
+        async move { Ok::<_, Infallible>(service.call(request).await) }
+    });
+
+    if let Err(e) = http1::Builder::new()
+        .serve_connection(TokioIo::new(stream), service)
+        .await
+    {
+        tracing::debug!("webhook connection error: {e}");
+    }
+}
+
+fn status(status: StatusCode) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::default());
+    *response.status_mut() = status;
+    response
+}
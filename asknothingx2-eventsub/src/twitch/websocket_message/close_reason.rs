@@ -0,0 +1,128 @@
+use std::fmt;
+
+use crate::twitch::types::Status;
+
+/// <https://dev.twitch.tv/docs/eventsub/handling-websocket-events/#close-message>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CloseReason {
+    /// 4000: Indicates a problem with the server (similar to an HTTP 500 status code).
+    InternalServerError,
+    /// 4001: Sending outgoing messages to the server is prohibited
+    /// with the exception of pong messages.
+    ClientSentInboundTraffic,
+    /// 4002: You must respond to ping messages with a pong message.
+    ClientFailedPingPong,
+    /// 4003: When you connect to the server,
+    /// you must create a subscription within 10 seconds
+    /// or the connection is closed.
+    ConnectionUnused,
+    /// 4004: When you receive a session_reconnect message,
+    /// you have 30 seconds to reconnect to the server and close the old connection.
+    ReconnectGraceTimeExpired,
+    /// 4005: Transient network timeout.
+    NetworkTimeout,
+    /// 4006: Transient network error.
+    NetworkError,
+    /// 4007: The reconnect URL is invalid.
+    InvalidReconnect,
+    /// Any other close code.
+    Other(u16),
+}
+
+/// What a client should do after the server closed the connection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CloseAction {
+    /// Open a new connection. The old session and its subscriptions are gone.
+    Reconnect,
+    /// Open a new connection and create subscriptions before the deadline.
+    /// Reconnecting without subscribing ends the same way.
+    Resubscribe,
+    /// The client is misbehaving; reconnecting will not help.
+    Fatal,
+}
+
+impl CloseReason {
+    pub fn from_code(code: u16) -> Self {
+        match code {
+            4000 => Self::InternalServerError,
+            4001 => Self::ClientSentInboundTraffic,
+            4002 => Self::ClientFailedPingPong,
+            4003 => Self::ConnectionUnused,
+            4004 => Self::ReconnectGraceTimeExpired,
+            4005 => Self::NetworkTimeout,
+            4006 => Self::NetworkError,
+            4007 => Self::InvalidReconnect,
+            code => Self::Other(code),
+        }
+    }
+
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::InternalServerError => 4000,
+            Self::ClientSentInboundTraffic => 4001,
+            Self::ClientFailedPingPong => 4002,
+            Self::ConnectionUnused => 4003,
+            Self::ReconnectGraceTimeExpired => 4004,
+            Self::NetworkTimeout => 4005,
+            Self::NetworkError => 4006,
+            Self::InvalidReconnect => 4007,
+            Self::Other(code) => *code,
+        }
+    }
+
+    pub fn action(&self) -> CloseAction {
+        match self {
+            Self::ClientSentInboundTraffic => CloseAction::Fatal,
+            Self::ConnectionUnused => CloseAction::Resubscribe,
+            Self::InternalServerError
+            | Self::ClientFailedPingPong
+            | Self::ReconnectGraceTimeExpired
+            | Self::NetworkTimeout
+            | Self::NetworkError
+            | Self::InvalidReconnect
+            | Self::Other(_) => CloseAction::Reconnect,
+        }
+    }
+
+    /// The status the subscriptions of the closed session end up with.
+    pub fn status(&self) -> Option<Status> {
+        match self {
+            Self::InternalServerError => Some(Status::WebsocketInternalError),
+            Self::ClientSentInboundTraffic => Some(Status::WebsocketReceivedInboundTraffic),
+            Self::ClientFailedPingPong => Some(Status::WebsocketFailedPingPong),
+            Self::ConnectionUnused => Some(Status::WebsocketConnectionUnused),
+            Self::ReconnectGraceTimeExpired | Self::InvalidReconnect => {
+                Some(Status::WebsocketFailedToReconnect)
+            }
+            Self::NetworkTimeout => Some(Status::WebsocketNetworkTimeout),
+            Self::NetworkError => Some(Status::WebsocketNetworkError),
+            Self::Other(_) => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::InternalServerError => "internal server error",
+            Self::ClientSentInboundTraffic => "client sent inbound traffic",
+            Self::ClientFailedPingPong => "client failed ping-pong",
+            Self::ConnectionUnused => "connection unused",
+            Self::ReconnectGraceTimeExpired => "reconnect grace time expired",
+            Self::NetworkTimeout => "network timeout",
+            Self::NetworkError => "network error",
+            Self::InvalidReconnect => "invalid reconnect",
+            Self::Other(_) => "unknown",
+        }
+    }
+}
+
+impl From<u16> for CloseReason {
+    fn from(code: u16) -> Self {
+        Self::from_code(code)
+    }
+}
+
+impl fmt::Display for CloseReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.as_str(), self.code())
+    }
+}
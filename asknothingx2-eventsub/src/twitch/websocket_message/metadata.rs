@@ -3,13 +3,14 @@ use std::{fmt, str};
 use chrono::{DateTime, FixedOffset};
 use serde::{de::Expected, Deserialize, Serialize};
 
-use crate::twitch::types::{MessageId, SubscriptionType};
+use crate::twitch::types::{timestamp::rfc3339_nanos, MessageId, SubscriptionType};
 
 /// <https://dev.twitch.tv/docs/eventsub/websocket-reference>
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct MetaData {
     pub message_id: MessageId,
     pub message_type: MessageType,
+    #[serde(with = "rfc3339_nanos")]
     pub message_timestamp: DateTime<FixedOffset>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_type: Option<SubscriptionType>,
@@ -26,6 +27,7 @@ impl<'de> Deserialize<'de> for MetaData {
         struct Helper {
             message_id: MessageId,
             message_type: MessageType,
+            #[serde(with = "rfc3339_nanos")]
             message_timestamp: DateTime<FixedOffset>,
             subscription_type: Option<SubscriptionType>,
             subscription_version: Option<String>,
@@ -44,6 +46,12 @@ impl<'de> Deserialize<'de> for MetaData {
                 (SubscriptionType::ChannelModerate, Some("2")) => {
                     SubscriptionType::ChannelModerateV2
                 }
+                (SubscriptionType::Unknown { kind, .. }, Some(version)) => {
+                    SubscriptionType::Unknown {
+                        kind,
+                        version: version.to_string(),
+                    }
+                }
                 (kind, _) => kind,
             }
         });
@@ -58,7 +66,7 @@ impl<'de> Deserialize<'de> for MetaData {
     }
 }
 
-#[derive(Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum MessageType {
     SessionWelcome,
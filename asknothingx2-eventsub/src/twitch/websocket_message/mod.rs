@@ -1,15 +1,26 @@
 //! <https://dev.twitch.tv/docs/eventsub/handling-websocket-events>
 //! NOTE All timestamps are in RFC3339 format and use nanoseconds instead of milliseconds.
+use std::str;
+
 use asknothingx2_util::api::EmptyObjectBody;
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
-use crate::twitch::types::payloads::SubscriptionPayload;
+use crate::twitch::{
+    error::WebSocketError,
+    types::{
+        payloads::{SubscriptionEventPayload, SubscriptionPayload},
+        timestamp::rfc3339_nanos,
+        Condition,
+    },
+};
 
 use super::types::SessionId;
 
+mod close_reason;
 mod metadata;
 
+pub use close_reason::{CloseAction, CloseReason};
 pub use metadata::{MessageType, MetaData};
 
 macro_rules! twitch_websocket_message {
@@ -40,6 +51,20 @@ macro_rules! twitch_websocket_message {
             pub payload: $generic,
         }
     };
+    (
+        $(#[$meta:meta])*
+        $name:ident<$generic:ident = $default:ty>,
+        $(#[$field_meta:meta])*
+        $payload:ident
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, serde::Serialize)]
+        pub struct $name<$generic = $default> {
+            pub metadata: MetaData,
+            $(#[$field_meta])*
+            pub payload: $payload<$generic>,
+        }
+    };
     (
         $(#[$meta:meta])*
         $name:ident<$generic:ident>,
@@ -80,7 +105,7 @@ twitch_websocket_message!(
 );
 twitch_websocket_message!(
     /// <https://dev.twitch.tv/docs/eventsub/handling-websocket-events/#revocation-message>
-    Revocation,
+    Revocation<C = Condition>,
     SubscriptionPayload
 );
 
@@ -90,18 +115,24 @@ twitch_websocket_message!(
 );
 
 macro_rules! twitch_websocket_message_deserialize {
-    ($struct:ident, $name:literal, $payload:ident, $message_type:ident) => {
-        impl<'de> Deserialize<'de> for $struct {
+    ($struct:ident$(<$generic:ident>)?, $name:literal, $payload:ty, $message_type:ident) => {
+        impl<'de $(, $generic)?> Deserialize<'de> for $struct$(<$generic>)?
+        where
+            $payload: Deserialize<'de>,
+        {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
                 use serde::de::{Error, Unexpected, Visitor};
 
-                struct EventVisitor;
+                struct EventVisitor$(<$generic>)?$((std::marker::PhantomData<$generic>))?;
 
-                impl<'de> Visitor<'de> for EventVisitor {
-                    type Value = $struct;
+                impl<'de $(, $generic)?> Visitor<'de> for EventVisitor$(<$generic>)?
+                where
+                    $payload: Deserialize<'de>,
+                {
+                    type Value = $struct$(<$generic>)?;
 
                     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
                         formatter.write_str($name)
@@ -152,7 +183,7 @@ macro_rules! twitch_websocket_message_deserialize {
                     }
                 }
 
-                deserializer.deserialize_map(EventVisitor)
+                deserializer.deserialize_map(EventVisitor$((std::marker::PhantomData::<$generic>))?)
             }
         }
     };
@@ -161,7 +192,12 @@ macro_rules! twitch_websocket_message_deserialize {
 twitch_websocket_message_deserialize!(Welcome, "Welcome", SessionPayload, SessionWelcome);
 twitch_websocket_message_deserialize!(Keepalive, "Keepalive", EmptyObjectBody, SessionKeepalive);
 twitch_websocket_message_deserialize!(Reconnect, "Reconnect", SessionPayload, SessionReconnect);
-twitch_websocket_message_deserialize!(Revocation, "Revocation", SubscriptionPayload, Revocation);
+twitch_websocket_message_deserialize!(
+    Revocation<C>,
+    "Revocation",
+    SubscriptionPayload<C>,
+    Revocation
+);
 
 impl<'de, Payload: Deserialize<'de>> Deserialize<'de> for Notification<Payload> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -231,17 +267,75 @@ impl<'de, Payload: Deserialize<'de>> Deserialize<'de> for Notification<Payload>
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SessionPayload {
     pub session: Session,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Session {
     /// sesion_id
     pub id: SessionId,
     pub status: String,
     pub keepalive_timeout_seconds: Option<u64>,
     pub reconnect_url: Option<String>,
+    #[serde(with = "rfc3339_nanos")]
     pub connected_at: DateTime<FixedOffset>,
 }
+
+/// A notification whose event has not been decoded into a concrete type yet.
+pub type RawNotification = Notification<SubscriptionEventPayload<serde_json::Value>>;
+
+/// Any message the server sends over an EventSub WebSocket connection.
+#[derive(Debug)]
+pub enum WebSocketMessage {
+    Welcome(Welcome),
+    Keepalive(Keepalive),
+    Notification(RawNotification),
+    Reconnect(Reconnect),
+    Revocation(Revocation),
+}
+
+impl WebSocketMessage {
+    pub fn metadata(&self) -> &MetaData {
+        match self {
+            Self::Welcome(message) => &message.metadata,
+            Self::Keepalive(message) => &message.metadata,
+            Self::Notification(message) => &message.metadata,
+            Self::Reconnect(message) => &message.metadata,
+            Self::Revocation(message) => &message.metadata,
+        }
+    }
+
+    pub fn message_type(&self) -> &MessageType {
+        &self.metadata().message_type
+    }
+}
+
+impl str::FromStr for WebSocketMessage {
+    type Err = WebSocketError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: serde_json::Value = serde_json::from_str(s)?;
+
+        let message_type = value
+            .get("metadata")
+            .and_then(|metadata| metadata.get("message_type"))
+            .and_then(serde_json::Value::as_str)
+            .ok_or(WebSocketError::MissingField("metadata.message_type"))?;
+
+        let message_type: MessageType = message_type
+            .parse()
+            .map_err(|_| WebSocketError::InvalidMessageType(message_type.to_string()))?;
+
+        let message = match message_type {
+            MessageType::SessionWelcome => Self::Welcome(serde_json::from_value(value)?),
+            MessageType::SessionKeepalive => Self::Keepalive(serde_json::from_value(value)?),
+            MessageType::Notification => Self::Notification(serde_json::from_value(value)?),
+            MessageType::SessionReconnect => Self::Reconnect(serde_json::from_value(value)?),
+            MessageType::Revocation => Self::Revocation(serde_json::from_value(value)?),
+        };
+
+        Ok(message)
+    }
+}
@@ -0,0 +1,87 @@
+use asknothingx2_eventsub::twitch::{
+    request::{GetConduitShardsRequest, ShardUpdate, UpdateConduitShardsRequest},
+    response::{ConduitShardsResponse, ConduitsResponse, UpdateConduitShardsResponse},
+    types::{ConduitId, SessionId, Status, Transport},
+    EventSubAPI,
+};
+use asknothingx2_util::{
+    api::Method,
+    oauth::{AccessToken, ClientId},
+};
+use twitch_highway::{request::IntoRequestBody, TwitchAPI};
+
+fn api() -> TwitchAPI {
+    TwitchAPI::new(
+        AccessToken::new("access_token".to_string()),
+        ClientId::new("client_id".to_string()),
+    )
+}
+
+#[test]
+fn conduit_requests() {
+    let request = api().create_conduit(5);
+    assert_eq!(request.method(), &Method::POST);
+    assert_eq!(
+        request.url().as_str(),
+        "https://api.twitch.tv/helix/eventsub/conduits"
+    );
+    assert_eq!(request.body().as_body().unwrap(), "{\"shard_count\":5}");
+
+    let request = api().update_conduit(ConduitId::new("bfcfc993"), 10);
+    assert_eq!(request.method(), &Method::PATCH);
+    assert_eq!(
+        request.body().as_body().unwrap(),
+        "{\"id\":\"bfcfc993\",\"shard_count\":10}"
+    );
+
+    let request = api().delete_conduit(ConduitId::new("bfcfc993"));
+    assert_eq!(request.method(), &Method::DELETE);
+    assert_eq!(
+        request.url().as_str(),
+        "https://api.twitch.tv/helix/eventsub/conduits?id=bfcfc993"
+    );
+}
+
+#[test]
+fn shard_requests() {
+    let request = api().get_conduit_shards(
+        GetConduitShardsRequest {
+            conduit_id: ConduitId::new("bfcfc993"),
+            status: Some(Status::Enabled),
+        },
+        None,
+    );
+    assert_eq!(
+        request.url().as_str(),
+        "https://api.twitch.tv/helix/eventsub/conduits/shards?conduit_id=bfcfc993&status=enabled"
+    );
+
+    let request = api().update_conduit_shards(UpdateConduitShardsRequest {
+        conduit_id: ConduitId::new("bfcfc993"),
+        shards: vec![ShardUpdate::new(
+            "0",
+            Transport::websocket(SessionId::new("AQoQILE98gtqShGmLD7AM6yJThAB")),
+        )],
+    });
+    let body = request.body().as_body().unwrap();
+    assert!(body.contains("\"conduit_id\":\"bfcfc993\""));
+    assert!(body.contains("\"id\":\"0\""));
+    assert!(body.contains("\"session_id\":\"AQoQILE98gtqShGmLD7AM6yJThAB\""));
+}
+
+#[test]
+fn responses() {
+    let conduits: ConduitsResponse = serde_json::from_str(
+        "{\"data\": [{\"id\": \"26b1c993-bfcf-44d9-b876-379dacafe75a\", \"shard_count\": 15}]}",
+    )
+    .unwrap();
+    assert_eq!(conduits.data[0].shard_count, 15);
+
+    let shards: ConduitShardsResponse = serde_json::from_str("{\"data\": [{\"id\": \"0\", \"status\": \"enabled\", \"transport\": {\"method\": \"webhook\", \"callback\": \"https://this-is-a-callback.com\"}}, {\"id\": \"1\", \"status\": \"websocket_disconnected\", \"transport\": {\"method\": \"websocket\", \"session_id\": \"9fd5164a-a958-4c60-b7f4-6a7202506ca0\", \"connected_at\": \"2020-11-10T14:32:18.730260295Z\", \"disconnected_at\": \"2020-11-11T14:32:18.730260295Z\"}}], \"pagination\": {}}").unwrap();
+    assert_eq!(shards.data.len(), 2);
+    assert_eq!(shards.data[1].status, Status::WebsocketDisconnected);
+    assert!(shards.pagination.is_none());
+
+    let updated: UpdateConduitShardsResponse = serde_json::from_str("{\"data\": [{\"id\": \"0\", \"status\": \"enabled\", \"transport\": {\"method\": \"webhook\", \"callback\": \"https://this-is-a-callback.com\"}}], \"errors\": [{\"id\": \"3\", \"message\": \"The shard id is outside the conduit's range\", \"code\": \"invalid_parameter\"}]}").unwrap();
+    assert_eq!(updated.errors[0].id.as_str(), "3");
+}
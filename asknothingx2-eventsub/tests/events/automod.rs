@@ -0,0 +1,155 @@
+use asknothingx2_eventsub::twitch::{
+    events::{
+        automod::{Boundary, HoldReason, MessageStatus, TermsUpdateAction},
+        chat::Fragment,
+    },
+    subscription_types::automod::{
+        AutomodMessageHoldPayload, AutomodMessageHoldV2Payload, AutomodMessageUpdatePayload,
+        AutomodMessageUpdateV2Payload, AutomodSettingsUpdatePayload, AutomodTermsUpdatePayload,
+    },
+};
+
+use super::notification;
+
+const CONDITION: &str = "{\"broadcaster_user_id\": \"1337\", \"moderator_user_id\": \"9001\"}";
+
+#[test]
+fn message_hold() {
+    let payload: AutomodMessageHoldPayload =
+        notification("automod.message.hold", "1", CONDITION, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"blah\", \"broadcaster_user_name\": \"Blah\", \"user_id\": \"456789012\", \"user_login\": \"baduser\", \"user_name\": \"BadUser\", \"message_id\": \"bad-message-id\", \"message\": {\"text\": \"This is a bad message… pogchamp\", \"fragments\": [{\"type\": \"text\", \"text\": \"This is a bad message… \", \"emote\": null, \"cheermote\": null}, {\"type\": \"emote\", \"text\": \"pogchamp\", \"emote\": {\"id\": \"899\", \"emote_set_id\": \"1\"}, \"cheermote\": null}]}, \"category\": \"aggressive\", \"level\": 1, \"held_at\": \"2022-12-02T15:00:00.00Z\"}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.message_id, "bad-message-id");
+    assert_eq!(event.message.text, "This is a bad message… pogchamp");
+    assert!(matches!(event.message.fragments[1], Fragment::Emote { .. }));
+    assert_eq!(event.category, "aggressive");
+    assert_eq!(event.level, 1);
+}
+
+#[test]
+fn message_hold_v2_automod() {
+    let payload: AutomodMessageHoldV2Payload =
+        notification("automod.message.hold", "2", CONDITION, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"blah\", \"broadcaster_user_name\": \"Blah\", \"user_id\": \"456789012\", \"user_login\": \"baduser\", \"user_name\": \"BadUser\", \"message_id\": \"bad-message-id\", \"message\": {\"text\": \"damn this\", \"fragments\": [{\"type\": \"text\", \"text\": \"damn this\", \"emote\": null, \"cheermote\": null}]}, \"reason\": \"automod\", \"automod\": {\"category\": \"swearing\", \"level\": 4, \"boundaries\": [{\"start_pos\": 0, \"end_pos\": 3}]}, \"blocked_term\": null, \"held_at\": \"2022-12-02T15:00:00.00Z\"}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.reason.tag(), "automod");
+    let HoldReason::Automod(automod) = &event.reason else {
+        panic!("expected an automod reason");
+    };
+    assert_eq!(automod.category, "swearing");
+    assert_eq!(automod.level, 4);
+    assert_eq!(
+        automod.boundaries,
+        [Boundary {
+            start_pos: 0,
+            end_pos: 3
+        }]
+    );
+}
+
+#[test]
+fn message_hold_v2_blocked_term() {
+    let payload: AutomodMessageHoldV2Payload =
+        notification("automod.message.hold", "2", CONDITION, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"blah\", \"broadcaster_user_name\": \"Blah\", \"user_id\": \"456789012\", \"user_login\": \"baduser\", \"user_name\": \"BadUser\", \"message_id\": \"bad-message-id\", \"message\": {\"text\": \"damn this\", \"fragments\": [{\"type\": \"text\", \"text\": \"damn this\", \"emote\": null, \"cheermote\": null}]}, \"reason\": \"blocked_term\", \"automod\": null, \"blocked_term\": {\"terms_found\": [{\"term_id\": \"123\", \"boundary\": {\"start_pos\": 0, \"end_pos\": 3}, \"owner_broadcaster_user_id\": \"1337\", \"owner_broadcaster_user_login\": \"blah\", \"owner_broadcaster_user_name\": \"Blah\"}, {\"term_id\": \"456\", \"boundary\": {\"start_pos\": 10, \"end_pos\": 14}, \"owner_broadcaster_user_id\": \"2468\", \"owner_broadcaster_user_login\": \"partner\", \"owner_broadcaster_user_name\": \"Partner\"}]}, \"held_at\": \"2022-12-02T15:00:00.00Z\"}");
+    let event = payload.payload.event;
+
+    let HoldReason::BlockedTerm(blocked) = &event.reason else {
+        panic!("expected a blocked term reason");
+    };
+    assert_eq!(blocked.terms_found.len(), 2);
+    assert_eq!(blocked.terms_found[0].term_id, "123");
+    assert_eq!(blocked.terms_found[1].boundary.start_pos, 10);
+    assert_eq!(
+        blocked.terms_found[1].owner_broadcaster_user_login,
+        "partner"
+    );
+}
+
+#[test]
+fn message_update() {
+    let payload: AutomodMessageUpdatePayload =
+        notification("automod.message.update", "1", CONDITION, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"blah\", \"broadcaster_user_name\": \"Blah\", \"user_id\": \"456789012\", \"user_login\": \"baduser\", \"user_name\": \"BadUser\", \"moderator_user_id\": \"9001\", \"moderator_user_login\": \"the_mod\", \"moderator_user_name\": \"The_Mod\", \"message_id\": \"bad-message-id\", \"message\": {\"text\": \"This is a bad message… pogchamp\", \"fragments\": [{\"type\": \"text\", \"text\": \"This is a bad message… \", \"emote\": null, \"cheermote\": null}, {\"type\": \"emote\", \"text\": \"pogchamp\", \"emote\": {\"id\": \"899\", \"emote_set_id\": \"1\"}, \"cheermote\": null}]}, \"category\": \"aggressive\", \"level\": 1, \"status\": \"approved\", \"held_at\": \"2022-12-02T15:00:00.00Z\"}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.moderator_user_login, "the_mod");
+    assert_eq!(event.status, MessageStatus::Approved);
+    assert_eq!(event.level, 1);
+}
+
+#[test]
+fn message_update_v2() {
+    let payload: AutomodMessageUpdateV2Payload =
+        notification("automod.message.update", "2", CONDITION, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"blah\", \"broadcaster_user_name\": \"Blah\", \"user_id\": \"456789012\", \"user_login\": \"baduser\", \"user_name\": \"BadUser\", \"moderator_user_id\": \"9001\", \"moderator_user_login\": \"the_mod\", \"moderator_user_name\": \"The_Mod\", \"message_id\": \"bad-message-id\", \"message\": {\"text\": \"damn this\", \"fragments\": [{\"type\": \"text\", \"text\": \"damn this\", \"emote\": null, \"cheermote\": null}]}, \"reason\": \"blocked_term\", \"automod\": null, \"blocked_term\": {\"terms_found\": [{\"term_id\": \"123\", \"boundary\": {\"start_pos\": 0, \"end_pos\": 3}, \"owner_broadcaster_user_id\": \"1337\", \"owner_broadcaster_user_login\": \"blah\", \"owner_broadcaster_user_name\": \"Blah\"}, {\"term_id\": \"456\", \"boundary\": {\"start_pos\": 10, \"end_pos\": 14}, \"owner_broadcaster_user_id\": \"2468\", \"owner_broadcaster_user_login\": \"partner\", \"owner_broadcaster_user_name\": \"Partner\"}]}, \"status\": \"expired\", \"held_at\": \"2022-12-02T15:00:00.00Z\"}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.status, MessageStatus::Expired);
+    assert!(matches!(event.reason, HoldReason::BlockedTerm(_)));
+}
+
+#[test]
+fn message_hold_v2_unknown_reason() {
+    let payload: AutomodMessageHoldV2Payload =
+        notification("automod.message.hold", "2", CONDITION, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"blah\", \"broadcaster_user_name\": \"Blah\", \"user_id\": \"456789012\", \"user_login\": \"baduser\", \"user_name\": \"BadUser\", \"message_id\": \"bad-message-id\", \"message\": {\"text\": \"damn this\", \"fragments\": [{\"type\": \"text\", \"text\": \"damn this\", \"emote\": null, \"cheermote\": null}]}, \"reason\": \"spam\", \"automod\": null, \"spam\": {\"score\": 97}, \"blocked_term\": null, \"held_at\": \"2022-12-02T15:00:00.00Z\"}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.reason.tag(), "spam");
+    let HoldReason::Unknown { kind, data } = &event.reason else {
+        panic!("expected an unknown reason: {:?}", event.reason);
+    };
+    assert_eq!(kind, "spam");
+    assert_eq!(data["spam"]["score"], 97);
+}
+
+#[test]
+fn message_status() {
+    assert_eq!(
+        serde_json::from_str::<MessageStatus>("\"denied\"").unwrap(),
+        MessageStatus::Denied
+    );
+    assert_eq!(
+        serde_json::to_string(&MessageStatus::Denied).unwrap(),
+        "\"denied\""
+    );
+
+    let status = serde_json::from_str::<MessageStatus>("\"pending\"").unwrap();
+    assert_eq!(status, MessageStatus::Unknown("pending".to_string()));
+    assert_eq!(serde_json::to_string(&status).unwrap(), "\"pending\"");
+}
+
+#[test]
+fn settings_update() {
+    let payload: AutomodSettingsUpdatePayload =
+        notification("automod.settings.update", "1", CONDITION, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"blah\", \"broadcaster_user_name\": \"Blah\", \"moderator_user_id\": \"9001\", \"moderator_user_login\": \"the_mod\", \"moderator_user_name\": \"The_Mod\", \"overall_level\": null, \"disability\": 0, \"aggression\": 0, \"sexuality_sex_or_gender\": 1, \"misogyny\": 2, \"bullying\": 0, \"swearing\": 4, \"race_ethnicity_or_religion\": 1, \"sex_based_terms\": 3}");
+    let event = payload.payload.event;
+
+    assert!(event.overall_level.is_none());
+    assert_eq!(event.levels.bullying, 0);
+    assert_eq!(event.levels.misogyny, 2);
+    assert_eq!(event.levels.swearing, 4);
+    assert_eq!(event.levels.sex_based_terms, 3);
+}
+
+#[test]
+fn settings_update_overall() {
+    let payload: AutomodSettingsUpdatePayload =
+        notification("automod.settings.update", "1", CONDITION, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"blah\", \"broadcaster_user_name\": \"Blah\", \"moderator_user_id\": \"9001\", \"moderator_user_login\": \"the_mod\", \"moderator_user_name\": \"The_Mod\", \"overall_level\": 3, \"disability\": 3, \"aggression\": 3, \"sexuality_sex_or_gender\": 3, \"misogyny\": 3, \"bullying\": 3, \"swearing\": 3, \"race_ethnicity_or_religion\": 3, \"sex_based_terms\": 3}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.overall_level, Some(3));
+    assert_eq!(event.levels.aggression, 3);
+}
+
+#[test]
+fn terms_update() {
+    let payload: AutomodTermsUpdatePayload =
+        notification("automod.terms.update", "1", CONDITION, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"blah\", \"broadcaster_user_name\": \"Blah\", \"moderator_user_id\": \"9001\", \"moderator_user_login\": \"the_mod\", \"moderator_user_name\": \"The_Mod\", \"action\": \"add_blocked\", \"from_automod\": false, \"terms\": [\"word1\", \"word2\"]}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.action, TermsUpdateAction::AddBlocked);
+    assert_eq!(
+        serde_json::from_str::<TermsUpdateAction>("\"add_warned\"").unwrap(),
+        TermsUpdateAction::Unknown("add_warned".to_string())
+    );
+    assert!(!event.from_automod);
+    assert_eq!(event.terms, ["word1", "word2"]);
+}
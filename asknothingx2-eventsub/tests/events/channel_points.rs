@@ -0,0 +1,109 @@
+use asknothingx2_eventsub::twitch::{
+    events::channel_points::RedemptionStatus,
+    subscription_types::channel_subscriptions::{
+        ChannelPointsAutomaticRewardRedemptionPayload, ChannelPointsCustomRewardAddPayload,
+        ChannelPointsCustomRewardRedemptionAddPayload,
+        ChannelPointsCustomRewardRedemptionUpdatePayload, ChannelPointsCustomRewardRemovePayload,
+        ChannelPointsCustomRewardUpdatePayload,
+    },
+};
+
+use super::notification;
+
+const CONDITION: &str = "{\"broadcaster_user_id\": \"1337\", \"reward_id\": \"9001\"}";
+
+#[test]
+fn custom_reward() {
+    let payload: ChannelPointsCustomRewardAddPayload = notification(
+        "channel.channel_points_custom_reward.add",
+        "1",
+        CONDITION,
+        "{\"id\": \"9001\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"is_enabled\": true, \"is_paused\": false, \"is_in_stock\": true, \"title\": \"Cool Reward\", \"cost\": 100, \"prompt\": \"reward prompt\", \"is_user_input_required\": true, \"should_redemptions_skip_request_queue\": false, \"cooldown_expires_at\": null, \"redemptions_redeemed_current_stream\": null, \"max_per_stream\": {\"is_enabled\": true, \"value\": 1000}, \"max_per_user_per_stream\": {\"is_enabled\": true, \"value\": 1000}, \"global_cooldown\": {\"is_enabled\": true, \"seconds\": 1000}, \"background_color\": \"#FA1ED2\", \"image\": null, \"default_image\": {\"url_1x\": \"https://static-cdn.jtvnw.net/image-1.png\", \"url_2x\": \"https://static-cdn.jtvnw.net/image-2.png\", \"url_4x\": \"https://static-cdn.jtvnw.net/image-4.png\"}}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(
+        payload.payload.subscription.condition.reward_id.as_deref(),
+        Some("9001")
+    );
+    assert_eq!(event.title, "Cool Reward");
+    assert_eq!(event.cost, 100);
+    assert!(event.max_per_user_per_stream.is_enabled);
+    assert_eq!(event.global_cooldown.seconds, 1000);
+    assert!(event.image.is_none());
+    assert_eq!(
+        event.default_image.url_1x,
+        "https://static-cdn.jtvnw.net/image-1.png"
+    );
+    assert!(event.cooldown_expires_at.is_none());
+    assert!(event.redemptions_redeemed_current_stream.is_none());
+
+    let payload: ChannelPointsCustomRewardUpdatePayload = notification(
+        "channel.channel_points_custom_reward.update",
+        "1",
+        CONDITION,
+        "{\"id\": \"9001\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"is_enabled\": true, \"is_paused\": true, \"is_in_stock\": true, \"title\": \"Cool Reward\", \"cost\": 100, \"prompt\": \"reward prompt\", \"is_user_input_required\": true, \"should_redemptions_skip_request_queue\": false, \"cooldown_expires_at\": \"2019-11-16T10:11:12.634234626Z\", \"redemptions_redeemed_current_stream\": 123, \"max_per_stream\": {\"is_enabled\": true, \"value\": 1000}, \"max_per_user_per_stream\": {\"is_enabled\": true, \"value\": 1000}, \"global_cooldown\": {\"is_enabled\": true, \"seconds\": 1000}, \"background_color\": \"#FA1ED2\", \"image\": {\"url_1x\": \"https://static-cdn.jtvnw.net/image-1.png\", \"url_2x\": \"https://static-cdn.jtvnw.net/image-2.png\", \"url_4x\": \"https://static-cdn.jtvnw.net/image-4.png\"}, \"default_image\": {\"url_1x\": \"https://static-cdn.jtvnw.net/image-1.png\", \"url_2x\": \"https://static-cdn.jtvnw.net/image-2.png\", \"url_4x\": \"https://static-cdn.jtvnw.net/image-4.png\"}}",
+    );
+    let event = payload.payload.event;
+    assert!(event.is_paused);
+    assert!(event.image.is_some());
+    assert!(event.cooldown_expires_at.is_some());
+    assert_eq!(event.redemptions_redeemed_current_stream, Some(123));
+
+    let payload: ChannelPointsCustomRewardRemovePayload = notification(
+        "channel.channel_points_custom_reward.remove",
+        "1",
+        CONDITION,
+        "{\"id\": \"9001\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"is_enabled\": true, \"is_paused\": false, \"is_in_stock\": true, \"title\": \"Cool Reward\", \"cost\": 100, \"prompt\": \"reward prompt\", \"is_user_input_required\": true, \"should_redemptions_skip_request_queue\": false, \"cooldown_expires_at\": null, \"redemptions_redeemed_current_stream\": null, \"max_per_stream\": {\"is_enabled\": true, \"value\": 1000}, \"max_per_user_per_stream\": {\"is_enabled\": true, \"value\": 1000}, \"global_cooldown\": {\"is_enabled\": true, \"seconds\": 1000}, \"background_color\": \"#FA1ED2\", \"image\": null, \"default_image\": {\"url_1x\": \"https://static-cdn.jtvnw.net/image-1.png\", \"url_2x\": \"https://static-cdn.jtvnw.net/image-2.png\", \"url_4x\": \"https://static-cdn.jtvnw.net/image-4.png\"}}",
+    );
+    assert_eq!(payload.payload.event.id, "9001");
+}
+
+#[test]
+fn custom_reward_redemption() {
+    let payload: ChannelPointsCustomRewardRedemptionAddPayload = notification(
+        "channel.channel_points_custom_reward_redemption.add",
+        "1",
+        CONDITION,
+        "{\"id\": \"17fa2df1-ad76-4804-bfa5-a40ef63efe63\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"user_id\": \"9001\", \"user_login\": \"cooler_user\", \"user_name\": \"Cooler_User\", \"user_input\": \"pogchamp\", \"status\": \"unfulfilled\", \"reward\": {\"id\": \"92af127c-7326-4483-a52b-b0da0be61c01\", \"title\": \"title\", \"cost\": 100, \"prompt\": \"reward prompt\"}, \"redeemed_at\": \"2020-07-15T17:16:03.17106713Z\"}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(event.status, RedemptionStatus::Unfulfilled);
+    assert_eq!(event.user_input, "pogchamp");
+    assert_eq!(event.reward.title, "title");
+
+    let payload: ChannelPointsCustomRewardRedemptionUpdatePayload = notification(
+        "channel.channel_points_custom_reward_redemption.update",
+        "1",
+        CONDITION,
+        "{\"id\": \"17fa2df1-ad76-4804-bfa5-a40ef63efe63\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"user_id\": \"9001\", \"user_login\": \"cooler_user\", \"user_name\": \"Cooler_User\", \"user_input\": \"\", \"status\": \"fulfilled\", \"reward\": {\"id\": \"92af127c-7326-4483-a52b-b0da0be61c01\", \"title\": \"title\", \"cost\": 100, \"prompt\": \"reward prompt\"}, \"redeemed_at\": \"2020-07-15T17:16:03.17106713Z\"}",
+    );
+    assert_eq!(payload.payload.event.status, RedemptionStatus::Fulfilled);
+}
+
+#[test]
+fn automatic_reward_redemption() {
+    let payload: ChannelPointsAutomaticRewardRedemptionPayload = notification(
+        "channel.channel_points_automatic_reward_redemption.add",
+        "1",
+        CONDITION,
+        "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"user_id\": \"9001\", \"user_login\": \"cooler_user\", \"user_name\": \"Cooler_User\", \"id\": \"f024099a-e0aa-4339-8ea1-81b2b8e5a7ef\", \"reward\": {\"type\": \"send_highlighted_message\", \"cost\": 100, \"unlocked_emote\": null}, \"message\": {\"text\": \"Hello world! VoHiYo\", \"emotes\": [{\"id\": \"81274\", \"begin\": 13, \"end\": 19}]}, \"user_input\": \"Hello world! VoHiYo \", \"redeemed_at\": \"2024-02-23T21:14:34.260398045Z\"}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(event.reward.kind, "send_highlighted_message");
+    assert!(event.reward.unlocked_emote.is_none());
+    assert_eq!(event.message.emotes[0].id, "81274");
+    assert_eq!(event.message.emotes[0].begin, 13);
+
+    let payload: ChannelPointsAutomaticRewardRedemptionPayload = notification(
+        "channel.channel_points_automatic_reward_redemption.add",
+        "1",
+        CONDITION,
+        "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"user_id\": \"9001\", \"user_login\": \"cooler_user\", \"user_name\": \"Cooler_User\", \"id\": \"f024099a-e0aa-4339-8ea1-81b2b8e5a7ef\", \"reward\": {\"type\": \"random_sub_emote_unlock\", \"cost\": 400, \"unlocked_emote\": {\"id\": \"emotesv2_0\", \"name\": \"Kappa\"}}, \"message\": {\"text\": \"\", \"emotes\": []}, \"user_input\": null, \"redeemed_at\": \"2024-02-23T21:14:34.260398045Z\"}",
+    );
+    let event = payload.payload.event;
+    assert_eq!(event.reward.unlocked_emote.unwrap().name, "Kappa");
+    assert!(event.user_input.is_none());
+}
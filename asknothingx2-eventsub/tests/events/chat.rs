@@ -0,0 +1,352 @@
+use asknothingx2_eventsub::twitch::{
+    events::chat::{ChatMessageType, Fragment, HeldMessageStatus, Notice},
+    subscription_types::channel_subscriptions::{
+        ChannelChatClearPayload, ChannelChatClearUserMessagesPayload,
+        ChannelChatMessageDeletePayload, ChannelChatMessagePayload, ChannelChatNotificationPayload,
+        ChannelChatSettingsUpdatePayload, ChannelChatUserMessageHoldPayload,
+        ChannelChatUserMessageUpdatePayload,
+    },
+    types::objects::Tier,
+};
+
+use super::notification;
+
+const CONDITION: &str = "{\"broadcaster_user_id\": \"1971641\", \"user_id\": \"1971641\"}";
+
+fn chat_notification(event: &str) -> ChannelChatNotificationPayload {
+    notification("channel.chat.notification", "1", CONDITION, event)
+}
+
+#[test]
+fn message() {
+    let payload: ChannelChatMessagePayload = notification(
+        "channel.chat.message",
+        "1",
+        CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"4145994\", \"chatter_user_login\": \"viewer32\", \"chatter_user_name\": \"viewer32\", \"message_id\": \"cc106a89-1814-919d-454c-f4f2f970aae7\", \"message\": {\"text\": \"Hi chat Cheer100 Kappa @streamer\", \"fragments\": [{\"type\": \"text\", \"text\": \"Hi chat \", \"cheermote\": null, \"emote\": null, \"mention\": null}, {\"type\": \"cheermote\", \"text\": \"Cheer100\", \"cheermote\": {\"prefix\": \"cheer\", \"bits\": 100, \"tier\": 1}, \"emote\": null, \"mention\": null}, {\"type\": \"text\", \"text\": \" \", \"cheermote\": null, \"emote\": null, \"mention\": null}, {\"type\": \"emote\", \"text\": \"Kappa\", \"cheermote\": null, \"emote\": {\"id\": \"25\", \"emote_set_id\": \"0\", \"owner_id\": \"0\", \"format\": [\"static\"]}, \"mention\": null}, {\"type\": \"text\", \"text\": \" \", \"cheermote\": null, \"emote\": null, \"mention\": null}, {\"type\": \"mention\", \"text\": \"@streamer\", \"cheermote\": null, \"emote\": null, \"mention\": {\"user_id\": \"1971641\", \"user_name\": \"streamer\", \"user_login\": \"streamer\"}}]}, \"color\": \"#00FF7F\", \"badges\": [{\"set_id\": \"moderator\", \"id\": \"1\", \"info\": \"\"}, {\"set_id\": \"subscriber\", \"id\": \"12\", \"info\": \"16\"}], \"message_type\": \"text\", \"cheer\": {\"bits\": 100}, \"reply\": {\"parent_message_id\": \"c1a63a05-f3a1-4dfa-a1fb-0b29dd2b1d48\", \"parent_message_body\": \"hello\", \"parent_user_id\": \"1337\", \"parent_user_name\": \"Cool_User\", \"parent_user_login\": \"cool_user\", \"thread_message_id\": \"c1a63a05-f3a1-4dfa-a1fb-0b29dd2b1d48\", \"thread_user_id\": \"1337\", \"thread_user_name\": \"Cool_User\", \"thread_user_login\": \"cool_user\"}, \"channel_points_custom_reward_id\": null, \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"source_message_id\": null, \"source_badges\": null}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(payload.payload.subscription.condition.user_id, "1971641");
+    assert_eq!(event.broadcaster_user_id, "1971641");
+    assert_eq!(event.chatter_user_login, "viewer32");
+    assert_eq!(event.message_type, ChatMessageType::Text);
+    assert_eq!(event.color, "#00FF7F");
+    assert_eq!(event.cheer.unwrap().bits, 100);
+    assert_eq!(event.badges.len(), 2);
+    assert_eq!(event.badges[1].set_id, "subscriber");
+    assert_eq!(event.badges[1].info, "16");
+    assert_eq!(event.reply.unwrap().parent_user_login, "cool_user");
+    assert!(event.source_broadcaster_user_id.is_none());
+
+    let fragments = &event.message.fragments;
+    assert_eq!(fragments.len(), 6);
+    assert_eq!(
+        fragments.iter().map(Fragment::text).collect::<String>(),
+        event.message.text
+    );
+    let Fragment::Cheermote { cheermote, .. } = &fragments[1] else {
+        panic!("expected cheermote: {:?}", fragments[1]);
+    };
+    assert_eq!(
+        (cheermote.prefix.as_str(), cheermote.bits, cheermote.tier),
+        ("cheer", 100, 1)
+    );
+    let Fragment::Emote { emote, .. } = &fragments[3] else {
+        panic!("expected emote: {:?}", fragments[3]);
+    };
+    assert_eq!(emote.id, "25");
+    assert_eq!(emote.format, ["static"]);
+    let Fragment::Mention { mention, .. } = &fragments[5] else {
+        panic!("expected mention: {:?}", fragments[5]);
+    };
+    assert_eq!(mention.user_login, "streamer");
+}
+
+#[test]
+fn message_from_shared_chat() {
+    let payload: ChannelChatMessagePayload = notification(
+        "channel.chat.message",
+        "1",
+        CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"4145994\", \"chatter_user_login\": \"viewer32\", \"chatter_user_name\": \"viewer32\", \"message_id\": \"cc106a89\", \"message\": {\"text\": \"hi\", \"fragments\": [{\"type\": \"text\", \"text\": \"hi\", \"cheermote\": null, \"emote\": null, \"mention\": null}]}, \"color\": \"\", \"badges\": [], \"message_type\": \"channel_points_highlighted\", \"cheer\": null, \"reply\": null, \"channel_points_custom_reward_id\": \"d8ef0b7e\", \"source_broadcaster_user_id\": \"112233\", \"source_broadcaster_user_login\": \"partner\", \"source_broadcaster_user_name\": \"Partner\", \"source_message_id\": \"0f9a2b\", \"source_badges\": [{\"set_id\": \"vip\", \"id\": \"1\", \"info\": \"\"}]}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(
+        event.message_type,
+        ChatMessageType::ChannelPointsHighlighted
+    );
+    assert_eq!(
+        event.channel_points_custom_reward_id.as_deref(),
+        Some("d8ef0b7e")
+    );
+    assert_eq!(event.source_broadcaster_user_id.as_deref(), Some("112233"));
+    assert_eq!(event.source_message_id.as_deref(), Some("0f9a2b"));
+    assert_eq!(event.source_badges.unwrap()[0].set_id, "vip");
+    assert!(event.cheer.is_none());
+    assert!(event.reply.is_none());
+}
+
+#[test]
+fn notification_resub() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"Great stream\", \"fragments\": [{\"type\": \"text\", \"text\": \"Great stream\", \"cheermote\": null, \"emote\": null, \"mention\": null}]}, \"notice_type\": \"resub\", \"sub\": null, \"resub\": {\"cumulative_months\": 10, \"duration_months\": 0, \"streak_months\": null, \"sub_tier\": \"1000\", \"is_prime\": false, \"is_gift\": false, \"gifter_is_anonymous\": null, \"gifter_user_id\": null, \"gifter_user_name\": null, \"gifter_user_login\": null}, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    assert_eq!(event.notice.tag(), "resub");
+    assert_eq!(event.message.text, "Great stream");
+    let Notice::Resub(resub) = event.notice else {
+        panic!("expected resub: {:?}", event.notice);
+    };
+    assert_eq!(resub.cumulative_months, 10);
+    assert_eq!(resub.streak_months, None);
+    assert_eq!(resub.sub_tier, Tier::Tier1);
+    assert!(!resub.is_gift);
+}
+
+#[test]
+fn notification_sub() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"sub\", \"sub\": {\"sub_tier\": \"2000\", \"is_prime\": false, \"duration_months\": 1}, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    let Notice::Sub(sub) = event.notice else {
+        panic!("expected sub: {:?}", event.notice);
+    };
+    assert_eq!(sub.sub_tier, Tier::Tier2);
+    assert_eq!(sub.duration_months, 1);
+}
+
+#[test]
+fn notification_sub_gift() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": true, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"sub_gift\", \"sub\": null, \"resub\": null, \"sub_gift\": {\"duration_months\": 1, \"cumulative_total\": null, \"recipient_user_id\": \"1234\", \"recipient_user_name\": \"Cool_User\", \"recipient_user_login\": \"cool_user\", \"sub_tier\": \"1000\", \"community_gift_id\": \"8317472\"}, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    assert!(event.chatter_is_anonymous);
+    let Notice::SubGift(gift) = event.notice else {
+        panic!("expected sub_gift: {:?}", event.notice);
+    };
+    assert_eq!(gift.recipient_user_login, "cool_user");
+    assert_eq!(gift.cumulative_total, None);
+    assert_eq!(gift.community_gift_id.as_deref(), Some("8317472"));
+}
+
+#[test]
+fn notification_community_sub_gift() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"community_sub_gift\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": {\"id\": \"8317472\", \"total\": 5, \"sub_tier\": \"1000\", \"cumulative_total\": 25}, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    let Notice::CommunitySubGift(gift) = event.notice else {
+        panic!("expected community_sub_gift: {:?}", event.notice);
+    };
+    assert_eq!(gift.total, 5);
+    assert_eq!(gift.cumulative_total, Some(25));
+}
+
+#[test]
+fn notification_gift_paid_upgrade() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"gift_paid_upgrade\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": {\"gifter_is_anonymous\": true, \"gifter_user_id\": null, \"gifter_user_name\": null, \"gifter_user_login\": null}, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    let Notice::GiftPaidUpgrade(upgrade) = event.notice else {
+        panic!("expected gift_paid_upgrade: {:?}", event.notice);
+    };
+    assert!(upgrade.gifter_is_anonymous);
+    assert!(upgrade.gifter_user_id.is_none());
+}
+
+#[test]
+fn notification_prime_paid_upgrade() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"prime_paid_upgrade\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": {\"sub_tier\": \"1000\"}, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    let Notice::PrimePaidUpgrade(upgrade) = event.notice else {
+        panic!("expected prime_paid_upgrade: {:?}", event.notice);
+    };
+    assert_eq!(upgrade.sub_tier, Tier::Tier1);
+}
+
+#[test]
+fn notification_raid() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"raid\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": {\"user_id\": \"1234\", \"user_name\": \"Cool_User\", \"user_login\": \"cool_user\", \"viewer_count\": 42, \"profile_image_url\": \"https://static-cdn.jtvnw.net/user-default-pictures-uv/ebe4cd89-b4f4-4cd9-adac-2f30151b4209-profile_image-300x300.png\"}, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    let Notice::Raid(raid) = event.notice else {
+        panic!("expected raid: {:?}", event.notice);
+    };
+    assert_eq!(raid.user_login, "cool_user");
+    assert_eq!(raid.viewer_count, 42);
+}
+
+#[test]
+fn notification_unraid() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"unraid\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": {}, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    assert!(matches!(event.notice, Notice::Unraid(_)));
+}
+
+#[test]
+fn notification_pay_it_forward() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"pay_it_forward\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": {\"gifter_is_anonymous\": false, \"gifter_user_id\": \"1234\", \"gifter_user_name\": \"Cool_User\", \"gifter_user_login\": \"cool_user\"}, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    let Notice::PayItForward(pay) = event.notice else {
+        panic!("expected pay_it_forward: {:?}", event.notice);
+    };
+    assert!(!pay.gifter_is_anonymous);
+    assert_eq!(pay.gifter_user_login.as_deref(), Some("cool_user"));
+}
+
+#[test]
+fn notification_announcement() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"announcement\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": {\"color\": \"PURPLE\"}, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    let Notice::Announcement(announcement) = event.notice else {
+        panic!("expected announcement: {:?}", event.notice);
+    };
+    assert_eq!(announcement.color, "PURPLE");
+}
+
+#[test]
+fn notification_bits_badge_tier() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"bits_badge_tier\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": {\"tier\": 1000}, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    let Notice::BitsBadgeTier(badge) = event.notice else {
+        panic!("expected bits_badge_tier: {:?}", event.notice);
+    };
+    assert_eq!(badge.tier, 1000);
+}
+
+#[test]
+fn notification_charity_donation() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"charity_donation\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": {\"charity_name\": \"Example Charity\", \"amount\": {\"value\": 1000, \"decimal_places\": 2, \"currency\": \"USD\"}}, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    let Notice::CharityDonation(donation) = event.notice else {
+        panic!("expected charity_donation: {:?}", event.notice);
+    };
+    assert_eq!(donation.charity_name, "Example Charity");
+    assert_eq!(donation.amount.value, 1000);
+    assert_eq!(donation.amount.currency, "USD");
+}
+
+#[test]
+fn notification_shared_chat() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"shared_chat_sub\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": {\"sub_tier\": \"3000\", \"is_prime\": false, \"duration_months\": 3}, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null, \"source_broadcaster_user_id\": \"112233\", \"source_broadcaster_user_login\": \"partner\", \"source_broadcaster_user_name\": \"Partner\", \"source_message_id\": \"2d4f8a\", \"source_badges\": []}").payload.event;
+
+    assert_eq!(event.notice.tag(), "shared_chat_sub");
+    assert_eq!(
+        event.source_broadcaster_user_login.as_deref(),
+        Some("partner")
+    );
+    let Notice::SharedChatSub(sub) = event.notice else {
+        panic!("expected shared_chat_sub: {:?}", event.notice);
+    };
+    assert_eq!(sub.sub_tier, Tier::Tier3);
+
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"shared_chat_raid\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": {\"user_id\": \"1234\", \"user_name\": \"Cool_User\", \"user_login\": \"cool_user\", \"viewer_count\": 7, \"profile_image_url\": \"https://example.com/image.png\"}, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+    let Notice::SharedChatRaid(raid) = event.notice else {
+        panic!("expected shared_chat_raid: {:?}", event.notice);
+    };
+    assert_eq!(raid.viewer_count, 7);
+
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"shared_chat_announcement\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": {\"color\": \"BLUE\"}}").payload.event;
+    let Notice::SharedChatAnnouncement(announcement) = event.notice else {
+        panic!("expected shared_chat_announcement: {:?}", event.notice);
+    };
+    assert_eq!(announcement.color, "BLUE");
+}
+
+#[test]
+fn notification_unknown_notice_type() {
+    let event = chat_notification("{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"chatter_user_id\": \"49912639\", \"chatter_user_login\": \"viewer23\", \"chatter_user_name\": \"viewer23\", \"chatter_is_anonymous\": false, \"color\": \"\", \"badges\": [], \"system_message\": \"viewer23 subscribed at Tier 1. They've subscribed for 10 months!\", \"message_id\": \"d62235c8-47ff-a4f4-84e8-5a29a65a9c03\", \"message\": {\"text\": \"\", \"fragments\": []}, \"notice_type\": \"watch_streak\", \"sub\": null, \"resub\": null, \"sub_gift\": null, \"community_sub_gift\": null, \"gift_paid_upgrade\": null, \"prime_paid_upgrade\": null, \"raid\": null, \"unraid\": null, \"pay_it_forward\": null, \"announcement\": null, \"watch_streak\": {\"streak_months\": 7}, \"bits_badge_tier\": null, \"charity_donation\": null, \"shared_chat_sub\": null, \"shared_chat_resub\": null, \"shared_chat_sub_gift\": null, \"shared_chat_community_sub_gift\": null, \"shared_chat_gift_paid_upgrade\": null, \"shared_chat_prime_paid_upgrade\": null, \"shared_chat_raid\": null, \"shared_chat_pay_it_forward\": null, \"shared_chat_announcement\": null}").payload.event;
+
+    assert_eq!(event.notice.tag(), "watch_streak");
+    let Notice::Unknown { kind, data } = &event.notice else {
+        panic!("expected an unknown notice: {:?}", event.notice);
+    };
+    assert_eq!(kind, "watch_streak");
+    assert_eq!(data["watch_streak"]["streak_months"], 7);
+
+    let se = serde_json::to_string(&event).unwrap();
+    assert!(se.contains("\"notice_type\":\"watch_streak\""));
+    assert!(se.contains("\"watch_streak\":{\"streak_months\":7}"));
+}
+
+#[test]
+fn clear() {
+    let payload: ChannelChatClearPayload =
+        notification("channel.chat.clear", "1", CONDITION, "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\"}");
+
+    assert_eq!(payload.payload.event.broadcaster_user_login, "streamer");
+}
+
+#[test]
+fn clear_user_messages() {
+    let payload: ChannelChatClearUserMessagesPayload = notification(
+        "channel.chat.clear_user_messages",
+        "1",
+        CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"target_user_id\": \"7734\", \"target_user_login\": \"uncool_viewer\", \"target_user_name\": \"uncool_viewer\"}",
+    );
+
+    assert_eq!(payload.payload.event.target_user_id, "7734");
+    assert_eq!(payload.payload.event.target_user_login, "uncool_viewer");
+}
+
+#[test]
+fn message_delete() {
+    let payload: ChannelChatMessageDeletePayload = notification(
+        "channel.chat.message_delete",
+        "1",
+        CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"target_user_id\": \"7734\", \"target_user_login\": \"uncool_viewer\", \"target_user_name\": \"uncool_viewer\", \"message_id\": \"ab24e0b0-2260-4bac-94e4-05eedd4ecd0e\"}",
+    );
+
+    assert_eq!(payload.payload.event.target_user_id, "7734");
+    assert_eq!(
+        payload.payload.event.message_id,
+        "ab24e0b0-2260-4bac-94e4-05eedd4ecd0e"
+    );
+}
+
+#[test]
+fn settings_update() {
+    let payload: ChannelChatSettingsUpdatePayload = notification(
+        "channel.chat_settings.update",
+        "1",
+        CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"emote_mode\": true, \"follower_mode\": false, \"follower_mode_duration_minutes\": null, \"slow_mode\": true, \"slow_mode_wait_time_seconds\": 10, \"subscriber_mode\": false, \"unique_chat_mode\": false}",
+    );
+    let event = payload.payload.event;
+
+    assert!(event.emote_mode);
+    assert!(!event.follower_mode);
+    assert_eq!(event.follower_mode_duration_minutes, None);
+    assert!(event.slow_mode);
+    assert_eq!(event.slow_mode_wait_time_seconds, Some(10));
+    assert!(!event.subscriber_mode);
+    assert!(!event.unique_chat_mode);
+}
+
+#[test]
+fn user_message_hold() {
+    let payload: ChannelChatUserMessageHoldPayload = notification(
+        "channel.chat.user_message_hold",
+        "1",
+        CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"user_id\": \"9001\", \"user_login\": \"baduser\", \"user_name\": \"BadUser\", \"message_id\": \"bad-message-id\", \"message\": {\"text\": \"This is a bad message… 🐝\", \"fragments\": [{\"type\": \"text\", \"text\": \"This is a bad message… \", \"cheermote\": null, \"emote\": null, \"mention\": null}, {\"type\": \"emote\", \"text\": \"🐝\", \"cheermote\": null, \"emote\": {\"id\": \"emotesv2_a28e5b8a\", \"emote_set_id\": \"1\", \"owner_id\": null, \"format\": [\"static\"]}, \"mention\": null}]}}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(event.user_login, "baduser");
+    assert_eq!(event.message_id, "bad-message-id");
+    let Fragment::Emote { emote, .. } = &event.message.fragments[1] else {
+        panic!("expected emote: {:?}", event.message.fragments[1]);
+    };
+    assert_eq!(emote.id, "emotesv2_a28e5b8a");
+    assert_eq!(emote.owner_id, None);
+}
+
+#[test]
+fn user_message_update() {
+    let payload: ChannelChatUserMessageUpdatePayload = notification(
+        "channel.chat.user_message_update",
+        "1",
+        CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"streamer\", \"user_id\": \"9001\", \"user_login\": \"baduser\", \"user_name\": \"BadUser\", \"status\": \"approved\", \"message_id\": \"bad-message-id\", \"message\": {\"text\": \"hello\", \"fragments\": [{\"type\": \"text\", \"text\": \"hello\", \"cheermote\": null, \"emote\": null, \"mention\": null}]}}",
+    );
+
+    assert_eq!(payload.payload.event.status, HeldMessageStatus::Approved);
+    assert_eq!(payload.payload.event.message.text, "hello");
+}
@@ -0,0 +1,178 @@
+use asknothingx2_eventsub::twitch::{
+    events::{
+        chat::Fragment,
+        guest_star::{GroupLayout, GuestState},
+        suspicious_user::{BanEvasionEvaluation, LowTrustStatus, SuspiciousUserType},
+    },
+    subscription_types::channel_subscriptions::{
+        ChannelGuestStarGuestUpdatePayload, ChannelGuestStarSessionBeginPayload,
+        ChannelGuestStarSessionEndPayload, ChannelGuestStarSettingsUpdatePayload,
+        ChannelSharedChatSessionBeginPayload, ChannelSharedChatSessionEndPayload,
+        ChannelSharedChatSessionUpdatePayload, ChannelSuspiciousUserMessagePayload,
+        ChannelSuspiciousUserUpdatePayload,
+    },
+};
+
+use super::notification;
+
+const CONDITION: &str = "{\"broadcaster_user_id\": \"1971641\"}";
+const MODERATOR_CONDITION: &str =
+    "{\"broadcaster_user_id\": \"1971641\", \"moderator_user_id\": \"9876\"}";
+
+#[test]
+fn shared_chat_begin() {
+    let payload: ChannelSharedChatSessionBeginPayload =
+        notification("channel.shared_chat.begin", "1", CONDITION, "{\"session_id\": \"2b64a92a-dbb8-424e-b1c3-304423ba1b6f\", \"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"host_broadcaster_user_id\": \"1971641\", \"host_broadcaster_user_login\": \"streamer\", \"host_broadcaster_user_name\": \"Streamer\", \"participants\": [{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_name\": \"Streamer\", \"broadcaster_user_login\": \"streamer\"}, {\"broadcaster_user_id\": \"112233\", \"broadcaster_user_name\": \"Streamer2\", \"broadcaster_user_login\": \"streamer2\"}]}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.session_id, "2b64a92a-dbb8-424e-b1c3-304423ba1b6f");
+    assert_eq!(event.host_broadcaster_user_id, "1971641");
+    assert_eq!(event.participants.len(), 2);
+    assert_eq!(event.participants[1].broadcaster_user_login, "streamer2");
+}
+
+#[test]
+fn shared_chat_update() {
+    let payload: ChannelSharedChatSessionUpdatePayload =
+        notification("channel.shared_chat.update", "1", CONDITION, "{\"session_id\": \"2b64a92a-dbb8-424e-b1c3-304423ba1b6f\", \"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"host_broadcaster_user_id\": \"1971641\", \"host_broadcaster_user_login\": \"streamer\", \"host_broadcaster_user_name\": \"Streamer\", \"participants\": [{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_name\": \"Streamer\", \"broadcaster_user_login\": \"streamer\"}, {\"broadcaster_user_id\": \"112233\", \"broadcaster_user_name\": \"Streamer2\", \"broadcaster_user_login\": \"streamer2\"}, {\"broadcaster_user_id\": \"445566\", \"broadcaster_user_name\": \"Streamer3\", \"broadcaster_user_login\": \"streamer3\"}]}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.participants.len(), 3);
+    assert_eq!(event.participants[2].broadcaster_user_name, "Streamer3");
+}
+
+#[test]
+fn shared_chat_end() {
+    let payload: ChannelSharedChatSessionEndPayload =
+        notification("channel.shared_chat.end", "1", CONDITION, "{\"session_id\": \"2b64a92a-dbb8-424e-b1c3-304423ba1b6f\", \"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"host_broadcaster_user_id\": \"1971641\", \"host_broadcaster_user_login\": \"streamer\", \"host_broadcaster_user_name\": \"Streamer\"}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.session_id, "2b64a92a-dbb8-424e-b1c3-304423ba1b6f");
+    assert_eq!(event.host_broadcaster_user_login, "streamer");
+}
+
+#[test]
+fn suspicious_user_message() {
+    let payload: ChannelSuspiciousUserMessagePayload = notification(
+        "channel.suspicious_user.message",
+        "1",
+        MODERATOR_CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"user_id\": \"1050263432\", \"user_login\": \"dflkjsdfsd\", \"user_name\": \"dflkjsdfsd\", \"low_trust_status\": \"active_monitoring\", \"shared_ban_channel_ids\": [\"100\", \"200\"], \"types\": [\"manual\", \"shared_channel_ban\"], \"ban_evasion_evaluation\": \"likely\", \"message\": {\"message_id\": \"101010\", \"text\": \"bad stuff pogchamp\", \"fragments\": [{\"type\": \"text\", \"text\": \"bad stuff \", \"cheermote\": null, \"emote\": null}, {\"type\": \"emote\", \"text\": \"pogchamp\", \"cheermote\": null, \"emote\": {\"id\": \"899\", \"emote_set_id\": \"1\"}}]}}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(event.low_trust_status, LowTrustStatus::ActiveMonitoring);
+    assert_eq!(
+        event.types,
+        [
+            SuspiciousUserType::Manual,
+            SuspiciousUserType::SharedChannelBan
+        ]
+    );
+    assert_eq!(event.shared_ban_channel_ids, ["100", "200"]);
+    assert_eq!(event.ban_evasion_evaluation, BanEvasionEvaluation::Likely);
+    assert_eq!(event.message.text, "bad stuff pogchamp");
+    assert!(matches!(event.message.fragments[1], Fragment::Emote { .. }));
+}
+
+#[test]
+fn suspicious_user_update() {
+    let payload: ChannelSuspiciousUserUpdatePayload = notification(
+        "channel.suspicious_user.update",
+        "1",
+        MODERATOR_CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"moderator_user_id\": \"9876\", \"moderator_user_login\": \"the_mod\", \"moderator_user_name\": \"The_Mod\", \"user_id\": \"1050263432\", \"user_login\": \"dflkjsdfsd\", \"user_name\": \"dflkjsdfsd\", \"low_trust_status\": \"restricted\"}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(event.moderator_user_login, "the_mod");
+    assert_eq!(event.low_trust_status, LowTrustStatus::Restricted);
+}
+
+#[test]
+fn low_trust_status() {
+    assert_eq!(
+        serde_json::from_str::<LowTrustStatus>("\"none\"").unwrap(),
+        LowTrustStatus::None
+    );
+    assert_eq!(
+        serde_json::from_str::<SuspiciousUserType>("\"ban_evader_detector\"").unwrap(),
+        SuspiciousUserType::BanEvaderDetector
+    );
+    assert_eq!(
+        serde_json::from_str::<BanEvasionEvaluation>("\"unknown\"").unwrap(),
+        BanEvasionEvaluation::Unknown
+    );
+}
+
+#[test]
+fn guest_star_session() {
+    let begin: ChannelGuestStarSessionBeginPayload = notification(
+        "channel.guest_star_session.begin",
+        "beta",
+        MODERATOR_CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"session_id\": \"2KFRQbFtpmfyD3IevNRnCzOPRJI\", \"started_at\": \"2023-04-11T16:20:03.17106713Z\", \"host_user_id\": \"9876\", \"host_user_login\": \"the_mod\", \"host_user_name\": \"The_Mod\"}",
+    );
+    assert_eq!(
+        begin.payload.event.session_id,
+        "2KFRQbFtpmfyD3IevNRnCzOPRJI"
+    );
+    assert_eq!(begin.payload.event.host_user_login, "the_mod");
+
+    let end: ChannelGuestStarSessionEndPayload = notification(
+        "channel.guest_star_session.end",
+        "beta",
+        MODERATOR_CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"session_id\": \"2KFRQbFtpmfyD3IevNRnCzOPRJI\", \"started_at\": \"2023-04-11T16:20:03.17106713Z\", \"ended_at\": \"2023-04-11T17:51:29.153485Z\", \"host_user_id\": \"9876\", \"host_user_login\": \"the_mod\", \"host_user_name\": \"The_Mod\"}",
+    );
+    let event = end.payload.event;
+    assert!(event.ended_at > event.started_at);
+}
+
+#[test]
+fn guest_star_guest_update() {
+    let payload: ChannelGuestStarGuestUpdatePayload = notification(
+        "channel.guest_star_guest.update",
+        "beta",
+        MODERATOR_CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"session_id\": \"2KFRQbFtpmfyD3IevNRnCzOPRJI\", \"moderator_user_id\": \"9876\", \"moderator_user_login\": \"the_mod\", \"moderator_user_name\": \"The_Mod\", \"guest_user_id\": \"1234\", \"guest_user_login\": \"guest\", \"guest_user_name\": \"Guest\", \"slot_id\": \"1\", \"state\": \"live\", \"host_user_id\": \"9876\", \"host_user_login\": \"the_mod\", \"host_user_name\": \"The_Mod\", \"host_video_enabled\": true, \"host_audio_enabled\": true, \"host_volume\": 100}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(event.guest_user_login.as_deref(), Some("guest"));
+    assert_eq!(event.slot_id.as_deref(), Some("1"));
+    assert_eq!(event.state, Some(GuestState::Live));
+    assert_eq!(event.host_video_enabled, Some(true));
+    assert_eq!(event.host_volume, Some(100));
+}
+
+#[test]
+fn guest_star_guest_left() {
+    let payload: ChannelGuestStarGuestUpdatePayload = notification(
+        "channel.guest_star_guest.update",
+        "beta",
+        MODERATOR_CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"session_id\": \"2KFRQbFtpmfyD3IevNRnCzOPRJI\", \"moderator_user_id\": null, \"moderator_user_login\": null, \"moderator_user_name\": null, \"guest_user_id\": \"1234\", \"guest_user_login\": \"guest\", \"guest_user_name\": \"Guest\", \"slot_id\": null, \"state\": null, \"host_user_id\": \"9876\", \"host_user_login\": \"the_mod\", \"host_user_name\": \"The_Mod\", \"host_video_enabled\": null, \"host_audio_enabled\": null, \"host_volume\": null}",
+    );
+    let event = payload.payload.event;
+
+    assert!(event.moderator_user_id.is_none());
+    assert!(event.slot_id.is_none());
+    assert!(event.state.is_none());
+    assert!(event.host_volume.is_none());
+}
+
+#[test]
+fn guest_star_settings_update() {
+    let payload: ChannelGuestStarSettingsUpdatePayload = notification(
+        "channel.guest_star_settings.update",
+        "beta",
+        MODERATOR_CONDITION,
+        "{\"broadcaster_user_id\": \"1971641\", \"broadcaster_user_login\": \"streamer\", \"broadcaster_user_name\": \"Streamer\", \"is_moderator_send_live_enabled\": true, \"slot_count\": 5, \"is_browser_source_audio_enabled\": true, \"group_layout\": \"horizontal_top\"}",
+    );
+    let event = payload.payload.event;
+
+    assert!(event.is_moderator_send_live_enabled);
+    assert_eq!(event.slot_count, 5);
+    assert_eq!(event.group_layout, GroupLayout::HorizontalTop);
+}
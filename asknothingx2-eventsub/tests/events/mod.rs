@@ -0,0 +1,59 @@
+use asknothingx2_eventsub::twitch::types::timestamp::rfc3339_nanos;
+use chrono::DateTime;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+mod automod;
+mod channel_points;
+mod chat;
+mod guest_star_shared_chat;
+mod moderation;
+mod monetization;
+mod poll_prediction;
+mod timestamp_format;
+
+/// Wraps `event` in a subscription envelope, decodes it as `T` and checks
+/// that `T` serializes back to an equivalent payload with every timestamp in
+/// Twitch's format.
+fn notification<T>(kind: &str, version: &str, condition: &str, event: &str) -> T
+where
+    T: DeserializeOwned + Serialize,
+{
+    let payload = format!("{{\"subscription\": {{\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"enabled\", \"type\": \"{kind}\", \"version\": \"{version}\", \"cost\": 0, \"condition\": {condition}, \"transport\": {{\"method\": \"webhook\", \"callback\": \"https://example.com/webhooks/callback\"}}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}}, \"event\": {event}}}");
+
+    let de: T = serde_json::from_str(&payload).unwrap();
+    let se = serde_json::to_value(&de).unwrap();
+    let again: T = serde_json::from_value(se.clone()).unwrap();
+    pretty_assertions::assert_eq!(serde_json::to_value(&again).unwrap(), se);
+    assert_timestamps(&serde_json::from_str(&payload).unwrap(), &se);
+
+    de
+}
+
+/// Checks that every `*_at` string in `original` was decoded as a timestamp
+/// and re-encoded in Twitch's format.
+fn assert_timestamps(original: &Value, encoded: &Value) {
+    match (original, encoded) {
+        (Value::Object(original), Value::Object(encoded)) => {
+            for (key, value) in original {
+                let Some(encoded) = encoded.get(key) else {
+                    continue;
+                };
+                match value {
+                    Value::String(raw) if key.ends_with("_at") => {
+                        let expected =
+                            rfc3339_nanos::format(&DateTime::parse_from_rfc3339(raw).unwrap());
+                        assert_eq!(encoded.as_str(), Some(expected.as_str()), "{key}");
+                    }
+                    _ => assert_timestamps(value, encoded),
+                }
+            }
+        }
+        (Value::Array(original), Value::Array(encoded)) => {
+            for (value, encoded) in original.iter().zip(encoded) {
+                assert_timestamps(value, encoded);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,256 @@
+use asknothingx2_eventsub::twitch::{
+    events::moderation::{
+        AutomodTermsAction, AutomodTermsList, ModerateAction, UnbanRequestStatus,
+    },
+    subscription_types::channel_subscriptions::{
+        ChannelBanPayload, ChannelModeratePayload, ChannelModerateV2Payload,
+        ChannelModeratorAddPayload, ChannelModeratorRemovePayload, ChannelUnbanPayload,
+        ChannelUnbanRequestCreatePayload, ChannelUnbanRequestResolvePayload, ChannelVIPAddPayload,
+        ChannelVIPRemovePayload, ChannelWarningAcknowledgementPayload, ChannelWarningSendPayload,
+    },
+};
+
+use super::notification;
+
+const BROADCASTER: &str = "{\"broadcaster_user_id\": \"1337\"}";
+const MODERATOR: &str = "{\"broadcaster_user_id\": \"1337\", \"moderator_user_id\": \"1339\"}";
+
+fn moderate(event: &str) -> ModerateAction {
+    let payload: ChannelModerateV2Payload = notification("channel.moderate", "2", MODERATOR, event);
+    assert_eq!(payload.payload.event.moderator_user_login, "mod_user");
+
+    payload.payload.event.action
+}
+
+#[test]
+fn ban() {
+    let payload: ChannelBanPayload = notification("channel.ban", "1", BROADCASTER, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"reason\": \"Offensive language\", \"banned_at\": \"2020-07-15T18:15:11.17106713Z\", \"ends_at\": \"2020-07-15T18:16:11.17106713Z\", \"is_permanent\": false}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.user_login, "cool_user");
+    assert_eq!(event.moderator_user_login, "mod_user");
+    assert_eq!(event.reason, "Offensive language");
+    assert!(event.ends_at.is_some());
+    assert!(!event.is_permanent);
+
+    let payload: ChannelBanPayload =
+        notification("channel.ban", "1", BROADCASTER, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"reason\": \"\", \"banned_at\": \"2020-07-15T18:15:11.17106713Z\", \"ends_at\": null, \"is_permanent\": true}");
+    assert!(payload.payload.event.is_permanent);
+    assert!(payload.payload.event.ends_at.is_none());
+}
+
+#[test]
+fn unban() {
+    let payload: ChannelUnbanPayload =
+        notification("channel.unban", "1", BROADCASTER, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\"}");
+
+    assert_eq!(payload.payload.event.user_id, "1234");
+    assert_eq!(payload.payload.event.moderator_user_id, "1339");
+}
+
+#[test]
+fn unban_request() {
+    let payload: ChannelUnbanRequestCreatePayload = notification(
+        "channel.unban_request.create",
+        "1",
+        MODERATOR,
+        "{\"id\": \"60\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"text\": \"unban please\", \"created_at\": \"2023-11-16T10:11:12.634234626Z\"}",
+    );
+    assert_eq!(
+        payload.payload.subscription.condition.moderator_user_id,
+        "1339"
+    );
+    assert_eq!(payload.payload.event.text, "unban please");
+
+    let payload: ChannelUnbanRequestResolvePayload = notification(
+        "channel.unban_request.resolve",
+        "1",
+        MODERATOR,
+        "{\"id\": \"60\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"resolution_text\": \"no\", \"status\": \"denied\"}",
+    );
+    assert_eq!(payload.payload.event.status, UnbanRequestStatus::Denied);
+    assert_eq!(
+        payload.payload.event.moderator_user_id.as_deref(),
+        Some("1339")
+    );
+    assert_eq!(payload.payload.event.resolution_text.as_deref(), Some("no"));
+
+    let payload: ChannelUnbanRequestResolvePayload = notification(
+        "channel.unban_request.resolve",
+        "1",
+        MODERATOR,
+        "{\"id\": \"60\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"moderator_user_id\": null, \"moderator_user_login\": null, \"moderator_user_name\": null, \"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"resolution_text\": null, \"status\": \"canceled\"}",
+    );
+    assert_eq!(payload.payload.event.status, UnbanRequestStatus::Canceled);
+    assert!(payload.payload.event.moderator_user_id.is_none());
+}
+
+#[test]
+fn moderator_and_vip() {
+    let payload: ChannelModeratorAddPayload =
+        notification("channel.moderator.add", "1", BROADCASTER, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\"}");
+    assert_eq!(payload.payload.event.user_login, "cool_user");
+    let payload: ChannelModeratorRemovePayload =
+        notification("channel.moderator.remove", "1", BROADCASTER, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\"}");
+    assert_eq!(payload.payload.event.broadcaster_user_id, "1337");
+
+    let payload: ChannelVIPAddPayload =
+        notification("channel.vip.add", "1", BROADCASTER, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\"}");
+    assert_eq!(payload.payload.event.user_login, "cool_user");
+    let payload: ChannelVIPRemovePayload =
+        notification("channel.vip.remove", "1", BROADCASTER, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\"}");
+    assert_eq!(payload.payload.event.broadcaster_user_id, "1337");
+}
+
+#[test]
+fn warning() {
+    let payload: ChannelWarningAcknowledgementPayload = notification(
+        "channel.warning.acknowledge",
+        "1",
+        MODERATOR,
+        "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\"}",
+    );
+    assert_eq!(payload.payload.event.user_login, "cool_user");
+
+    let payload: ChannelWarningSendPayload =
+        notification("channel.warning.send", "1", MODERATOR, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"reason\": \"cut it out\", \"chat_rules_cited\": null}");
+    assert_eq!(payload.payload.event.moderator_user_login, "mod_user");
+    assert_eq!(payload.payload.event.reason.as_deref(), Some("cut it out"));
+    assert!(payload.payload.event.chat_rules_cited.is_none());
+}
+
+#[test]
+fn moderate_v1() {
+    let payload: ChannelModeratePayload =
+        notification("channel.moderate", "1", MODERATOR, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"ban\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": {\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"reason\": \"spam\"}, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null}");
+
+    assert_eq!(payload.payload.event.action.tag(), "ban");
+    let ModerateAction::Ban(ban) = payload.payload.event.action else {
+        panic!("expected ban: {:?}", payload.payload.event.action);
+    };
+    assert_eq!(ban.user_login, "cool_user");
+    assert_eq!(ban.reason.as_deref(), Some("spam"));
+}
+
+#[test]
+fn moderate_timeout() {
+    let ModerateAction::Timeout(timeout) = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"timeout\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": {\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"reason\": null, \"expires_at\": \"2024-03-11T17:13:34.000000000Z\"}, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}") else {
+        panic!("expected timeout");
+    };
+    assert_eq!(timeout.user_id, "1234");
+    assert!(timeout.reason.is_none());
+    assert_eq!(timeout.expires_at.to_rfc3339(), "2024-03-11T17:13:34+00:00");
+}
+
+#[test]
+fn moderate_chat_modes() {
+    let ModerateAction::Followers(followers) = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"followers\", \"followers\": {\"follow_duration_minutes\": 10}, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}") else {
+        panic!("expected followers");
+    };
+    assert_eq!(followers.follow_duration_minutes, 10);
+
+    let ModerateAction::Slow(slow) = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"slow\", \"followers\": null, \"slow\": {\"wait_time_seconds\": 30}, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}") else {
+        panic!("expected slow");
+    };
+    assert_eq!(slow.wait_time_seconds, 30);
+
+    assert!(matches!(moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"emoteonly\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}"), ModerateAction::EmoteOnly));
+}
+
+#[test]
+fn moderate_users() {
+    let ModerateAction::Vip(vip) = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"vip\", \"followers\": null, \"slow\": null, \"vip\": {\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\"}, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}") else {
+        panic!("expected vip");
+    };
+    assert_eq!(vip.user_login, "cool_user");
+
+    let ModerateAction::Raid(raid) = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"raid\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": {\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"viewer_count\": 42}, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}") else {
+        panic!("expected raid");
+    };
+    assert_eq!(raid.viewer_count, 42);
+
+    let ModerateAction::Delete(delete) = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"delete\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": {\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"message_id\": \"bbf2ab0f-ff6e-4f3a-a1ea-d4b4d8e1c1e4\", \"message_body\": \"Hello\"}, \"automod_terms\": null, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}") else {
+        panic!("expected delete");
+    };
+    assert_eq!(delete.message_body, "Hello");
+}
+
+#[test]
+fn moderate_automod_terms() {
+    let action = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"add_blocked_term\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": {\"action\": \"add\", \"list\": \"blocked\", \"terms\": [\"evil\", \"bad\"], \"from_automod\": false}, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}");
+
+    assert_eq!(action.tag(), "add_blocked_term");
+    let ModerateAction::AddBlockedTerm(terms) = action else {
+        panic!("expected add_blocked_term: {action:?}");
+    };
+    assert_eq!(terms.action, AutomodTermsAction::Add);
+    assert_eq!(terms.list, AutomodTermsList::Blocked);
+    assert_eq!(terms.terms, ["evil", "bad"]);
+    assert!(!terms.from_automod);
+}
+
+#[test]
+fn moderate_unban_request() {
+    let ModerateAction::ApproveUnbanRequest(request) = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"approve_unban_request\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": {\"is_approved\": true, \"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"moderator_message\": \"ok\"}, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}") else {
+        panic!("expected approve_unban_request");
+    };
+    assert!(request.is_approved);
+    assert_eq!(request.moderator_message, "ok");
+}
+
+#[test]
+fn moderate_warn() {
+    let ModerateAction::Warn(warn) = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"warn\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null, \"warn\": {\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"reason\": \"cut it out\", \"chat_rules_cited\": [\"Rule 1\"]}, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}") else {
+        panic!("expected warn");
+    };
+    assert_eq!(warn.reason.as_deref(), Some("cut it out"));
+    assert_eq!(warn.chat_rules_cited.unwrap(), ["Rule 1"]);
+}
+
+#[test]
+fn moderate_shared_chat() {
+    let payload: ChannelModerateV2Payload =
+        notification("channel.moderate", "2", MODERATOR, "{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": \"41292030\", \"source_broadcaster_user_login\": \"adflynn404\", \"source_broadcaster_user_name\": \"adflynn404\", \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"shared_chat_ban\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": {\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"reason\": null}, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null}");
+    let event = payload.payload.event;
+
+    assert_eq!(
+        event.source_broadcaster_user_login.as_deref(),
+        Some("adflynn404")
+    );
+    let ModerateAction::SharedChatBan(ban) = event.action else {
+        panic!("expected shared_chat_ban: {:?}", event.action);
+    };
+    assert_eq!(ban.user_login, "cool_user");
+    assert!(ban.reason.is_none());
+}
+
+#[test]
+fn moderate_unknown_action() {
+    let action = moderate("{\"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"source_broadcaster_user_id\": null, \"source_broadcaster_user_login\": null, \"source_broadcaster_user_name\": null, \"moderator_user_id\": \"1339\", \"moderator_user_login\": \"mod_user\", \"moderator_user_name\": \"Mod_User\", \"action\": \"pin\", \"followers\": null, \"slow\": null, \"vip\": null, \"unvip\": null, \"mod\": null, \"unmod\": null, \"ban\": null, \"unban\": null, \"timeout\": null, \"untimeout\": null, \"raid\": null, \"unraid\": null, \"delete\": null, \"automod_terms\": null, \"unban_request\": null, \"warn\": null, \"shared_chat_ban\": null, \"shared_chat_unban\": null, \"shared_chat_timeout\": null, \"shared_chat_untimeout\": null, \"shared_chat_delete\": null, \"pin\": {\"message_id\": \"bbf2ab0f-ff6e-4f3a-a1ea-d4b4d8e1c1e4\"}}");
+
+    assert_eq!(action.tag(), "pin");
+    let ModerateAction::Unknown { kind, data } = &action else {
+        panic!("expected an unknown action: {action:?}");
+    };
+    assert_eq!(kind, "pin");
+    assert_eq!(
+        data["pin"]["message_id"],
+        "bbf2ab0f-ff6e-4f3a-a1ea-d4b4d8e1c1e4"
+    );
+}
+
+#[test]
+fn unknown_status() {
+    let status = serde_json::from_str::<UnbanRequestStatus>("\"expired\"").unwrap();
+    assert_eq!(status, UnbanRequestStatus::Unknown("expired".to_string()));
+    assert_eq!(serde_json::to_string(&status).unwrap(), "\"expired\"");
+
+    assert_eq!(
+        serde_json::from_str::<AutomodTermsList>("\"blocked\"").unwrap(),
+        AutomodTermsList::Blocked
+    );
+    assert_eq!(
+        serde_json::from_str::<AutomodTermsList>("\"warned\"").unwrap(),
+        AutomodTermsList::Unknown("warned".to_string())
+    );
+}
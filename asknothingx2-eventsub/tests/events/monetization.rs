@@ -0,0 +1,204 @@
+use asknothingx2_eventsub::twitch::{
+    events::MaybeAnonymous,
+    subscription_types::channel_subscriptions::{
+        ChannelAdBreakBeginPayload, ChannelCheerPayload, ChannelRaidPayload,
+        ChannelSubscribePayload, ChannelSubscriptionEndPayload, ChannelSubscriptionGiftPayload,
+        ChannelSubscriptionMessagePayload, ShoutoutCreatePayload, ShoutoutReceivedPayload,
+    },
+    types::objects::Tier,
+};
+
+use super::notification;
+
+const CONDITION: &str = "{\"broadcaster_user_id\": \"1337\"}";
+const MODERATOR_CONDITION: &str =
+    "{\"broadcaster_user_id\": \"12345\", \"moderator_user_id\": \"98765\"}";
+
+#[test]
+fn tier() {
+    for (raw, tier) in [
+        ("\"1000\"", Tier::Tier1),
+        ("\"2000\"", Tier::Tier2),
+        ("\"3000\"", Tier::Tier3),
+        ("\"Prime\"", Tier::Prime),
+        ("\"prime\"", Tier::Prime),
+    ] {
+        assert_eq!(serde_json::from_str::<Tier>(raw).unwrap(), tier);
+    }
+    assert_eq!(serde_json::to_string(&Tier::Tier2).unwrap(), "\"2000\"");
+    assert_eq!(serde_json::to_string(&Tier::Prime).unwrap(), "\"Prime\"");
+    assert!(serde_json::from_str::<Tier>("\"4000\"").is_err());
+}
+
+#[test]
+fn subscribe() {
+    let payload: ChannelSubscribePayload =
+        notification("channel.subscribe", "1", CONDITION, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"tier\": \"1000\", \"is_gift\": false}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.user_login, "cool_user");
+    assert_eq!(event.broadcaster_user_id.as_str(), "1337");
+    assert_eq!(event.tier, Tier::Tier1);
+    assert!(!event.is_gift);
+}
+
+#[test]
+fn subscription_end() {
+    let payload: ChannelSubscriptionEndPayload =
+        notification("channel.subscription.end", "1", CONDITION, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"tier\": \"3000\", \"is_gift\": true}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.tier, Tier::Tier3);
+    assert!(event.is_gift);
+}
+
+#[test]
+fn subscription_gift() {
+    let payload: ChannelSubscriptionGiftPayload =
+        notification("channel.subscription.gift", "1", CONDITION, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"total\": 2, \"tier\": \"1000\", \"cumulative_total\": 284, \"is_anonymous\": false}");
+    let event = payload.payload.event;
+
+    assert!(!event.gifter.is_anonymous());
+    assert_eq!(event.gifter.user_id(), Some("1234"));
+    match &event.gifter {
+        MaybeAnonymous::User { user_login, .. } => assert_eq!(user_login, "cool_user"),
+        MaybeAnonymous::Anonymous => panic!("expected a named gifter"),
+    }
+    assert_eq!(event.total, 2);
+    assert_eq!(event.tier, Tier::Tier1);
+    assert_eq!(event.cumulative_total, Some(284));
+}
+
+#[test]
+fn subscription_gift_anonymous() {
+    let payload: ChannelSubscriptionGiftPayload =
+        notification("channel.subscription.gift", "1", CONDITION, "{\"user_id\": null, \"user_login\": null, \"user_name\": null, \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"total\": 5, \"tier\": \"2000\", \"cumulative_total\": null, \"is_anonymous\": true}");
+    let event = payload.payload.event;
+
+    assert!(event.gifter.is_anonymous());
+    assert_eq!(event.gifter.user_id(), None);
+    assert_eq!(event.tier, Tier::Tier2);
+    assert!(event.cumulative_total.is_none());
+
+    let value = serde_json::to_value(&event).unwrap();
+    assert_eq!(value["is_anonymous"], true);
+    assert!(value["user_id"].is_null());
+}
+
+#[test]
+fn subscription_message() {
+    let payload: ChannelSubscriptionMessagePayload =
+        notification("channel.subscription.message", "1", CONDITION, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"tier\": \"1000\", \"message\": {\"text\": \"Love the stream! FevziGG\", \"emotes\": [{\"begin\": 17, \"end\": 22, \"id\": \"89\"}, {\"begin\": 23, \"end\": 30, \"id\": \"302976485\"}]}, \"cumulative_months\": 15, \"streak_months\": 1, \"duration_months\": 6}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.tier, Tier::Tier1);
+    assert_eq!(event.message.text, "Love the stream! FevziGG");
+    assert_eq!(event.message.emotes[0].id, "89");
+    assert_eq!(event.cumulative_months, 15);
+    assert_eq!(event.streak_months, Some(1));
+    assert_eq!(event.duration_months, 6);
+}
+
+#[test]
+fn subscription_message_hidden_streak() {
+    let payload: ChannelSubscriptionMessagePayload =
+        notification("channel.subscription.message", "1", CONDITION, "{\"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"tier\": \"Prime\", \"message\": {\"text\": \"Thanks!\", \"emotes\": []}, \"cumulative_months\": 3, \"streak_months\": null, \"duration_months\": 1}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.tier, Tier::Prime);
+    assert!(event.message.emotes.is_empty());
+    assert!(event.streak_months.is_none());
+}
+
+#[test]
+fn cheer() {
+    let payload: ChannelCheerPayload = notification("channel.cheer", "1", CONDITION, "{\"is_anonymous\": false, \"user_id\": \"1234\", \"user_login\": \"cool_user\", \"user_name\": \"Cool_User\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"message\": \"pogchamp\", \"bits\": 1000}");
+    let event = payload.payload.event;
+
+    assert!(!event.user.is_anonymous());
+    assert_eq!(event.user.user_id(), Some("1234"));
+    assert_eq!(event.message, "pogchamp");
+    assert_eq!(event.bits, 1000);
+}
+
+#[test]
+fn cheer_anonymous() {
+    let payload: ChannelCheerPayload =
+        notification("channel.cheer", "1", CONDITION, "{\"is_anonymous\": true, \"user_id\": null, \"user_login\": null, \"user_name\": null, \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"message\": \"Cheer100\", \"bits\": 100}");
+    let event = payload.payload.event;
+
+    assert!(event.user.is_anonymous());
+    assert_eq!(event.user.user_id(), None);
+    assert_eq!(event.bits, 100);
+}
+
+#[test]
+fn raid() {
+    let payload: ChannelRaidPayload = notification(
+        "channel.raid",
+        "1",
+        "{\"from_broadcaster_user_id\": \"1234\", \"to_broadcaster_user_id\": null}",
+        "{\"from_broadcaster_user_id\": \"1234\", \"from_broadcaster_user_login\": \"cool_user\", \"from_broadcaster_user_name\": \"Cool_User\", \"to_broadcaster_user_id\": \"1337\", \"to_broadcaster_user_login\": \"cool_user\", \"to_broadcaster_user_name\": \"Cool_User\", \"viewers\": 9001}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(
+        payload
+            .payload
+            .subscription
+            .condition
+            .from_broadcaster_user_id
+            .as_deref(),
+        Some("1234")
+    );
+    assert_eq!(event.from_broadcaster_user_id.as_str(), "1234");
+    assert_eq!(event.to_broadcaster_user_login, "cool_user");
+    assert_eq!(event.viewers, 9001);
+}
+
+#[test]
+fn ad_break_begin() {
+    let payload: ChannelAdBreakBeginPayload = notification(
+        "channel.ad_break.begin",
+        "1",
+        "{\"broadcaster_id\": \"1337\"}",
+        "{\"duration_seconds\": 60, \"started_at\": \"2019-11-16T10:11:12.634234626Z\", \"is_automatic\": false, \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cooler_user\", \"broadcaster_user_name\": \"Cooler_User\", \"requester_user_id\": \"1234\", \"requester_user_login\": \"cool_user\", \"requester_user_name\": \"Cool_User\"}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(event.duration_seconds, 60);
+    assert!(!event.is_automatic);
+    assert_eq!(event.requester_user_login, "cool_user");
+    assert_eq!(
+        event.started_at.to_rfc3339(),
+        "2019-11-16T10:11:12.634234626+00:00"
+    );
+}
+
+#[test]
+fn shoutout_create() {
+    let payload: ShoutoutCreatePayload =
+        notification("channel.shoutout.create", "1", MODERATOR_CONDITION, "{\"broadcaster_user_id\": \"12345\", \"broadcaster_user_name\": \"SimplySimple\", \"broadcaster_user_login\": \"simplysimple\", \"moderator_user_id\": \"98765\", \"moderator_user_name\": \"ParticularlyParticular123\", \"moderator_user_login\": \"particularlyparticular123\", \"to_broadcaster_user_id\": \"626262\", \"to_broadcaster_user_name\": \"SandySanderman\", \"to_broadcaster_user_login\": \"sandysanderman\", \"started_at\": \"2022-07-26T17:00:03.17106713Z\", \"viewer_count\": 860, \"cooldown_ends_at\": \"2022-07-26T17:02:03.17106713Z\", \"target_cooldown_ends_at\": \"2022-07-26T18:00:03.17106713Z\"}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.broadcaster_user_id.as_str(), "12345");
+    assert_eq!(event.to_broadcaster_user_id.as_str(), "626262");
+    assert_eq!(event.moderator_user_id, "98765");
+    assert_eq!(event.viewer_count, 860);
+    assert!(event.target_cooldown_ends_at > event.cooldown_ends_at);
+}
+
+#[test]
+fn shoutout_received() {
+    let payload: ShoutoutReceivedPayload = notification(
+        "channel.shoutout.receive",
+        "1",
+        MODERATOR_CONDITION,
+        "{\"broadcaster_user_id\": \"626262\", \"broadcaster_user_name\": \"SandySanderman\", \"broadcaster_user_login\": \"sandysanderman\", \"from_broadcaster_user_id\": \"12345\", \"from_broadcaster_user_name\": \"SimplySimple\", \"from_broadcaster_user_login\": \"simplysimple\", \"viewer_count\": 860, \"started_at\": \"2022-07-26T17:00:03.17106713Z\"}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(event.from_broadcaster_user_id.as_str(), "12345");
+    assert_eq!(event.from_broadcaster_user_name, "SimplySimple");
+    assert_eq!(event.viewer_count, 860);
+}
@@ -0,0 +1,96 @@
+use asknothingx2_eventsub::twitch::{
+    events::{poll::PollStatus, prediction::PredictionStatus},
+    subscription_types::channel_subscriptions::{
+        ChannelPollBeginPayload, ChannelPollEndPayload, ChannelPollProgressPayload,
+        ChannelPredictionBeginPayload, ChannelPredictionEndPayload, ChannelPredictionLockPayload,
+        ChannelPredictionProgressPayload,
+    },
+    types::objects::OutcomeColor,
+};
+
+use super::notification;
+
+const CONDITION: &str = "{\"broadcaster_user_id\": \"1337\"}";
+
+#[test]
+fn poll() {
+    let payload: ChannelPollBeginPayload =
+        notification("channel.poll.begin", "1", CONDITION, "{\"id\": \"1243456\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"title\": \"Aren’t shoes just really hard socks?\", \"choices\": [{\"id\": \"123\", \"title\": \"Yeah!\"}, {\"id\": \"124\", \"title\": \"No!\"}], \"bits_voting\": {\"is_enabled\": false, \"amount_per_vote\": 0}, \"channel_points_voting\": {\"is_enabled\": true, \"amount_per_vote\": 10}, \"started_at\": \"2020-07-15T17:16:03.17106713Z\", \"ends_at\": \"2020-07-15T17:16:08.17106713Z\"}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.choices.len(), 2);
+    assert_eq!(event.choices[0].votes, 0);
+    assert!(!event.bits_voting.is_enabled);
+    assert_eq!(event.channel_points_voting.amount_per_vote, 10);
+
+    let payload: ChannelPollProgressPayload =
+        notification("channel.poll.progress", "1", CONDITION, "{\"id\": \"1243456\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"title\": \"Aren’t shoes just really hard socks?\", \"choices\": [{\"id\": \"123\", \"title\": \"Yeah!\", \"bits_votes\": 0, \"channel_points_votes\": 7, \"votes\": 12}, {\"id\": \"124\", \"title\": \"No!\", \"bits_votes\": 0, \"channel_points_votes\": 4, \"votes\": 14}], \"bits_voting\": {\"is_enabled\": false, \"amount_per_vote\": 0}, \"channel_points_voting\": {\"is_enabled\": true, \"amount_per_vote\": 10}, \"started_at\": \"2020-07-15T17:16:03.17106713Z\", \"ends_at\": \"2020-07-15T17:16:08.17106713Z\"}");
+    let event = payload.payload.event;
+    assert_eq!(event.choices[1].votes, 14);
+    assert_eq!(event.choices[1].channel_points_votes, 4);
+
+    let payload: ChannelPollEndPayload =
+        notification("channel.poll.end", "1", CONDITION, "{\"id\": \"1243456\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"title\": \"Aren’t shoes just really hard socks?\", \"choices\": [{\"id\": \"123\", \"title\": \"Yeah!\", \"bits_votes\": 0, \"channel_points_votes\": 7, \"votes\": 12}, {\"id\": \"124\", \"title\": \"No!\", \"bits_votes\": 0, \"channel_points_votes\": 4, \"votes\": 14}], \"bits_voting\": {\"is_enabled\": false, \"amount_per_vote\": 0}, \"channel_points_voting\": {\"is_enabled\": true, \"amount_per_vote\": 10}, \"started_at\": \"2020-07-15T17:16:03.17106713Z\", \"status\": \"completed\", \"ended_at\": \"2020-07-15T17:16:11.17106713Z\"}");
+    let event = payload.payload.event;
+    assert_eq!(event.status, PollStatus::Completed);
+    assert_eq!(event.choices[0].votes, 12);
+}
+
+#[test]
+fn prediction() {
+    let payload: ChannelPredictionBeginPayload = notification(
+        "channel.prediction.begin",
+        "1",
+        CONDITION,
+        "{\"id\": \"1243456\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"title\": \"Aren’t shoes just really hard socks?\", \"outcomes\": [{\"id\": \"1243456\", \"title\": \"Yeah!\", \"color\": \"blue\"}, {\"id\": \"2243456\", \"title\": \"No!\", \"color\": \"pink\"}], \"started_at\": \"2020-07-15T17:16:03.17106713Z\", \"locks_at\": \"2020-07-15T17:21:03.17106713Z\"}",
+    );
+    let event = payload.payload.event;
+
+    assert_eq!(event.outcomes[0].color, OutcomeColor::Blue);
+    assert_eq!(event.outcomes[1].color, OutcomeColor::Pink);
+    assert!(event.outcomes[0].top_predictors.is_empty());
+
+    let payload: ChannelPredictionProgressPayload = notification(
+        "channel.prediction.progress",
+        "1",
+        CONDITION,
+        "{\"id\": \"1243456\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"title\": \"Aren’t shoes just really hard socks?\", \"outcomes\": [{\"id\": \"1243456\", \"title\": \"Yeah!\", \"color\": \"blue\", \"users\": 10, \"channel_points\": 15000, \"top_predictors\": [{\"user_name\": \"Cool_User\", \"user_login\": \"cool_user\", \"user_id\": \"1234\", \"channel_points_won\": null, \"channel_points_used\": 500}, {\"user_name\": \"Coolest_User\", \"user_login\": \"coolest_user\", \"user_id\": \"1236\", \"channel_points_won\": null, \"channel_points_used\": 200}]}, {\"id\": \"2243456\", \"title\": \"No!\", \"color\": \"pink\", \"users\": 2, \"channel_points\": 200, \"top_predictors\": []}], \"started_at\": \"2020-07-15T17:16:03.17106713Z\", \"locks_at\": \"2020-07-15T17:21:03.17106713Z\"}",
+    );
+    let event = payload.payload.event;
+    assert_eq!(event.outcomes[0].channel_points, 15000);
+    assert_eq!(event.outcomes[0].top_predictors.len(), 2);
+    assert!(event.outcomes[0].top_predictors[0]
+        .channel_points_won
+        .is_none());
+
+    let payload: ChannelPredictionLockPayload = notification(
+        "channel.prediction.lock",
+        "1",
+        CONDITION,
+        "{\"id\": \"1243456\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"title\": \"Aren’t shoes just really hard socks?\", \"outcomes\": [{\"id\": \"1243456\", \"title\": \"Yeah!\", \"color\": \"blue\", \"users\": 10, \"channel_points\": 15000, \"top_predictors\": [{\"user_name\": \"Cool_User\", \"user_login\": \"cool_user\", \"user_id\": \"1234\", \"channel_points_won\": null, \"channel_points_used\": 500}, {\"user_name\": \"Coolest_User\", \"user_login\": \"coolest_user\", \"user_id\": \"1236\", \"channel_points_won\": null, \"channel_points_used\": 200}]}, {\"id\": \"2243456\", \"title\": \"No!\", \"color\": \"pink\", \"users\": 2, \"channel_points\": 200, \"top_predictors\": []}], \"started_at\": \"2020-07-15T17:16:03.17106713Z\", \"locked_at\": \"2020-07-15T17:21:03.17106713Z\"}",
+    );
+    assert_eq!(payload.payload.event.outcomes[1].users, 2);
+}
+
+#[test]
+fn prediction_end() {
+    let payload: ChannelPredictionEndPayload =
+        notification("channel.prediction.end", "1", CONDITION, "{\"id\": \"1243456\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"title\": \"Aren’t shoes just really hard socks?\", \"winning_outcome_id\": \"1243456\", \"outcomes\": [{\"id\": \"1243456\", \"title\": \"Yeah!\", \"color\": \"blue\", \"users\": 10, \"channel_points\": 15000, \"top_predictors\": [{\"user_name\": \"Cool_User\", \"user_login\": \"cool_user\", \"user_id\": \"1234\", \"channel_points_won\": 10000, \"channel_points_used\": 500}]}, {\"id\": \"2243456\", \"title\": \"No!\", \"color\": \"pink\", \"users\": 2, \"channel_points\": 200, \"top_predictors\": []}], \"status\": \"resolved\", \"started_at\": \"2020-07-15T17:16:03.17106713Z\", \"ended_at\": \"2020-07-15T17:16:11.17106713Z\"}");
+    let event = payload.payload.event;
+
+    assert_eq!(event.status, PredictionStatus::Resolved);
+    assert_eq!(event.winning_outcome_id.as_deref(), Some("1243456"));
+    assert_eq!(
+        event.outcomes[0].top_predictors[0].channel_points_won,
+        Some(10000)
+    );
+
+    let payload: ChannelPredictionEndPayload = notification(
+        "channel.prediction.end",
+        "1",
+        CONDITION,
+        "{\"id\": \"1243456\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"title\": \"Aren’t shoes just really hard socks?\", \"winning_outcome_id\": null, \"outcomes\": [{\"id\": \"1243456\", \"title\": \"Yeah!\", \"color\": \"blue\", \"users\": 10, \"channel_points\": 15000, \"top_predictors\": [{\"user_name\": \"Cool_User\", \"user_login\": \"cool_user\", \"user_id\": \"1234\", \"channel_points_won\": null, \"channel_points_used\": 500}, {\"user_name\": \"Coolest_User\", \"user_login\": \"coolest_user\", \"user_id\": \"1236\", \"channel_points_won\": null, \"channel_points_used\": 200}]}, {\"id\": \"2243456\", \"title\": \"No!\", \"color\": \"pink\", \"users\": 2, \"channel_points\": 200, \"top_predictors\": []}], \"status\": \"canceled\", \"started_at\": \"2020-07-15T17:16:03.17106713Z\", \"ended_at\": \"2020-07-15T17:16:11.17106713Z\"}",
+    );
+    assert_eq!(payload.payload.event.status, PredictionStatus::Canceled);
+    assert!(payload.payload.event.winning_outcome_id.is_none());
+}
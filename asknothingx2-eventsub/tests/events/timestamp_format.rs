@@ -0,0 +1,97 @@
+use asknothingx2_eventsub::twitch::{
+    events::stream::StreamOnlineEvent, types::timestamp::rfc3339_nanos,
+};
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Timestamps {
+    #[serde(with = "rfc3339_nanos")]
+    at: DateTime<FixedOffset>,
+    #[serde(serialize_with = "rfc3339_nanos::serialize_option")]
+    maybe_at: Option<DateTime<FixedOffset>>,
+}
+
+fn round_trip(raw: &str) -> String {
+    let value: Timestamps = serde_json::from_str(&format!("{{\"at\": \"{raw}\"}}")).unwrap();
+    serde_json::to_value(&value).unwrap()["at"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn exact_round_trip() {
+    for raw in [
+        "2019-11-16T10:11:12.634234626Z",
+        "2020-07-15T17:16:03.171067130Z",
+        "2024-02-23T21:14:34.000000000Z",
+    ] {
+        assert_eq!(round_trip(raw), raw);
+    }
+}
+
+#[test]
+fn normalizes_precision() {
+    assert_eq!(
+        round_trip("2020-07-15T17:16:03.17106713Z"),
+        "2020-07-15T17:16:03.171067130Z"
+    );
+    assert_eq!(
+        round_trip("2022-12-02T15:00:00.00Z"),
+        "2022-12-02T15:00:00.000000000Z"
+    );
+    assert_eq!(
+        round_trip("2023-04-11T16:20:03Z"),
+        "2023-04-11T16:20:03.000000000Z"
+    );
+}
+
+#[test]
+fn keeps_offset() {
+    assert_eq!(
+        round_trip("2023-04-11T18:20:03.5+02:00"),
+        "2023-04-11T18:20:03.500000000+02:00"
+    );
+}
+
+#[test]
+fn option() {
+    let value: Timestamps =
+        serde_json::from_str("{\"at\": \"2019-11-16T10:11:12.634234626Z\"}").unwrap();
+    assert!(value.maybe_at.is_none());
+
+    let value: Timestamps =
+        serde_json::from_str("{\"at\": \"2019-11-16T10:11:12.634234626Z\", \"maybe_at\": null}")
+            .unwrap();
+    assert!(value.maybe_at.is_none());
+
+    let value: Timestamps = serde_json::from_str(
+        "{\"at\": \"2019-11-16T10:11:12.634234626Z\", \"maybe_at\": \"2019-11-16T10:11:13Z\"}",
+    )
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&value).unwrap()["maybe_at"],
+        "2019-11-16T10:11:13.000000000Z"
+    );
+}
+
+#[test]
+fn rejects_invalid() {
+    assert!(serde_json::from_str::<Timestamps>("{\"at\": \"2019-11-16 10:11:12\"}").is_err());
+    assert!(serde_json::from_str::<Timestamps>("{\"at\": 1573899072}").is_err());
+}
+
+#[test]
+fn from_value() {
+    let event: StreamOnlineEvent = serde_json::from_value(serde_json::json!({
+        "type": "live",
+        "started_at": "2020-10-11T10:11:12.123Z",
+    }))
+    .unwrap();
+
+    assert_eq!(
+        rfc3339_nanos::format(&event.started_at),
+        "2020-10-11T10:11:12.123000000Z"
+    );
+}
@@ -1,5 +1,22 @@
 #[macro_use]
+#[allow(unused_macros)]
 mod util;
 
+#[cfg(all(feature = "twitch-conduit", feature = "twitch-websocket"))]
+mod conduit;
+#[cfg(feature = "twitch-webhook")]
+mod events;
+#[cfg(all(feature = "twitch-manage", feature = "twitch-websocket"))]
+mod manage;
+#[cfg(feature = "twitch-router")]
+mod router;
+#[cfg(all(
+    feature = "twitch-webhook",
+    feature = "twitch-websocket",
+    feature = "twitch-conduit"
+))]
 mod subscription;
+#[cfg(feature = "twitch-webhook")]
+mod webhook;
+#[cfg(feature = "twitch-websocket")]
 mod websocket_message;
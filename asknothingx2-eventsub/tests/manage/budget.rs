@@ -0,0 +1,201 @@
+use asknothingx2_eventsub::twitch::{
+    manage::{CostBudget, CostReason, Reconciler, Subscriptions},
+    request::CreateEventSubRequest,
+    response::EventSubscriptionsResponse,
+    types::{
+        BroadcasterCondition, BroadcasterModeratorCondition, BroadcasterUserCondition, Condition,
+        ConduitShardDisabledCondition, ExtensionBitsTransactionCondition, SessionId,
+        SubscriptionType, Transport, UserAuthorizationCondition,
+    },
+};
+use futures_util::StreamExt;
+
+use super::{page, subscription, MockServer};
+
+fn request(kind: SubscriptionType, broadcaster: &str) -> CreateEventSubRequest<Condition> {
+    CreateEventSubRequest::new(
+        kind,
+        BroadcasterCondition::new(broadcaster).into(),
+        websocket(),
+    )
+}
+
+fn websocket() -> Transport {
+    Transport::websocket(SessionId::new("AQoQexAWVYKSTIu4ec_2VAxyuhAB"))
+}
+
+fn response(total_cost: u64, max_total_cost: u64) -> EventSubscriptionsResponse {
+    serde_json::from_str(&format!(
+        "{{\"data\":[],\"total\":0,\"total_cost\":{total_cost},\"max_total_cost\":{max_total_cost},\"pagination\":{{}}}}"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn explains_cost() {
+    let budget = CostBudget::new();
+    budget.authorize("1337");
+
+    let authorized = budget.estimate(&request(SubscriptionType::StreamOnline, "1337"));
+    assert_eq!(authorized.cost, 0);
+    assert_eq!(
+        authorized.reason,
+        CostReason::Authorized("1337".to_string())
+    );
+    assert_eq!(
+        authorized.reason.to_string(),
+        "costs 0: user 1337 authorized the app"
+    );
+
+    let other = budget.estimate(&request(SubscriptionType::StreamOnline, "42"));
+    assert_eq!(other.cost, 1);
+    assert_eq!(other.reason, CostReason::NotAuthorized);
+    assert_eq!(
+        other.reason.to_string(),
+        "costs 1: no user in the condition authorized the app"
+    );
+}
+
+#[test]
+fn types_requiring_authorization_are_free() {
+    let budget = CostBudget::new();
+    let batch = [
+        CreateEventSubRequest::new(
+            SubscriptionType::ChannelFollow,
+            BroadcasterModeratorCondition::new("42", "42").into(),
+            websocket(),
+        ),
+        CreateEventSubRequest::new(
+            SubscriptionType::ChannelChatMessage,
+            BroadcasterUserCondition::new("42", "1337").into(),
+            websocket(),
+        ),
+        CreateEventSubRequest::new(
+            SubscriptionType::ChannelModerateV2,
+            BroadcasterModeratorCondition::new("42", "1337").into(),
+            websocket(),
+        ),
+    ];
+
+    let forecast = budget.forecast(&batch);
+    assert_eq!(forecast.cost, 0);
+    assert!(forecast
+        .estimates
+        .iter()
+        .all(|estimate| estimate.reason == CostReason::RequiresAuthorization));
+    assert_eq!(
+        forecast.estimates[0].reason.to_string(),
+        "costs 0: the type requires user authorization"
+    );
+}
+
+#[test]
+fn app_level_types_are_free() {
+    let budget = CostBudget::new();
+    let batch = [
+        CreateEventSubRequest::new(
+            SubscriptionType::ConduitShardDisabled,
+            ConduitShardDisabledCondition::new("uo6dggojyb8d6soh92zknwmi5ej1q2").into(),
+            websocket(),
+        ),
+        CreateEventSubRequest::new(
+            SubscriptionType::UserAuthorizationRevoke,
+            UserAuthorizationCondition::new("uo6dggojyb8d6soh92zknwmi5ej1q2").into(),
+            websocket(),
+        ),
+        CreateEventSubRequest::new(
+            SubscriptionType::ExtensionBitsTransactionCreate,
+            ExtensionBitsTransactionCondition::new("deadbeef").into(),
+            websocket(),
+        ),
+    ];
+
+    let forecast = budget.forecast(&batch);
+    assert_eq!(forecast.cost, 0);
+    assert!(forecast
+        .estimates
+        .iter()
+        .all(|estimate| estimate.reason == CostReason::AppLevel));
+    assert_eq!(
+        forecast.estimates[0].reason.to_string(),
+        "costs 0: the condition names no user"
+    );
+}
+
+#[test]
+fn forecast_against_max_total_cost() {
+    let budget = CostBudget::new();
+    budget.authorize("1337");
+    let batch = [
+        request(SubscriptionType::StreamOnline, "1337"),
+        request(SubscriptionType::StreamOnline, "42"),
+        request(SubscriptionType::StreamOffline, "42"),
+    ];
+
+    let forecast = budget.forecast(&batch);
+    assert_eq!(forecast.cost, 2);
+    assert_eq!(forecast.max_total_cost, None);
+    assert!(forecast.fits());
+
+    budget.record(&response(8, 10));
+    assert_eq!(budget.remaining(), Some(2));
+    assert!(budget.forecast(&batch).fits());
+
+    budget.record(&response(9, 10));
+    let forecast = budget.forecast(&batch);
+    assert!(!forecast.fits());
+    assert_eq!(forecast.over_by(), 1);
+    assert_eq!(
+        forecast
+            .estimates
+            .iter()
+            .map(|estimate| estimate.cost)
+            .collect::<Vec<_>>(),
+        [0, 1, 1]
+    );
+
+    budget.release(1);
+    assert!(budget.forecast(&batch).fits());
+}
+
+#[tokio::test]
+async fn updated_from_responses() {
+    let server = MockServer::start(vec![
+        (
+            200,
+            page(
+                &[subscription("1", "stream.online", "enabled", "42")],
+                7,
+                None,
+            ),
+        ),
+        (
+            200,
+            page(
+                &[subscription("1", "stream.online", "enabled", "42")],
+                7,
+                None,
+            ),
+        ),
+        (204, String::new()),
+    ])
+    .await;
+    let api = server.api();
+    let budget = CostBudget::new();
+
+    let listed = Subscriptions::new(&api, None)
+        .budget(budget.clone())
+        .count()
+        .await;
+    assert_eq!(listed, 1);
+    assert_eq!(budget.total_cost(), 7);
+    assert_eq!(budget.max_total_cost(), Some(10000));
+
+    let reconciled = Reconciler::new(&api, Vec::new())
+        .budget(budget.clone())
+        .apply()
+        .await
+        .unwrap();
+    assert_eq!(reconciled.deleted.len(), 1);
+    assert_eq!(budget.total_cost(), 6);
+}
@@ -0,0 +1,252 @@
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "twitch-conduit")]
+use asknothingx2_eventsub::twitch::{
+    request::{
+        CreateConduitRequest, GetConduitShardsRequest, UpdateConduitRequest,
+        UpdateConduitShardsRequest,
+    },
+    response::{ConduitShardsResponse, ConduitsResponse, UpdateConduitShardsResponse},
+    types::ConduitId,
+};
+use asknothingx2_eventsub::twitch::{
+    request::{CreateEventSubRequest, GetEventRequest},
+    response::EventSubscriptionsResponse,
+    types::{Condition, SubscriptionId},
+    EventSubAPI,
+};
+use asknothingx2_util::oauth::{AccessToken, ClientId};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+use twitch_highway::{
+    base::TwitchAPIBase,
+    request::{EmptyBody, TwitchAPIRequest},
+    test_url::TestUrl,
+    types::PaginationQuery,
+    TwitchAPI,
+};
+
+mod budget;
+mod reconcile;
+mod subscriptions;
+
+/// Sends every request to a local [`MockServer`] instead of api.twitch.tv.
+pub struct MockApi {
+    api: TwitchAPI,
+    port: u16,
+}
+
+impl MockApi {
+    pub fn new(port: u16) -> Self {
+        Self {
+            api: TwitchAPI::new(
+                AccessToken::new("access_token".to_string()),
+                ClientId::new("client_id".to_string()),
+            ),
+            port,
+        }
+    }
+}
+
+impl TwitchAPIBase for MockApi {
+    fn access_token(&self) -> &AccessToken {
+        self.api.access_token()
+    }
+    fn client_id(&self) -> &ClientId {
+        self.api.client_id()
+    }
+}
+
+impl EventSubAPI for MockApi {
+    fn create_eventsub(
+        &self,
+        request: CreateEventSubRequest<Condition>,
+    ) -> TwitchAPIRequest<CreateEventSubRequest<Condition>, EventSubscriptionsResponse> {
+        self.api
+            .create_eventsub(request)
+            .with_url(Some(self.port), None, false)
+    }
+    fn delete_eventsub(
+        &self,
+        subscription_id: SubscriptionId,
+    ) -> TwitchAPIRequest<EmptyBody, EmptyBody> {
+        self.api
+            .delete_eventsub(subscription_id)
+            .with_url(Some(self.port), None, false)
+    }
+    fn get_eventsub(
+        &self,
+        opts: Option<GetEventRequest>,
+        pagination: Option<PaginationQuery>,
+    ) -> TwitchAPIRequest<EmptyBody, EventSubscriptionsResponse> {
+        self.api
+            .get_eventsub(opts, pagination)
+            .with_url(Some(self.port), None, false)
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn get_conduits(&self) -> TwitchAPIRequest<EmptyBody, ConduitsResponse> {
+        self.api
+            .get_conduits()
+            .with_url(Some(self.port), None, false)
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn create_conduit(
+        &self,
+        shard_count: u64,
+    ) -> TwitchAPIRequest<CreateConduitRequest, ConduitsResponse> {
+        self.api
+            .create_conduit(shard_count)
+            .with_url(Some(self.port), None, false)
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn update_conduit(
+        &self,
+        conduit_id: ConduitId,
+        shard_count: u64,
+    ) -> TwitchAPIRequest<UpdateConduitRequest, ConduitsResponse> {
+        self.api
+            .update_conduit(conduit_id, shard_count)
+            .with_url(Some(self.port), None, false)
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn delete_conduit(&self, conduit_id: ConduitId) -> TwitchAPIRequest<EmptyBody, EmptyBody> {
+        self.api
+            .delete_conduit(conduit_id)
+            .with_url(Some(self.port), None, false)
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn get_conduit_shards(
+        &self,
+        request: GetConduitShardsRequest,
+        pagination: Option<PaginationQuery>,
+    ) -> TwitchAPIRequest<EmptyBody, ConduitShardsResponse> {
+        self.api
+            .get_conduit_shards(request, pagination)
+            .with_url(Some(self.port), None, false)
+    }
+    #[cfg(feature = "twitch-conduit")]
+    fn update_conduit_shards(
+        &self,
+        request: UpdateConduitShardsRequest,
+    ) -> TwitchAPIRequest<UpdateConduitShardsRequest, UpdateConduitShardsResponse> {
+        self.api
+            .update_conduit_shards(request)
+            .with_url(Some(self.port), None, false)
+    }
+}
+
+/// Answers each HTTP request with the next of `responses` and records its
+/// request line, e.g. `GET /eventsub/subscriptions?status=enabled`, and body.
+pub struct MockServer {
+    pub port: u16,
+    requests: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl MockServer {
+    pub async fn start(responses: Vec<(u16, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let seen = requests.clone();
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let (mut tcp, _) = listener.accept().await.unwrap();
+                let (line, request) = read_request(&mut tcp).await;
+                seen.lock().unwrap().push((
+                    line.trim_end_matches(" HTTP/1.1")
+                        .trim_end_matches('?')
+                        .to_string(),
+                    request,
+                ));
+
+                let response = format!(
+                    "HTTP/1.1 {status} OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                tcp.write_all(response.as_bytes()).await.unwrap();
+                tcp.shutdown().await.unwrap();
+            }
+        });
+
+        Self { port, requests }
+    }
+
+    pub fn api(&self) -> MockApi {
+        MockApi::new(self.port)
+    }
+
+    pub fn requests(&self) -> Vec<String> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(line, _)| line.clone())
+            .collect()
+    }
+
+    pub fn bodies(&self) -> Vec<String> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, body)| body.clone())
+            .collect()
+    }
+}
+
+/// Reads the request line and the `content-length` bytes of body.
+async fn read_request(tcp: &mut TcpStream) -> (String, String) {
+    let mut buf = Vec::new();
+    let mut chunk = [0; 8192];
+    loop {
+        let n = tcp.read(&mut chunk).await.unwrap();
+        buf.extend_from_slice(&chunk[..n]);
+
+        let text = String::from_utf8_lossy(&buf);
+        let Some(end) = text.find("\r\n\r\n") else {
+            if n == 0 {
+                return (
+                    text.lines().next().unwrap_or_default().to_string(),
+                    String::new(),
+                );
+            }
+            continue;
+        };
+        let head = &text[..end];
+        let length = head
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().ok())?
+            })
+            .unwrap_or(0);
+        if buf.len() >= end + 4 + length || n == 0 {
+            let line = head.lines().next().unwrap_or_default().to_string();
+            let body = String::from_utf8_lossy(&buf[end + 4..]).to_string();
+            return (line, body);
+        }
+    }
+}
+
+/// A subscription as returned by Get EventSub Subscriptions.
+pub fn subscription(id: &str, kind: &str, status: &str, user_id: &str) -> String {
+    format!(
+        "{{\"id\":\"{id}\",\"status\":\"{status}\",\"type\":\"{kind}\",\"version\":\"1\",\"cost\":1,\"condition\":{{\"broadcaster_user_id\":\"{user_id}\"}},\"transport\":{{\"method\":\"websocket\",\"session_id\":\"AQoQexAWVYKSTIu4ec_2VAxyuhAB\",\"connected_at\":\"2022-11-16T10:11:12.634234626Z\"}},\"created_at\":\"2022-11-16T10:11:12.634234626Z\"}}"
+    )
+}
+
+/// A Get EventSub Subscriptions page.
+pub fn page(data: &[String], total_cost: u64, cursor: Option<&str>) -> String {
+    let pagination = cursor
+        .map(|c| format!("{{\"cursor\":\"{c}\"}}"))
+        .unwrap_or_else(|| "{}".to_string());
+    format!(
+        "{{\"data\":[{}],\"total\":{},\"total_cost\":{total_cost},\"max_total_cost\":10000,\"pagination\":{pagination}}}",
+        data.join(","),
+        data.len()
+    )
+}
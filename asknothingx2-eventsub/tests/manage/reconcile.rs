@@ -0,0 +1,206 @@
+use asknothingx2_eventsub::twitch::{
+    error::RequestError,
+    manage::{Change, Reconciler, StaleReason},
+    request::CreateEventSubRequest,
+    types::{
+        BroadcasterCondition, ChannelRaidCondition, Condition, SessionId, Status, SubscriptionType,
+        Transport,
+    },
+};
+
+use super::{page, subscription, MockServer};
+
+const SESSION_ID: &str = "AQoQexAWVYKSTIu4ec_2VAxyuhAB";
+
+fn desired(kind: SubscriptionType, broadcaster: &str) -> CreateEventSubRequest<Condition> {
+    CreateEventSubRequest::new(
+        kind,
+        BroadcasterCondition::new(broadcaster).into(),
+        Transport::websocket(SessionId::new(SESSION_ID)),
+    )
+}
+
+fn existing() -> String {
+    page(
+        &[
+            subscription("1", "stream.online", "enabled", "1337"),
+            subscription("2", "stream.online", "enabled", "1337"),
+            subscription("3", "stream.offline", "authorization_revoked", "1337"),
+            subscription("4", "channel.subscribe", "enabled", "42"),
+        ],
+        4,
+        None,
+    )
+}
+
+fn desired_set() -> Vec<CreateEventSubRequest<Condition>> {
+    vec![
+        desired(SubscriptionType::StreamOnline, "1337"),
+        desired(SubscriptionType::StreamOffline, "1337"),
+        desired(SubscriptionType::ChannelSubscribe, "1337"),
+    ]
+}
+
+#[tokio::test]
+async fn plan_is_dry_run() {
+    let server = MockServer::start(vec![(200, existing())]).await;
+    let api = server.api();
+
+    let plan = Reconciler::new(&api, desired_set()).plan().await.unwrap();
+
+    assert!(!plan.is_empty());
+    assert_eq!(plan.keep.len(), 1);
+    assert_eq!(plan.keep[0].id.as_str(), "1");
+
+    let delete: Vec<_> = plan
+        .delete
+        .iter()
+        .map(|stale| (stale.subscription.id.as_str(), stale.reason.clone()))
+        .collect();
+    assert_eq!(
+        delete,
+        [
+            ("2", StaleReason::Duplicate),
+            ("3", StaleReason::Failed(Status::AuthorizationRevoked)),
+            ("4", StaleReason::NotDesired),
+        ]
+    );
+
+    let create: Vec<_> = plan.create.iter().map(|r| r.kind.clone()).collect();
+    assert_eq!(
+        create,
+        [
+            SubscriptionType::StreamOffline,
+            SubscriptionType::ChannelSubscribe
+        ]
+    );
+    assert_eq!(server.requests(), ["GET /eventsub/subscriptions"]);
+}
+
+#[tokio::test]
+async fn apply_deletes_then_creates() {
+    let created = page(
+        &[subscription("5", "stream.offline", "enabled", "1337")],
+        4,
+        None,
+    );
+    let server = MockServer::start(vec![
+        (200, existing()),
+        (204, String::new()),
+        (204, String::new()),
+        (204, String::new()),
+        (202, created),
+        (
+            409,
+            "{\"error\":\"Conflict\",\"status\":409,\"message\":\"subscription already exists\"}"
+                .to_string(),
+        ),
+    ])
+    .await;
+    let api = server.api();
+
+    let reconciled = Reconciler::new(&api, desired_set()).apply().await.unwrap();
+
+    let deleted: Vec<_> = reconciled.deleted.iter().map(|id| id.as_str()).collect();
+    assert_eq!(deleted, ["2", "3", "4"]);
+    assert_eq!(reconciled.created.len(), 1);
+    assert_eq!(reconciled.created[0].id.as_str(), "5");
+    assert_eq!(reconciled.kept.len(), 1);
+
+    assert_eq!(reconciled.failures.len(), 1);
+    let failure = &reconciled.failures[0];
+    assert!(matches!(
+        &failure.change,
+        Change::Create(request) if request.kind == SubscriptionType::ChannelSubscribe
+    ));
+    assert!(matches!(
+        &failure.error,
+        RequestError::Api { status, .. } if status.as_u16() == 409
+    ));
+
+    assert_eq!(
+        server.requests(),
+        [
+            "GET /eventsub/subscriptions",
+            "DELETE /eventsub/subscriptions?id=2",
+            "DELETE /eventsub/subscriptions?id=3",
+            "DELETE /eventsub/subscriptions?id=4",
+            "POST /eventsub/subscriptions",
+            "POST /eventsub/subscriptions",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn matching_set_is_empty_plan() {
+    let server = MockServer::start(vec![(
+        200,
+        page(
+            &[subscription("1", "stream.online", "enabled", "1337")],
+            1,
+            None,
+        ),
+    )])
+    .await;
+    let api = server.api();
+
+    let plan = Reconciler::new(&api, vec![desired(SubscriptionType::StreamOnline, "1337")])
+        .plan()
+        .await
+        .unwrap();
+    assert!(plan.is_empty());
+}
+
+#[tokio::test]
+async fn empty_fields_still_match() {
+    let with_condition = |id: &str, kind: &str, version: &str, condition: &str| {
+        format!(
+            "{{\"id\":\"{id}\",\"status\":\"enabled\",\"type\":\"{kind}\",\"version\":\"{version}\",\"cost\":0,\"condition\":{condition},\"transport\":{{\"method\":\"websocket\",\"session_id\":\"{SESSION_ID}\",\"connected_at\":\"2022-11-16T10:11:12.634234626Z\"}},\"created_at\":\"2022-11-16T10:11:12.634234626Z\"}}"
+        )
+    };
+    let server = MockServer::start(vec![(
+        200,
+        page(
+            &[
+                with_condition(
+                    "1",
+                    "channel.raid",
+                    "1",
+                    "{\"from_broadcaster_user_id\":\"1337\",\"to_broadcaster_user_id\":\"\"}",
+                ),
+                with_condition(
+                    "2",
+                    "channel.brand_new.event",
+                    "3",
+                    "{\"broadcaster_user_id\":\"1337\",\"widget_id\":\"\",\"user_id\":null}",
+                ),
+            ],
+            0,
+            None,
+        ),
+    )])
+    .await;
+    let api = server.api();
+
+    let desired = vec![
+        CreateEventSubRequest::new(
+            SubscriptionType::ChannelRaid,
+            ChannelRaidCondition::new()
+                .from_broadcaster_user_id("1337")
+                .into(),
+            Transport::websocket(SessionId::new(SESSION_ID)),
+        ),
+        CreateEventSubRequest::new(
+            SubscriptionType::Unknown {
+                kind: "channel.brand_new.event".to_string(),
+                version: "3".to_string(),
+            },
+            Condition::Unknown(serde_json::json!({"broadcaster_user_id": "1337"})),
+            Transport::websocket(SessionId::new(SESSION_ID)),
+        ),
+    ];
+
+    let plan = Reconciler::new(&api, desired).plan().await.unwrap();
+    assert!(plan.is_empty(), "{plan:?}");
+    assert_eq!(plan.keep.len(), 2);
+}
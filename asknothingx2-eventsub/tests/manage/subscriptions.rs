@@ -0,0 +1,102 @@
+use asknothingx2_eventsub::twitch::{
+    error::RequestError,
+    manage::Subscriptions,
+    request::GetEventRequest,
+    types::{Status, SubscriptionType},
+};
+use futures_util::{StreamExt, TryStreamExt};
+
+use super::{page, subscription, MockServer};
+
+#[tokio::test]
+async fn follows_cursor_until_exhausted() {
+    let server = MockServer::start(vec![
+        (
+            200,
+            page(
+                &[
+                    subscription("1", "channel.update", "enabled", "1337"),
+                    subscription("2", "stream.online", "enabled", "1337"),
+                ],
+                2,
+                Some("page2"),
+            ),
+        ),
+        (
+            200,
+            page(
+                &[subscription("3", "stream.offline", "enabled", "1337")],
+                5,
+                None,
+            ),
+        ),
+    ])
+    .await;
+    let api = server.api();
+
+    let mut stream = Subscriptions::new(
+        &api,
+        Some(GetEventRequest {
+            status: Some(Status::Enabled),
+            ..Default::default()
+        }),
+    )
+    .first(2);
+    assert_eq!(stream.total_cost(), None);
+
+    let mut ids = Vec::new();
+    while let Some(subscription) = stream.try_next().await.unwrap() {
+        ids.push(subscription.id.to_string());
+    }
+
+    assert_eq!(ids, ["1", "2", "3"]);
+    assert_eq!(stream.total_cost(), Some(2));
+    assert_eq!(stream.max_total_cost(), Some(10000));
+    assert_eq!(
+        server.requests(),
+        [
+            "GET /eventsub/subscriptions?status=enabled&first=2",
+            "GET /eventsub/subscriptions?status=enabled&first=2&after=page2",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn passes_type_filter() {
+    let server = MockServer::start(vec![(200, page(&[], 0, None))]).await;
+    let api = server.api();
+
+    let stream = Subscriptions::new(
+        &api,
+        Some(GetEventRequest {
+            kind: Some(SubscriptionType::StreamOnline),
+            ..Default::default()
+        }),
+    );
+    assert_eq!(stream.count().await, 0);
+    assert_eq!(
+        server.requests(),
+        ["GET /eventsub/subscriptions?type=stream.online"]
+    );
+}
+
+#[tokio::test]
+async fn ends_after_error() {
+    let server = MockServer::start(vec![(
+        401,
+        "{\"error\":\"Unauthorized\",\"status\":401,\"message\":\"Invalid OAuth token\"}"
+            .to_string(),
+    )])
+    .await;
+    let api = server.api();
+
+    let results: Vec<_> = Subscriptions::new(&api, None).collect().await;
+    assert_eq!(results.len(), 1);
+    match &results[0] {
+        Err(RequestError::Api { status, message }) => {
+            assert_eq!(status.as_u16(), 401);
+            assert_eq!(message, "Invalid OAuth token");
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+}
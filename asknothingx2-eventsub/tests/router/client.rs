@@ -0,0 +1,68 @@
+use asknothingx2_eventsub::twitch::{
+    error::ClientError,
+    router::WebSocketClient,
+    websocket_message::{CloseAction, CloseReason, WebSocketMessage},
+};
+use futures_util::SinkExt;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::{
+    protocol::{frame::coding::CloseCode, CloseFrame},
+    Message,
+};
+use url::Url;
+
+const WELCOME: &str = "{\"metadata\": {\"message_id\": \"96a3f3b5-5dec-4eed-908e-e11ee657416c\", \"message_type\": \"session_welcome\", \"message_timestamp\": \"2023-07-19T14:56:51.634234626Z\"}, \"payload\": {\"session\": {\"id\": \"AQoQILE98gtqShGmLD7AM6yJThAB\", \"status\": \"connected\", \"connected_at\": \"2023-07-19T14:56:51.616329898Z\", \"keepalive_timeout_seconds\": 10, \"reconnect_url\": null}}}";
+
+/// Accepts one connection, sends a Welcome and closes it with `code`.
+async fn serve_close(code: u16) -> Url {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+        ws.send(Message::text(WELCOME)).await.unwrap();
+        ws.close(Some(CloseFrame {
+            code: CloseCode::from(code),
+            reason: "".into(),
+        }))
+        .await
+        .unwrap();
+    });
+
+    Url::parse(&format!("ws://{addr}")).unwrap()
+}
+
+#[tokio::test]
+async fn close_code_is_typed() {
+    let mut client = WebSocketClient::new()
+        .url(serve_close(4001).await)
+        .auto_reconnect(false);
+
+    assert!(matches!(
+        client.next_message().await,
+        Ok(WebSocketMessage::Welcome(_))
+    ));
+    assert!(client.subscribe_deadline().is_some());
+
+    let err = client.next_message().await.unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::Closed(CloseReason::ClientSentInboundTraffic)
+    ));
+    assert_eq!(err.action(), CloseAction::Fatal);
+    assert!(!client.is_connected());
+}
+
+#[tokio::test]
+async fn connection_unused_reports_subscribe_window() {
+    let mut client = WebSocketClient::new()
+        .url(serve_close(4003).await)
+        .auto_reconnect(false);
+
+    client.next_message().await.unwrap();
+
+    let err = client.next_message().await.unwrap_err();
+    assert!(matches!(err, ClientError::SubscribeWindowExpired(_)));
+    assert_eq!(err.action(), CloseAction::Resubscribe);
+}
@@ -0,0 +1,160 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use asknothingx2_eventsub::twitch::{
+    error::HandlerError,
+    router::{Router, WebSocketClient},
+    types::SubscriptionType,
+    websocket_message::WebSocketMessage,
+};
+use futures_util::{SinkExt, StreamExt};
+use tokio::{net::TcpListener, sync::mpsc, time};
+use tokio_tungstenite::tungstenite::Message;
+use twitch_highway::types::BroadcasterId;
+use url::Url;
+
+fn follow(broadcaster_user_id: &str) -> WebSocketMessage {
+    format!("{{\"metadata\": {{\"message_id\": \"befa7b53-d79d-478f-86b9-120f112b044e\", \"message_type\": \"notification\", \"message_timestamp\": \"2022-11-16T10:11:12.464757833Z\", \"subscription_type\": \"channel.follow\", \"subscription_version\": \"2\"}}, \"payload\": {{\"subscription\": {{\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"enabled\", \"type\": \"channel.follow\", \"version\": \"2\", \"cost\": 1, \"condition\": {{\"broadcaster_user_id\": \"{broadcaster_user_id}\", \"moderator_user_id\": \"{broadcaster_user_id}\"}}, \"transport\": {{\"method\": \"websocket\", \"session_id\": \"AQoQexAWVYKSTIu4ec_2VAxyuhAB\"}}, \"created_at\": \"2022-11-16T10:11:12.464757833Z\"}}, \"event\": {{\"user_id\": \"1337\", \"user_login\": \"awesome_user\", \"user_name\": \"Awesome_User\", \"broadcaster_user_id\": \"{broadcaster_user_id}\", \"broadcaster_user_login\": \"twitch\", \"broadcaster_user_name\": \"Twitch\", \"followed_at\": \"2023-07-15T18:16:11.17106713Z\"}}}}}}")
+        .parse()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn broadcaster_route_takes_precedence() {
+    let called = Arc::new(Mutex::new(Vec::new()));
+
+    let by_type = called.clone();
+    let by_broadcaster = called.clone();
+    let router = Router::new()
+        .route(SubscriptionType::ChannelFollow, move |ctx| {
+            let called = by_type.clone();
+            async move {
+                called
+                    .lock()
+                    .unwrap()
+                    .push(format!("type:{}", ctx.event["user_login"]));
+                Ok(())
+            }
+        })
+        .route_broadcaster(
+            SubscriptionType::ChannelFollow,
+            BroadcasterId::new("12826"),
+            move |_| {
+                let called = by_broadcaster.clone();
+                async move {
+                    called.lock().unwrap().push("broadcaster".to_string());
+                    Ok(())
+                }
+            },
+        );
+
+    router.dispatch(follow("12826")).await;
+    router.dispatch(follow("1")).await;
+
+    assert_eq!(
+        *called.lock().unwrap(),
+        vec![
+            "broadcaster".to_string(),
+            "type:\"awesome_user\"".to_string()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn panicking_handler_is_isolated() {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+
+    let hook = errors.clone();
+    let router = Router::new()
+        .route(SubscriptionType::ChannelFollow, |_| async {
+            panic!("boom")
+        })
+        .fallback(|_| async { Err("unhandled".into()) })
+        .on_error(move |e| hook.lock().unwrap().push(e.to_string()));
+
+    router.dispatch(follow("12826")).await;
+
+    let errors = errors.lock().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("panicked: boom"));
+}
+
+#[tokio::test]
+async fn fallback_receives_unrouted_errors() {
+    let errors = Arc::new(Mutex::new(Vec::<String>::new()));
+
+    let hook = errors.clone();
+    let router = Router::new()
+        .fallback(|_| async { Err("unhandled".into()) })
+        .on_error(move |e: &HandlerError| hook.lock().unwrap().push(e.to_string()));
+
+    router.dispatch(follow("12826")).await;
+
+    assert_eq!(
+        *errors.lock().unwrap(),
+        vec!["Handler for channel.follow failed: unhandled".to_string()]
+    );
+}
+
+fn welcome(session_id: &str) -> String {
+    format!("{{\"metadata\": {{\"message_id\": \"96a3f3b5-5dec-4eed-908e-e11ee657416c\", \"message_type\": \"session_welcome\", \"message_timestamp\": \"2023-07-19T14:56:51.634234626Z\"}}, \"payload\": {{\"session\": {{\"id\": \"{session_id}\", \"status\": \"connected\", \"connected_at\": \"2023-07-19T14:56:51.616329898Z\", \"keepalive_timeout_seconds\": 10, \"reconnect_url\": null}}}}}}")
+}
+
+/// Welcomes the first connection with `old-session` and asks it to move to a
+/// second connection, which is welcomed with `new-session`.
+async fn serve_reconnect() -> Url {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut old = tokio_tungstenite::accept_async(tcp).await.unwrap();
+        old.send(Message::text(welcome("old-session")))
+            .await
+            .unwrap();
+        old.send(Message::text(format!("{{\"metadata\": {{\"message_id\": \"84c1e79a-2a4b-4c13-ba0b-4312293e9308\", \"message_type\": \"session_reconnect\", \"message_timestamp\": \"2022-11-18T09:10:11.634234626Z\"}}, \"payload\": {{\"session\": {{\"id\": \"old-session\", \"status\": \"reconnecting\", \"keepalive_timeout_seconds\": null, \"reconnect_url\": \"ws://{addr}\", \"connected_at\": \"2022-11-16T10:11:12.634234626Z\"}}}}}}")))
+            .await
+            .unwrap();
+
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut new = tokio_tungstenite::accept_async(tcp).await.unwrap();
+        new.send(Message::text(welcome("new-session")))
+            .await
+            .unwrap();
+        while let Some(Ok(_)) = new.next().await {}
+        drop(old);
+    });
+
+    Url::parse(&format!("ws://{addr}")).unwrap()
+}
+
+#[tokio::test]
+async fn reconnect_handler_receives_new_session() {
+    let (tx, mut sessions) = mpsc::unbounded_channel();
+
+    let router = Router::new().on_reconnect(move |session| {
+        let tx = tx.clone();
+        async move {
+            tx.send((session.id.as_str().to_string(), session.status.to_string()))
+                .unwrap();
+            Ok(())
+        }
+    });
+    let client = WebSocketClient::new()
+        .url(serve_reconnect().await)
+        .auto_reconnect(false);
+    let running = tokio::spawn(async move { router.run(client).await });
+
+    let session = time::timeout(Duration::from_secs(5), sessions.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    running.abort();
+
+    assert_eq!(
+        session,
+        ("new-session".to_string(), "connected".to_string())
+    );
+}
@@ -0,0 +1,46 @@
+#[cfg(feature = "twitch-manage")]
+use futures_util::{SinkExt, StreamExt};
+#[cfg(feature = "twitch-manage")]
+use tokio::{net::TcpListener, sync::mpsc};
+#[cfg(feature = "twitch-manage")]
+use tokio_tungstenite::tungstenite::Message;
+#[cfg(feature = "twitch-manage")]
+use url::Url;
+
+mod client;
+mod dispatch;
+mod pool;
+#[cfg(feature = "twitch-manage")]
+mod subscriber;
+#[cfg(all(feature = "twitch-conduit", feature = "twitch-manage"))]
+mod supervisor;
+
+/// Welcomes the `n`th connection with session `session-n` and reports
+/// the session of every connection the client closes.
+/// The first `dropped` connections are dropped right after the Welcome.
+#[cfg(feature = "twitch-manage")]
+pub async fn serve_sessions(dropped: usize) -> (Url, mpsc::UnboundedReceiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (closed_tx, closed) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        for n in 0.. {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let closed_tx = closed_tx.clone();
+            tokio::spawn(async move {
+                let session_id = format!("session-{n}");
+                let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+                ws.send(Message::text(format!("{{\"metadata\": {{\"message_id\": \"96a3f3b5-5dec-4eed-908e-e11ee657416c\", \"message_type\": \"session_welcome\", \"message_timestamp\": \"2023-07-19T14:56:51.634234626Z\"}}, \"payload\": {{\"session\": {{\"id\": \"{session_id}\", \"status\": \"connected\", \"connected_at\": \"2023-07-19T14:56:51.616329898Z\", \"keepalive_timeout_seconds\": 10, \"reconnect_url\": null}}}}}}")))
+                    .await
+                    .unwrap();
+                if n >= dropped {
+                    while let Some(Ok(_)) = ws.next().await {}
+                }
+                let _ = closed_tx.send(session_id);
+            });
+        }
+    });
+
+    (Url::parse(&format!("ws://{addr}")).unwrap(), closed)
+}
@@ -0,0 +1,46 @@
+use asknothingx2_eventsub::twitch::{
+    error::LimitError,
+    request::CreateEventSubRequest,
+    router::{
+        limits::{MAX_CONNECTIONS, MAX_SESSION_SUBSCRIPTIONS},
+        WebSocketPool,
+    },
+    types::{BroadcasterCondition, Condition, SessionId, SubscriptionType, Transport},
+};
+use asknothingx2_util::oauth::{AccessToken, ClientId};
+use twitch_highway::TwitchAPI;
+
+fn request() -> CreateEventSubRequest<Condition> {
+    CreateEventSubRequest::new(
+        SubscriptionType::StreamOnline,
+        BroadcasterCondition::new("1337").into(),
+        Transport::websocket(SessionId::new("placeholder")),
+    )
+}
+
+#[test]
+fn spreads_over_sessions() {
+    let mut pool = WebSocketPool::new(TwitchAPI::new(
+        AccessToken::new("access_token".to_string()),
+        ClientId::new("client_id".to_string()),
+    ));
+
+    assert_eq!(pool.subscribe(request()), Ok(0));
+    for _ in 1..MAX_SESSION_SUBSCRIPTIONS {
+        pool.subscribe(request()).unwrap();
+    }
+    assert_eq!(pool.subscribe(request()), Ok(1));
+    for _ in 1..MAX_SESSION_SUBSCRIPTIONS * (MAX_CONNECTIONS - 1) {
+        pool.subscribe(request()).unwrap();
+    }
+
+    assert_eq!(
+        pool.session_sizes(),
+        vec![MAX_SESSION_SUBSCRIPTIONS; MAX_CONNECTIONS]
+    );
+    assert_eq!(
+        pool.subscribe(request()),
+        Err(LimitError::TooManyConnections(MAX_CONNECTIONS))
+    );
+    assert_eq!(pool.into_clients().len(), MAX_CONNECTIONS);
+}
@@ -0,0 +1,231 @@
+use asknothingx2_eventsub::twitch::{
+    error::{LimitError, RequestError, SubscribeError},
+    request::CreateEventSubRequest,
+    router::WebSocketClient,
+    types::{
+        BroadcasterCondition, BroadcasterModeratorCondition, Condition, SessionId,
+        SubscriptionType, Transport,
+    },
+    websocket_message::WebSocketMessage,
+};
+
+use super::serve_sessions;
+use crate::manage::{page, subscription, MockServer};
+
+fn request(kind: SubscriptionType) -> CreateEventSubRequest<Condition> {
+    request_for(kind, BroadcasterCondition::new("1337"))
+}
+
+fn request_for(
+    kind: SubscriptionType,
+    condition: impl Into<Condition>,
+) -> CreateEventSubRequest<Condition> {
+    CreateEventSubRequest::new(
+        kind,
+        condition.into(),
+        Transport::websocket(SessionId::new("placeholder")),
+    )
+}
+
+fn created(id: &str, kind: &str, total_cost: u64) -> (u16, String) {
+    (
+        202,
+        page(
+            &[subscription(id, kind, "enabled", "1337")],
+            total_cost,
+            None,
+        ),
+    )
+}
+
+fn session_id(message: WebSocketMessage) -> String {
+    let WebSocketMessage::Welcome(welcome) = message else {
+        panic!("expected a Welcome, got {message:?}");
+    };
+    welcome.payload.session.id.as_str().to_string()
+}
+
+#[tokio::test]
+async fn subscribes_on_welcome() {
+    let server = MockServer::start(vec![
+        created("1", "stream.online", 1),
+        created("2", "stream.offline", 2),
+    ])
+    .await;
+    let (url, _closed) = serve_sessions(0).await;
+
+    let mut client = WebSocketClient::new()
+        .url(url)
+        .auto_reconnect(false)
+        .subscriptions(
+            server.api(),
+            [
+                request(SubscriptionType::StreamOnline),
+                request(SubscriptionType::StreamOffline),
+            ],
+        );
+
+    assert_eq!(
+        session_id(client.next_message().await.unwrap()),
+        "session-0"
+    );
+    assert!(client.failed_subscriptions().is_empty());
+    assert_eq!(
+        server.requests(),
+        [
+            "POST /eventsub/subscriptions",
+            "POST /eventsub/subscriptions"
+        ]
+    );
+    let bodies = server.bodies();
+    assert!(bodies
+        .iter()
+        .all(|body| body.contains("\"session_id\":\"session-0\"")));
+    assert!(bodies
+        .iter()
+        .any(|body| body.contains("\"type\":\"stream.online\"")));
+    assert!(bodies
+        .iter()
+        .any(|body| body.contains("\"type\":\"stream.offline\"")));
+    assert!(client.cost_usage().unwrap().max_total_cost.is_some());
+}
+
+#[tokio::test]
+async fn reports_failed_subscriptions() {
+    let server = MockServer::start(vec![
+        created("1", "stream.online", 1),
+        (
+            409,
+            "{\"error\":\"Conflict\",\"status\":409,\"message\":\"subscription already exists\"}"
+                .to_string(),
+        ),
+    ])
+    .await;
+    let (url, _closed) = serve_sessions(0).await;
+
+    let mut client = WebSocketClient::new()
+        .url(url)
+        .auto_reconnect(false)
+        .subscriptions(
+            server.api(),
+            [
+                request(SubscriptionType::StreamOnline),
+                request(SubscriptionType::StreamOffline),
+            ],
+        );
+    client.next_message().await.unwrap();
+
+    // The requests are sent concurrently; the second one to arrive is rejected.
+    let failures = client.failed_subscriptions();
+    assert_eq!(failures.len(), 1);
+    assert!(server.bodies()[1].contains(&format!(
+        "\"type\":\"{}\"",
+        failures[0].request.kind.as_str()
+    )));
+    assert!(matches!(
+        &failures[0].error,
+        SubscribeError::Request(RequestError::Api { message, .. })
+            if message == "subscription already exists"
+    ));
+}
+
+#[tokio::test]
+async fn resubscribes_after_reconnect() {
+    let server = MockServer::start(vec![
+        created("1", "stream.online", 1),
+        created("2", "stream.online", 1),
+    ])
+    .await;
+    let (url, _closed) = serve_sessions(1).await;
+
+    let mut client = WebSocketClient::new()
+        .url(url)
+        .subscriptions(server.api(), [request(SubscriptionType::StreamOnline)]);
+
+    assert_eq!(
+        session_id(client.next_message().await.unwrap()),
+        "session-0"
+    );
+    // The first connection is dropped without a close frame.
+    assert_eq!(
+        session_id(client.next_message().await.unwrap()),
+        "session-1"
+    );
+
+    assert!(client.failed_subscriptions().is_empty());
+    let bodies = server.bodies();
+    assert_eq!(bodies.len(), 2);
+    assert!(bodies[0].contains("\"session_id\":\"session-0\""));
+    assert!(bodies[1].contains("\"session_id\":\"session-1\""));
+}
+
+#[tokio::test]
+async fn reports_undecodable_response() {
+    let server = MockServer::start(vec![(202, "{\"data\":\"unexpected\"}".to_string())]).await;
+    let (url, _closed) = serve_sessions(0).await;
+
+    let mut client = WebSocketClient::new()
+        .url(url)
+        .auto_reconnect(false)
+        .subscriptions(server.api(), [request(SubscriptionType::StreamOnline)]);
+    client.next_message().await.unwrap();
+
+    let failures = client.failed_subscriptions();
+    assert_eq!(failures.len(), 1);
+    assert!(matches!(
+        failures[0].error,
+        SubscribeError::Request(RequestError::Decode(_))
+    ));
+    assert_eq!(client.cost_usage().unwrap().max_total_cost, None);
+}
+
+#[tokio::test]
+async fn rejects_batch_over_remaining_cost() {
+    let server = MockServer::start(vec![created("1", "stream.online", 9998); 7]).await;
+    let (url, _closed) = serve_sessions(1).await;
+
+    let mut client = WebSocketClient::new().url(url).subscriptions(
+        server.api(),
+        [
+            request_for(
+                SubscriptionType::StreamOnline,
+                BroadcasterCondition::new("1"),
+            ),
+            request_for(
+                SubscriptionType::StreamOnline,
+                BroadcasterCondition::new("2"),
+            ),
+            request_for(
+                SubscriptionType::StreamOnline,
+                BroadcasterCondition::new("3"),
+            ),
+            request_for(
+                SubscriptionType::ChannelFollow,
+                BroadcasterModeratorCondition::new("3", "3"),
+            ),
+        ],
+    );
+
+    // The first session learns that 2 of 10000 are left.
+    client.next_message().await.unwrap();
+    assert!(client.failed_subscriptions().is_empty());
+    assert_eq!(server.requests().len(), 4);
+
+    client.next_message().await.unwrap();
+    let failures = client.failed_subscriptions();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(
+        failures[0].request.condition.broadcaster_user_id(),
+        Some("3")
+    );
+    assert_eq!(failures[0].request.kind, SubscriptionType::StreamOnline);
+    assert!(matches!(
+        failures[0].error,
+        SubscribeError::Limit(LimitError::CostExceeded {
+            total_cost: 9998,
+            max_total_cost: 10000,
+        })
+    ));
+    // The free `channel.follow` is still created.
+    assert_eq!(server.requests().len(), 7);
+}
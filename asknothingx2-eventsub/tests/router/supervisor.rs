@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use asknothingx2_eventsub::twitch::{
+    events::conduit_shard::ConduitShardDisabledEvent,
+    router::{ConduitShards, ShardSupervisor, WebSocketClient},
+    types::ConduitId,
+    websocket_message::WebSocketMessage,
+};
+use futures_util::StreamExt;
+use tokio::time;
+use url::Url;
+
+use super::serve_sessions;
+use crate::manage::MockServer;
+
+const CONDUIT_ID: &str = "bfcfc993-26b1-b876-44d9-afe75a379dac";
+
+fn conduit(shard_count: usize) -> (u16, String) {
+    (
+        200,
+        format!("{{\"data\":[{{\"id\":\"{CONDUIT_ID}\",\"shard_count\":{shard_count}}}]}}"),
+    )
+}
+
+fn attached(shard_id: &str) -> (u16, String) {
+    (
+        202,
+        format!("{{\"data\":[{{\"id\":\"{shard_id}\",\"status\":\"enabled\",\"transport\":{{\"method\":\"websocket\",\"session_id\":\"session\",\"connected_at\":\"2020-11-10T14:32:18.730260295Z\"}}}}],\"errors\":[]}}"),
+    )
+}
+
+fn rejected(shard_id: &str) -> (u16, String) {
+    (
+        202,
+        format!("{{\"data\":[],\"errors\":[{{\"id\":\"{shard_id}\",\"message\":\"The websocket session does not exist or has already disconnected.\",\"code\":\"websocket_session_not_found\"}}]}}"),
+    )
+}
+
+async fn start(server: &MockServer, url: Url, shard_count: usize) -> ConduitShards {
+    ShardSupervisor::new(server.api(), ConduitId::new(CONDUIT_ID), shard_count)
+        .client(move || {
+            WebSocketClient::new()
+                .url(url.clone())
+                .auto_reconnect(false)
+        })
+        .start()
+        .await
+        .unwrap()
+}
+
+/// The next message, as `(shard_id, session_id)` of a Welcome.
+async fn next_welcome(shards: &mut ConduitShards) -> (String, String) {
+    let (shard_id, message) = time::timeout(Duration::from_secs(5), shards.next())
+        .await
+        .unwrap()
+        .unwrap();
+    let WebSocketMessage::Welcome(welcome) = message else {
+        panic!("expected a Welcome, got {message:?}");
+    };
+    (
+        shard_id.as_str().to_string(),
+        welcome.payload.session.id.as_str().to_string(),
+    )
+}
+
+async fn wait_for_requests(server: &MockServer, count: usize) {
+    time::timeout(Duration::from_secs(5), async {
+        while server.requests().len() < count {
+            time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+}
+
+fn disabled(conduit_id: &str, shard_id: &str) -> ConduitShardDisabledEvent {
+    serde_json::from_str(&format!("{{\"conduit_id\": \"{conduit_id}\", \"shard_id\": \"{shard_id}\", \"status\": \"websocket_disconnected\", \"transport\": {{\"method\": \"websocket\", \"session_id\": \"ad1c9fc3-0d99-4eb7-8a04-8608e8ff9ec9\", \"connected_at\": \"2020-11-10T14:32:18.730260295Z\", \"disconnected_at\": \"2020-11-11T14:32:18.730260295Z\"}}}}")).unwrap()
+}
+
+#[tokio::test]
+async fn attaches_on_welcome() {
+    let server = MockServer::start(vec![conduit(1), attached("0")]).await;
+    let (url, _closed) = serve_sessions(0).await;
+
+    let mut shards = start(&server, url, 1).await;
+
+    assert_eq!(
+        next_welcome(&mut shards).await,
+        ("0".to_string(), "session-0".to_string())
+    );
+    assert_eq!(
+        server.requests(),
+        [
+            "PATCH /eventsub/conduits",
+            "PATCH /eventsub/conduits/shards"
+        ]
+    );
+    let bodies = server.bodies();
+    assert!(bodies[0].contains("\"shard_count\":1"));
+    assert!(bodies[1].contains("\"id\":\"0\""));
+    assert!(bodies[1].contains("\"session_id\":\"session-0\""));
+}
+
+#[tokio::test]
+async fn restarts_shard_that_was_not_attached() {
+    let server = MockServer::start(vec![conduit(1), rejected("0"), attached("0")]).await;
+    let (url, mut closed) = serve_sessions(0).await;
+
+    let mut shards = start(&server, url, 1).await;
+
+    // The rejected session is closed instead of handed out.
+    assert_eq!(
+        next_welcome(&mut shards).await,
+        ("0".to_string(), "session-1".to_string())
+    );
+    assert_eq!(closed.recv().await.unwrap(), "session-0");
+    assert!(server.bodies()[2].contains("\"session_id\":\"session-1\""));
+}
+
+#[tokio::test]
+async fn reattaches_disabled_shard() {
+    let server = MockServer::start(vec![conduit(1), attached("0"), attached("0")]).await;
+    let (url, _closed) = serve_sessions(0).await;
+
+    let mut shards = start(&server, url, 1).await;
+    next_welcome(&mut shards).await;
+
+    shards.shard_disabled(&disabled("other-conduit", "0"));
+    shards.shard_disabled(&disabled(CONDUIT_ID, "0"));
+    wait_for_requests(&server, 3).await;
+
+    assert_eq!(
+        server.requests(),
+        [
+            "PATCH /eventsub/conduits",
+            "PATCH /eventsub/conduits/shards",
+            "PATCH /eventsub/conduits/shards",
+        ]
+    );
+    assert!(server.bodies()[2].contains("\"session_id\":\"session-0\""));
+}
+
+#[tokio::test]
+async fn scales_up_and_down() {
+    let server = MockServer::start(vec![
+        conduit(1),
+        attached("0"),
+        conduit(2),
+        attached("1"),
+        conduit(1),
+    ])
+    .await;
+    let (url, mut closed) = serve_sessions(0).await;
+
+    let mut shards = start(&server, url, 1).await;
+    next_welcome(&mut shards).await;
+
+    shards.scale(2);
+    assert_eq!(
+        next_welcome(&mut shards).await,
+        ("1".to_string(), "session-1".to_string())
+    );
+    assert!(server.bodies()[2].contains("\"shard_count\":2"));
+    assert!(server.bodies()[3].contains("\"id\":\"1\""));
+
+    shards.scale(1);
+    assert_eq!(
+        time::timeout(Duration::from_secs(5), closed.recv())
+            .await
+            .unwrap()
+            .unwrap(),
+        "session-1"
+    );
+    assert_eq!(server.requests().len(), 5);
+    assert!(server.bodies()[4].contains("\"shard_count\":1"));
+}
@@ -1,11 +1,12 @@
-use asknothingx2_eventsub::twitch::subscription_types::channel_subscriptions::{
-    ChannelFollowPayload, ChannelFollowRequest,
+use asknothingx2_eventsub::twitch::{
+    subscription_types::channel_subscriptions::{ChannelFollowPayload, ChannelFollowRequest},
+    types::{BroadcasterUserId, ModeratorUserId},
 };
 
 fn_expected_request!(
     request: ChannelFollowRequest::webhook(
-        "1337",
-        "1337",
+        BroadcasterUserId::new("1337"),
+        ModeratorUserId::new("1337"),
         "https://example.com/webhooks/callback",
         Some("s3cRe7"),
     ),
@@ -43,12 +44,8 @@ fn_expected_payload!(
         disconnected_at: None
     },
     block condition: {
-        broadcaster_user_id: Some("1337".to_string()),
-        moderator_user_id: Some("1337".to_string()),
-        broadcaster_id: None,
-        user_id: None,
-        reward_id: None,
-        client_id: None
+        broadcaster_user_id: "1337",
+        moderator_user_id: "1337"
     },
     block event: {
         user_id: "1234",
@@ -1,13 +1,16 @@
-use asknothingx2_eventsub::twitch::subscription_types::channel_subscriptions::{
-    ChannelRaidPayload, ChannelRaidRequest,
+use asknothingx2_eventsub::twitch::{
+    subscription_types::channel_subscriptions::{ChannelRaidPayload, ChannelRaidRequest},
+    types::{BroadcasterUserId, Transport},
 };
 
 fn_expected_request!(
-    request: ChannelRaidRequest::webhook(
-        "https://example.com/webhooks/callback",
-        Some("s3cRe7")
+    request: ChannelRaidRequest::to_broadcaster(
+        BroadcasterUserId::new("1337"),
+        Transport::webhook(
+            "https://example.com/webhooks/callback",
+            Some("s3cRe7".to_string())
         )
-        .set_to_broadcacter_user_id("1337"),
+    ),
     body: {
         contain: [
             "\"type\":\"channel.raid\"",
@@ -0,0 +1,134 @@
+use asknothingx2_eventsub::twitch::{
+    request::CreateEventSubRequest,
+    subscription_types::channel_subscriptions::{
+        ChannelPointsCustomRewardAddRequest, ChannelRaidRequest,
+    },
+    types::{
+        BroadcasterCondition, BroadcasterModeratorCondition, BroadcasterUserId,
+        ChannelRaidCondition, RewardCondition, Subscription, SubscriptionType, Transport,
+    },
+    Condition,
+};
+
+fn subscription(kind: &str, version: &str, condition: &str) -> Subscription {
+    serde_json::from_str(&format!(
+        "{{\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"enabled\", \"type\": \"{kind}\", \"version\": \"{version}\", \"cost\": 1, \"condition\": {condition}, \"transport\": {{\"method\": \"webhook\", \"callback\": \"https://example.com/webhooks/callback\"}}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}}"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn picks_variant_by_type() {
+    let follow = subscription(
+        "channel.follow",
+        "2",
+        "{\"broadcaster_user_id\": \"1337\", \"moderator_user_id\": \"9001\"}",
+    );
+    assert_eq!(
+        follow.condition,
+        Condition::BroadcasterModerator(BroadcasterModeratorCondition::new("1337", "9001"))
+    );
+    assert_eq!(follow.condition.broadcaster_user_id(), Some("1337"));
+
+    let online = subscription("stream.online", "1", "{\"broadcaster_user_id\": \"1337\"}");
+    assert_eq!(
+        online.condition,
+        Condition::Broadcaster(BroadcasterCondition::new("1337"))
+    );
+
+    let reward = subscription(
+        "channel.channel_points_custom_reward.add",
+        "1",
+        "{\"broadcaster_user_id\": \"1337\"}",
+    );
+    assert_eq!(
+        reward.condition,
+        Condition::Reward(RewardCondition::new("1337"))
+    );
+
+    let raid = subscription(
+        "channel.raid",
+        "1",
+        "{\"from_broadcaster_user_id\": \"1234\", \"to_broadcaster_user_id\": \"\"}",
+    );
+    assert_eq!(
+        raid.condition,
+        Condition::ChannelRaid(ChannelRaidCondition::new().from_broadcaster_user_id("1234"))
+    );
+}
+
+#[test]
+fn empty_optional_field_is_none() {
+    let reward = subscription(
+        "channel.channel_points_custom_reward.add",
+        "1",
+        "{\"broadcaster_user_id\": \"1337\", \"reward_id\": \"\"}",
+    );
+    assert_eq!(
+        reward.condition,
+        Condition::Reward(RewardCondition::new("1337"))
+    );
+}
+
+#[test]
+fn rejects_missing_required_field() {
+    let err = serde_json::from_str::<Subscription<BroadcasterModeratorCondition>>(
+        "{\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"enabled\", \"type\": \"channel.follow\", \"version\": \"2\", \"cost\": 1, \"condition\": {\"broadcaster_user_id\": \"1337\"}, \"transport\": {\"method\": \"webhook\", \"callback\": \"https://example.com/webhooks/callback\"}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}",
+    )
+    .unwrap_err();
+
+    assert!(err.to_string().contains("moderator_user_id"));
+}
+
+#[test]
+fn falls_back_to_unknown() {
+    for condition in [
+        serde_json::json!({"broadcaster_user_id": 1337, "moderator_user_id": "9001"}),
+        serde_json::json!({"broadcaster_user_id": "1337"}),
+        serde_json::json!({"broadcaster_user_id": "1337", "moderator_user_id": null}),
+        serde_json::json!({"broadcaster_user_id": "1337", "moderator_user_id": "9001", "user_id": "1"}),
+    ] {
+        let follow = subscription("channel.follow", "2", &condition.to_string());
+
+        assert_eq!(follow.kind, SubscriptionType::ChannelFollow);
+        assert_eq!(follow.condition, Condition::Unknown(condition));
+    }
+
+    // A condition of another family doesn't pass for a broadcaster condition.
+    let condition = serde_json::json!({"broadcaster_user_id": "1337", "moderator_user_id": "9001"});
+    let online = subscription("stream.online", "1", &condition.to_string());
+    assert_eq!(online.condition, Condition::Unknown(condition));
+}
+
+#[test]
+fn into_generic_request() {
+    let request: CreateEventSubRequest<Condition> = ChannelRaidRequest::to_broadcaster(
+        BroadcasterUserId::new("1337"),
+        Transport::webhook("https://example.com/webhooks/callback", None),
+    )
+    .into();
+
+    assert_eq!(request.kind, SubscriptionType::ChannelRaid);
+    assert_eq!(request.condition.broadcaster_user_id(), Some("1337"));
+
+    let request = ChannelRaidRequest::from_broadcaster(
+        BroadcasterUserId::new("1337"),
+        Transport::webhook("https://example.com/webhooks/callback", None),
+    );
+    assert_eq!(
+        serde_json::to_value(&request.0.condition).unwrap(),
+        serde_json::json!({"from_broadcaster_user_id": "1337"})
+    );
+
+    let request: CreateEventSubRequest<Condition> = ChannelPointsCustomRewardAddRequest::webhook(
+        BroadcasterUserId::new("1337"),
+        "https://example.com/webhooks/callback",
+        None,
+    )
+    .into();
+
+    assert_eq!(
+        serde_json::to_value(&request.condition).unwrap(),
+        serde_json::json!({"broadcaster_user_id": "1337"})
+    );
+}
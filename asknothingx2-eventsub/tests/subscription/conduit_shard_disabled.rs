@@ -1,10 +1,13 @@
-use asknothingx2_eventsub::twitch::subscription_types::channel_subscriptions::{
-    ConduitShardDisabledPayload, ConduitShardDisabledRequest,
+use asknothingx2_eventsub::twitch::{
+    subscription_types::channel_subscriptions::{
+        ConduitShardDisabledPayload, ConduitShardDisabledRequest,
+    },
+    types::ClientId,
 };
 
 fn_expected_request!(
     request: ConduitShardDisabledRequest::webhook(
-        "uo6dggojyb8d6soh92zknwmi5ej1q2",
+        ClientId::new("uo6dggojyb8d6soh92zknwmi5ej1q2"),
         "https://example.com/webhooks/callback",
         Some("s3cRe7"),
     ),
@@ -64,7 +67,7 @@ fn_expected_payload!(
         "\"client_id\":\"uo6dggojyb8d6soh92zknwmi5ej1q2\"",
         "\"method\":\"webhook\"",
         "\"callback\":\"https://example.com/webhooks/callback\"",
-        "\"created_at\":\"2023-04-11T10:11:12.123Z\"",
+        "\"created_at\":\"2023-04-11T10:11:12.123000000Z\"",
         "\"conduit_id\":\"bfcfc993-26b1-b876-44d9-afe75a379dac\"",
         "\"shard_id\":\"4\"",
         "\"status\":\"websocket_disconnected\"",
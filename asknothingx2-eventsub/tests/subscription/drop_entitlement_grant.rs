@@ -1,15 +1,18 @@
-use asknothingx2_eventsub::twitch::subscription_types::channel_subscriptions::drop_entitlement_grant::{
-    DropEntitlementGrantPayloadNotification, DropEntitlementGrantRequest,
+use asknothingx2_eventsub::twitch::{
+    subscription_types::channel_subscriptions::drop_entitlement_grant::{
+        DropEntitlementGrantPayloadNotification, DropEntitlementGrantRequest,
+    },
+    types::{CampaignId, CategoryId, OrganizationId},
 };
 
 fn_expected_request!(
     request: DropEntitlementGrantRequest::webhook(
-        "9001",
+        OrganizationId::new("9001"),
         "https://example.com/webhooks/callback",
         Some("s3cRe7"),
     )
-    .set_category_id("9002")
-    .set_campaign_id("9003"),
+    .set_category_id(CategoryId::new("9002"))
+    .set_campaign_id(CampaignId::new("9003")),
     body: {
         contain: [
             "\"type\":\"drop.entitlement.grant\"",
@@ -73,7 +76,7 @@ fn_expected_payload!(
         "\"user_login\":\"cool_user\"",
         "\"entitlement_id\":\"fb78259e-fb81-4d1b-8333-34a06ffc24c0\"",
         "\"benefit_id\":\"74c52265-e214-48a6-91b9-23b6014e8041\"",
-        "\"created_at\":\"2019-01-28T04:17:53.325Z\"",
+        "\"created_at\":\"2019-01-28T04:17:53.325000000Z\"",
         "\"id\":\"bf7c8577-e3e3-4881-a78a-e9446641d45c\",",
         "\"organization_id\":\"9001\"",
         "\"category_id\":\"9002\"",
@@ -84,6 +87,6 @@ fn_expected_payload!(
         "\"user_login\":\"cooler_user\"",
         "\"entitlement_id\":\"fb78259e-fb81-4d1b-8333-34a06ffc24c0\"",
         "\"benefit_id\":\"74c52265-e214-48a6-91b9-23b6014e8041\"",
-        "\"created_at\":\"2019-01-28T04:17:53.325Z\""
+        "\"created_at\":\"2019-01-28T04:17:53.325000000Z\""
     ]
 );
@@ -1,10 +1,13 @@
-use asknothingx2_eventsub::twitch::subscription_types::channel_subscriptions::{
-    ExtensionBitsTransactionPayload, ExtensionBitsTransactionRequest,
+use asknothingx2_eventsub::twitch::{
+    subscription_types::channel_subscriptions::{
+        ExtensionBitsTransactionPayload, ExtensionBitsTransactionRequest,
+    },
+    types::ExtensionClientId,
 };
 
 fn_expected_request!(
     request: ExtensionBitsTransactionRequest::webhook(
-        "deadbeef",
+        ExtensionClientId::new("deadbeef"),
         "https://example.com/webhooks/callback",
         Some("s3cRe7"),
     ),
@@ -1,5 +1,7 @@
 mod channel_follow;
 mod channel_raid;
+mod condition;
 mod conduit_shard_disabled;
 mod drop_entitlement_grant;
 mod extension_bits_transaction;
+mod unknown;
@@ -0,0 +1,81 @@
+use asknothingx2_eventsub::twitch::{
+    request::CreateEventSubRequest,
+    response::EventSubscriptionsResponse,
+    types::{Status, SubscriptionType},
+    Condition,
+};
+use twitch_highway::request::IntoRequestBody;
+
+fn unknown(version: &str) -> SubscriptionType {
+    SubscriptionType::Unknown {
+        kind: "channel.brand_new.event".to_string(),
+        version: version.to_string(),
+    }
+}
+
+const PAGE: &str = "{\"data\": [{\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"enabled\", \"type\": \"channel.follow\", \"version\": \"2\", \"cost\": 0, \"condition\": {\"broadcaster_user_id\": \"1337\", \"moderator_user_id\": \"1337\"}, \"transport\": {\"method\": \"webhook\", \"callback\": \"https://example.com/webhooks/callback\"}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}, {\"id\": \"26b1c993-bfcf-44d9-b876-379dacafe75a\", \"status\": \"brand_new_status\", \"type\": \"channel.brand_new.event\", \"version\": \"3\", \"cost\": 1, \"condition\": {\"broadcaster_user_id\": \"1337\", \"widget_id\": \"42\"}, \"transport\": {\"method\": \"webhook\", \"callback\": \"https://example.com/webhooks/callback\"}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}], \"total\": 2, \"total_cost\": 1, \"max_total_cost\": 10000, \"pagination\": {}}";
+
+#[test]
+fn page_with_unknown_type_and_status() {
+    let page: EventSubscriptionsResponse = serde_json::from_str(PAGE).unwrap();
+
+    assert_eq!(page.data.len(), 2);
+    assert_eq!(page.data[0].kind, SubscriptionType::ChannelFollow);
+    assert_eq!(page.data[0].status, Status::Enabled);
+
+    let unknown = &page.data[1];
+    assert_eq!(unknown.kind, self::unknown("3"));
+    assert_eq!(unknown.kind.as_str(), "channel.brand_new.event");
+    assert_eq!(unknown.kind.version(), "3");
+    assert_eq!(unknown.version, "3");
+    assert_eq!(
+        unknown.status,
+        Status::Unknown("brand_new_status".to_string())
+    );
+    assert_eq!(unknown.status, "brand_new_status");
+    assert_eq!(
+        unknown.condition,
+        Condition::Unknown(serde_json::json!({"broadcaster_user_id": "1337", "widget_id": "42"}))
+    );
+    assert_eq!(unknown.condition.broadcaster_user_id(), Some("1337"));
+}
+
+#[test]
+fn round_trip() {
+    let page: EventSubscriptionsResponse = serde_json::from_str(PAGE).unwrap();
+    let value = serde_json::to_value(&page).unwrap();
+
+    assert_eq!(value["data"][1]["type"], "channel.brand_new.event");
+    assert_eq!(value["data"][1]["status"], "brand_new_status");
+    assert_eq!(value["data"][1]["condition"]["widget_id"], "42");
+
+    let again: EventSubscriptionsResponse = serde_json::from_value(value).unwrap();
+    assert_eq!(again.data[1].kind, page.data[1].kind);
+    assert_eq!(again.data[1].status, page.data[1].status);
+}
+
+#[test]
+fn from_str_stays_strict() {
+    assert!("channel.brand_new.event"
+        .parse::<SubscriptionType>()
+        .is_err());
+    assert_eq!(
+        serde_json::from_str::<SubscriptionType>("\"channel.brand_new.event\"").unwrap(),
+        unknown("1")
+    );
+}
+
+#[test]
+fn recreate_keeps_version() {
+    let page: EventSubscriptionsResponse = serde_json::from_str(PAGE).unwrap();
+    let existing = page.data.into_iter().nth(1).unwrap();
+
+    let request = CreateEventSubRequest::new(existing.kind, existing.condition, existing.transport);
+    assert_eq!(request.version, "3");
+    let body = request.as_body().unwrap();
+    assert!(body.contains("\"type\":\"channel.brand_new.event\""));
+    assert!(body.contains("\"version\":\"3\""));
+
+    let again: CreateEventSubRequest<Condition> = serde_json::from_str(&body).unwrap();
+    assert_eq!(again.kind, unknown("3"));
+}
@@ -0,0 +1,114 @@
+use asknothingx2_eventsub::twitch::{
+    error::{HeaderVerificationError, WebhookError},
+    types::{secret::Secret, SubscriptionType},
+    webhook::{WebhookEvent, WebhookHandler},
+};
+use bytes::Bytes;
+use chrono::{SecondsFormat, Utc};
+use hmac::{Hmac, Mac};
+use http::{Request, StatusCode};
+use sha2::Sha256;
+
+const SUBSCRIPTION: &str = "{\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"webhook_callback_verification_pending\", \"type\": \"channel.follow\", \"version\": \"2\", \"cost\": 1, \"condition\": {\"broadcaster_user_id\": \"12826\", \"moderator_user_id\": \"12826\"}, \"transport\": {\"method\": \"webhook\", \"callback\": \"https://example.com/webhooks/callback\"}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}";
+
+fn request(secret: &Secret, message_type: &str, body: String) -> Request<Bytes> {
+    let message_id = "e76c6bd4-55c9-4987-8304-da1588d8988b";
+    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Nanos, true);
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_str().as_bytes()).unwrap();
+    mac.update(format!("{message_id}{timestamp}{body}").as_bytes());
+    let signature = hex::encode(mac.finalize().into_bytes());
+
+    Request::post("/webhooks/callback")
+        .header("Twitch-Eventsub-Message-Id", message_id)
+        .header("Twitch-Eventsub-Message-Timestamp", timestamp)
+        .header(
+            "Twitch-Eventsub-Message-Signature",
+            format!("sha256={signature}"),
+        )
+        .header("Twitch-Eventsub-Message-Type", message_type)
+        .body(Bytes::from(body))
+        .unwrap()
+}
+
+#[test]
+fn challenge_is_echoed() {
+    let secret = Secret::new();
+    let body = format!(
+        "{{\"challenge\": \"pogchamp-kappa-360noscope-vohiyo\", \"subscription\": {SUBSCRIPTION}}}"
+    );
+    let request = request(&secret, "webhook_callback_verification", body);
+
+    let handled = WebhookHandler::new(secret).handle(&request);
+
+    assert_eq!(handled.response.status(), StatusCode::OK);
+    assert_eq!(
+        handled.response.body(),
+        &Bytes::from_static(b"pogchamp-kappa-360noscope-vohiyo")
+    );
+    assert!(matches!(
+        handled.event,
+        Ok(WebhookEvent::Verification { .. })
+    ));
+}
+
+#[test]
+fn notification_is_decoded() {
+    let secret = Secret::new();
+    let body = format!("{{\"subscription\": {SUBSCRIPTION}, \"event\": {{\"user_id\": \"1337\", \"user_login\": \"awesome_user\"}}}}");
+    let request = request(&secret, "notification", body);
+
+    let handled = WebhookHandler::new(secret).handle(&request);
+
+    assert_eq!(handled.response.status(), StatusCode::NO_CONTENT);
+    let Ok(WebhookEvent::Notification(notification)) = handled.event else {
+        panic!("expected notification");
+    };
+    assert_eq!(
+        notification.subscription.kind,
+        SubscriptionType::ChannelFollow
+    );
+    assert_eq!(notification.event["user_login"], "awesome_user");
+}
+
+#[test]
+fn revocation_is_delivered() {
+    let secret = Secret::new();
+    let body = format!("{{\"subscription\": {SUBSCRIPTION}}}");
+    let request = request(&secret, "revocation", body);
+
+    let handled = WebhookHandler::new(secret).handle(&request);
+
+    assert_eq!(handled.response.status(), StatusCode::NO_CONTENT);
+    assert!(matches!(handled.event, Ok(WebhookEvent::Revocation(_))));
+}
+
+#[test]
+fn duplicate_is_acknowledged() {
+    let secret = Secret::new();
+    let body = format!("{{\"subscription\": {SUBSCRIPTION}}}");
+    let request = request(&secret, "revocation", body);
+    let handler = WebhookHandler::new(secret);
+
+    assert!(handler.handle(&request).event.is_ok());
+
+    let handled = handler.handle(&request);
+    assert_eq!(handled.response.status(), StatusCode::NO_CONTENT);
+    assert!(matches!(
+        handled.event,
+        Err(WebhookError::Verification(
+            HeaderVerificationError::DuplicateMessage(_)
+        ))
+    ));
+}
+
+#[test]
+fn wrong_secret_is_forbidden() {
+    let body = format!("{{\"subscription\": {SUBSCRIPTION}}}");
+    let request = request(&Secret::new(), "revocation", body);
+
+    let handled = WebhookHandler::new(Secret::new()).handle(&request);
+
+    assert_eq!(handled.response.status(), StatusCode::FORBIDDEN);
+    assert!(matches!(handled.event, Err(WebhookError::Verification(_))));
+}
@@ -0,0 +1,8 @@
+mod handler;
+mod replay;
+mod secret;
+#[cfg(all(feature = "twitch-webhook-server", feature = "twitch-webhook-test"))]
+mod sender;
+#[cfg(feature = "twitch-webhook-server")]
+mod server;
+mod signing;
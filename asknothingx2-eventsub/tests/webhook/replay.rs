@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use asknothingx2_eventsub::twitch::{error::HeaderVerificationError, webhook::ReplayGuard};
+use asknothingx2_util::api::HeaderMap;
+use chrono::{DateTime, SecondsFormat, TimeDelta, Utc};
+
+fn headers(message_id: &str, timestamp: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert("twitch-eventsub-message-id", message_id.parse().unwrap());
+    headers.insert(
+        "twitch-eventsub-message-timestamp",
+        timestamp.parse().unwrap(),
+    );
+    headers.insert(
+        "twitch-eventsub-message-signature",
+        "sha256=00".parse().unwrap(),
+    );
+    headers
+}
+
+fn sent_at() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2023-07-19T10:11:12.634234626Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+#[test]
+fn expired_message() {
+    let guard = ReplayGuard::new();
+    let headers = headers("1", "2023-07-19T10:11:12.634234626Z");
+
+    assert!(guard
+        .verify_at(&headers, sent_at() + TimeDelta::minutes(9))
+        .is_ok());
+    assert!(matches!(
+        guard.verify_at(&headers, sent_at() + TimeDelta::minutes(11)),
+        Err(HeaderVerificationError::MessageExpired { .. })
+    ));
+}
+
+#[test]
+fn future_timestamp() {
+    let guard = ReplayGuard::new().clock_skew(Duration::from_secs(5));
+
+    assert!(guard
+        .verify_at(
+            &headers("1", "2023-07-19T10:11:12.634234626Z"),
+            sent_at() - TimeDelta::seconds(4)
+        )
+        .is_ok());
+    assert!(matches!(
+        guard.verify_at(
+            &headers("2", "2023-07-19T10:11:12.634234626Z"),
+            sent_at() - TimeDelta::seconds(6)
+        ),
+        Err(HeaderVerificationError::TimestampInFuture(_))
+    ));
+}
+
+#[test]
+fn duplicate_message() {
+    let guard = ReplayGuard::new();
+    let headers = headers("1", &Utc::now().to_rfc3339_opts(SecondsFormat::Nanos, true));
+
+    assert!(guard.verify(&headers).is_ok());
+    assert!(matches!(
+        guard.verify(&headers),
+        Err(HeaderVerificationError::DuplicateMessage(id)) if id == "1"
+    ));
+}
+
+#[test]
+fn invalid_timestamp() {
+    assert!(matches!(
+        ReplayGuard::new().verify(&headers("1", "yesterday")),
+        Err(HeaderVerificationError::InvalidTimestamp(_))
+    ));
+}
+
+#[test]
+fn unbounded_max_age() {
+    let guard = ReplayGuard::new()
+        .max_age(Duration::MAX)
+        .clock_skew(Duration::MAX);
+
+    for timestamp in [
+        "2023-07-19T10:11:12.634234626Z",
+        "9999-12-31T23:59:59.999999999Z",
+        "0001-01-01T00:00:00Z",
+    ] {
+        assert!(guard
+            .verify_at(&headers(timestamp, timestamp), sent_at())
+            .is_ok());
+        assert!(matches!(
+            guard.verify_at(&headers(timestamp, timestamp), sent_at()),
+            Err(HeaderVerificationError::DuplicateMessage(_))
+        ));
+    }
+}
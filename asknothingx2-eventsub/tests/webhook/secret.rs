@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use asknothingx2_eventsub::twitch::{
+    error::{HeaderVerificationError, SecretError},
+    types::secret::{RotatingSecret, Secret},
+};
+use asknothingx2_util::api::HeaderMap;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+fn signed(secret: &str, body: &str) -> HeaderMap {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(format!("12019-11-16T10:11:12.634234626Z{body}").as_bytes());
+    let signature = hex::encode(mac.finalize().into_bytes());
+
+    let mut headers = HeaderMap::new();
+    headers.insert("twitch-eventsub-message-id", "1".parse().unwrap());
+    headers.insert(
+        "twitch-eventsub-message-timestamp",
+        "2019-11-16T10:11:12.634234626Z".parse().unwrap(),
+    );
+    headers.insert(
+        "twitch-eventsub-message-signature",
+        format!("sha256={signature}").parse().unwrap(),
+    );
+    headers
+}
+
+#[test]
+fn validates_length_and_ascii() {
+    assert!("s3cre7w0rd".parse::<Secret>().is_ok());
+    assert!(matches!(
+        "short".parse::<Secret>(),
+        Err(SecretError::InvalidLength(5))
+    ));
+    assert!(matches!(
+        Secret::from_string("x".repeat(101)),
+        Err(SecretError::InvalidLength(101))
+    ));
+    assert!(matches!(
+        Secret::from_string("sécret-with-accent"),
+        Err(SecretError::NonAscii)
+    ));
+    assert_eq!(Secret::new().as_str().len(), 64);
+}
+
+#[test]
+fn loads_from_file() {
+    let path = std::env::temp_dir().join("asknothingx2-eventsub-secret");
+    std::fs::write(&path, "s3cre7w0rd\n").unwrap();
+
+    let secret = Secret::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(secret.as_str(), "s3cre7w0rd");
+    assert!(secret
+        .verify_twitch_signature(&signed("s3cre7w0rd", "{}"), b"{}")
+        .is_ok());
+}
+
+#[test]
+fn missing_env_var() {
+    assert!(matches!(
+        Secret::from_env("ASKNOTHINGX2_EVENTSUB_MISSING_SECRET"),
+        Err(SecretError::Env(_))
+    ));
+}
+
+#[test]
+fn rotation_accepts_both_secrets() {
+    let mut secret = RotatingSecret::new("old-s3cre7w0rd".parse().unwrap());
+    secret.rotate("new-s3cre7w0rd".parse().unwrap(), Duration::from_secs(60));
+
+    assert!(secret
+        .verify_twitch_signature(&signed("new-s3cre7w0rd", "{}"), b"{}")
+        .is_ok());
+    assert!(secret
+        .verify_twitch_signature(&signed("old-s3cre7w0rd", "{}"), b"{}")
+        .is_ok());
+
+    secret.rotate("newer-s3cre7w0rd".parse().unwrap(), Duration::ZERO);
+    assert!(matches!(
+        secret.verify_twitch_signature(&signed("new-s3cre7w0rd", "{}"), b"{}"),
+        Err(HeaderVerificationError::SignatureVerification(_))
+    ));
+}
+
+#[test]
+fn rotation_window_saturates() {
+    let mut secret = RotatingSecret::new("old-s3cre7w0rd".parse().unwrap());
+    secret.rotate("new-s3cre7w0rd".parse().unwrap(), Duration::MAX);
+
+    assert!(secret
+        .verify_twitch_signature(&signed("old-s3cre7w0rd", "{}"), b"{}")
+        .is_ok());
+}
@@ -0,0 +1,69 @@
+use asknothingx2_eventsub::twitch::{
+    types::{secret::Secret, Subscription},
+    webhook::{FakeSender, WebhookEvent, WebhookHandler, WebhookServer},
+};
+use futures_util::StreamExt;
+use http::StatusCode;
+use serde_json::json;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+#[tokio::test]
+async fn delivers_to_local_server() {
+    let secret: Secret = "s3cre7w0rd".parse().unwrap();
+    let mut events = WebhookServer::new(WebhookHandler::new(secret.clone()))
+        .bind("127.0.0.1:0".parse().unwrap())
+        .path("/eventsub")
+        .start()
+        .await
+        .unwrap();
+
+    let url = format!("http://127.0.0.1:{}/eventsub", events.local_addr().port());
+    let sender = FakeSender::new(url.parse().unwrap(), secret);
+    let subscription: Subscription = serde_json::from_str("{\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"enabled\", \"type\": \"stream.online\", \"version\": \"1\", \"cost\": 0, \"condition\": {\"broadcaster_user_id\": \"1337\"}, \"transport\": {\"method\": \"webhook\", \"callback\": \"https://example.com/webhooks/callback\"}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}").unwrap();
+
+    let response = sender
+        .notification(&subscription, &json!({"id": "9001", "type": "live"}))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let Some(WebhookEvent::Notification(notification)) = events.next().await else {
+        panic!("expected notification");
+    };
+    assert_eq!(notification.event["type"], "live");
+
+    let response = sender.revocation(&subscription).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn host_header_keeps_port() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let head = tokio::spawn(async move {
+        let (mut tcp, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0; 8192];
+        let n = tcp.read(&mut buf).await.unwrap();
+        tcp.write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        String::from_utf8_lossy(&buf[..n]).to_lowercase()
+    });
+
+    let url = format!("http://127.0.0.1:{port}/eventsub");
+    let sender = FakeSender::new(url.parse().unwrap(), "s3cre7w0rd".parse().unwrap());
+    let response = sender
+        .send(http::Request::new(bytes::Bytes::from_static(b"{}")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let head = head.await.unwrap();
+    assert!(
+        head.contains(&format!("\r\nhost: 127.0.0.1:{port}\r\n")),
+        "{head}"
+    );
+}
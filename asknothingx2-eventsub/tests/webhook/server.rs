@@ -0,0 +1,223 @@
+use std::{sync::Arc, time::Duration};
+
+use asknothingx2_eventsub::twitch::{
+    types::secret::Secret,
+    webhook::{WebhookEvent, WebhookEvents, WebhookHandler, WebhookServer},
+};
+use chrono::{SecondsFormat, Utc};
+use futures_util::StreamExt;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    time,
+};
+use tokio_rustls::{
+    rustls::{
+        crypto::ring,
+        pki_types::{pem::PemObject, CertificateDer, ServerName},
+        ClientConfig, RootCertStore,
+    },
+    TlsConnector,
+};
+
+const CERT: &[u8] = include_bytes!("certs/localhost.crt");
+const KEY: &[u8] = include_bytes!("certs/localhost.key");
+const SECRET: &str = "s3cre7w0rd";
+
+async fn post(port: u16, path: &str, message_type: &str, body: &str) -> String {
+    send(
+        port,
+        SECRET,
+        "e76c6bd4-55c9-4987-8304-da1588d8988b",
+        path,
+        message_type,
+        body,
+    )
+    .await
+}
+
+/// Posts `body` signed with `secret`.
+async fn send(
+    port: u16,
+    secret: &str,
+    message_id: &str,
+    path: &str,
+    message_type: &str,
+    body: &str,
+) -> String {
+    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Nanos, true);
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(format!("{message_id}{timestamp}{body}").as_bytes());
+    let signature = hex::encode(mac.finalize().into_bytes());
+
+    let mut roots = RootCertStore::empty();
+    roots
+        .add(CertificateDer::from_pem_slice(CERT).unwrap())
+        .unwrap();
+    let config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    let tcp = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    let mut tls = TlsConnector::from(Arc::new(config))
+        .connect(ServerName::try_from("localhost").unwrap(), tcp)
+        .await
+        .unwrap();
+
+    let request = format!(
+        "POST {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\nTwitch-Eventsub-Message-Id: {message_id}\r\nTwitch-Eventsub-Message-Timestamp: {timestamp}\r\nTwitch-Eventsub-Message-Signature: sha256={signature}\r\nTwitch-Eventsub-Message-Type: {message_type}\r\n\r\n{body}",
+        body.len()
+    );
+    tls.write_all(request.as_bytes()).await.unwrap();
+
+    let mut response = String::new();
+    tls.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+#[tokio::test]
+async fn serves_over_tls() {
+    let mut events = WebhookServer::new(WebhookHandler::new(SECRET.parse::<Secret>().unwrap()))
+        .bind("127.0.0.1:0".parse().unwrap())
+        .path("/eventsub")
+        .tls(CERT, KEY)
+        .unwrap()
+        .start()
+        .await
+        .unwrap();
+    let port = events.local_addr().port();
+
+    let subscription = "{\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"webhook_callback_verification_pending\", \"type\": \"channel.follow\", \"version\": \"2\", \"cost\": 1, \"condition\": {\"broadcaster_user_id\": \"12826\", \"moderator_user_id\": \"12826\"}, \"transport\": {\"method\": \"webhook\", \"callback\": \"https://localhost/eventsub\"}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}";
+    let body = format!(
+        "{{\"challenge\": \"pogchamp-kappa-360noscope-vohiyo\", \"subscription\": {subscription}}}"
+    );
+
+    let response = post(port, "/eventsub", "webhook_callback_verification", &body).await;
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.ends_with("pogchamp-kappa-360noscope-vohiyo"));
+    assert!(matches!(
+        events.next().await,
+        Some(WebhookEvent::Verification { .. })
+    ));
+
+    let response = post(port, "/other", "webhook_callback_verification", &body).await;
+    assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+}
+
+#[tokio::test]
+async fn rotates_secret_while_running() {
+    let handler = Arc::new(WebhookHandler::new(SECRET.parse::<Secret>().unwrap()));
+    let mut events = WebhookServer::new(handler.clone())
+        .bind("127.0.0.1:0".parse().unwrap())
+        .path("/eventsub")
+        .tls(CERT, KEY)
+        .unwrap()
+        .start()
+        .await
+        .unwrap();
+    let port = events.local_addr().port();
+
+    let body = "{\"subscription\": {\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"authorization_revoked\", \"type\": \"channel.follow\", \"version\": \"2\", \"cost\": 1, \"condition\": {\"broadcaster_user_id\": \"12826\"}, \"transport\": {\"method\": \"webhook\", \"callback\": \"https://localhost/eventsub\"}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}}";
+
+    let response = send(port, SECRET, "1", "/eventsub", "revocation", body).await;
+    assert!(response.starts_with("HTTP/1.1 204 No Content"));
+    assert!(matches!(
+        events.next().await,
+        Some(WebhookEvent::Revocation(_))
+    ));
+
+    handler.rotate_secret("n3w-s3cre7w0rd".parse().unwrap(), Duration::ZERO);
+
+    let response = send(port, SECRET, "2", "/eventsub", "revocation", body).await;
+    assert!(response.starts_with("HTTP/1.1 403 Forbidden"));
+
+    let response = send(port, "n3w-s3cre7w0rd", "3", "/eventsub", "revocation", body).await;
+    assert!(response.starts_with("HTTP/1.1 204 No Content"));
+    assert!(matches!(
+        events.next().await,
+        Some(WebhookEvent::Revocation(_))
+    ));
+}
+
+async fn start(secret: &str) -> WebhookEvents {
+    WebhookServer::new(WebhookHandler::new(secret.parse::<Secret>().unwrap()))
+        .bind("127.0.0.1:0".parse().unwrap())
+        .path("/eventsub")
+        .tls(CERT, KEY)
+        .unwrap()
+        .start()
+        .await
+        .unwrap()
+}
+
+const NOTIFICATION: &str = "{\"subscription\": {\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"enabled\", \"type\": \"stream.online\", \"version\": \"1\", \"cost\": 0, \"condition\": {\"broadcaster_user_id\": \"1337\"}, \"transport\": {\"method\": \"webhook\", \"callback\": \"https://localhost/eventsub\"}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}, \"event\": {\"id\": \"9001\", \"broadcaster_user_id\": \"1337\", \"broadcaster_user_login\": \"cool_user\", \"broadcaster_user_name\": \"Cool_User\", \"type\": \"live\", \"started_at\": \"2020-10-11T10:11:12.123Z\"}}";
+
+#[tokio::test]
+async fn delivers_signed_notification() {
+    let mut events = start(SECRET).await;
+    let port = events.local_addr().port();
+
+    let response = send(port, SECRET, "1", "/eventsub", "notification", NOTIFICATION).await;
+    assert!(response.starts_with("HTTP/1.1 204 No Content"));
+
+    let Some(WebhookEvent::Notification(notification)) = events.next().await else {
+        panic!("expected a notification");
+    };
+    assert_eq!(notification.message_id, "1");
+    assert_eq!(notification.subscription.kind.as_str(), "stream.online");
+    assert_eq!(
+        notification.event,
+        serde_json::from_str::<serde_json::Value>(NOTIFICATION).unwrap()["event"]
+    );
+}
+
+#[tokio::test]
+async fn rejects_bad_signature() {
+    let mut events = start(SECRET).await;
+    let port = events.local_addr().port();
+
+    let response = send(
+        port,
+        "n0t-the-s3cre7",
+        "1",
+        "/eventsub",
+        "notification",
+        NOTIFICATION,
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 403 Forbidden"));
+    assert!(time::timeout(Duration::from_millis(100), events.next())
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn rejects_oversized_body() {
+    let events = WebhookServer::new(WebhookHandler::new(SECRET.parse::<Secret>().unwrap()))
+        .bind("127.0.0.1:0".parse().unwrap())
+        .start()
+        .await
+        .unwrap();
+
+    let body = vec![b' '; 1024 * 1024 + 1];
+    let mut tcp = TcpStream::connect(events.local_addr()).await.unwrap();
+    tcp.write_all(
+        format!(
+            "POST / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n",
+            body.len()
+        )
+        .as_bytes(),
+    )
+    .await
+    .unwrap();
+    // The server may answer before reading everything.
+    let _ = tcp.write_all(&body).await;
+
+    let mut response = vec![0; 64];
+    let n = tcp.read(&mut response).await.unwrap();
+    assert!(String::from_utf8_lossy(&response[..n]).starts_with("HTTP/1.1 413 Payload Too Large"));
+}
@@ -0,0 +1,53 @@
+use asknothingx2_eventsub::twitch::{
+    types::{secret::Secret, Subscription},
+    webhook::{MessageSigner, WebhookEvent, WebhookHandler, WebhookMessageType},
+};
+use http::StatusCode;
+use serde_json::json;
+
+fn subscription() -> Subscription {
+    serde_json::from_str("{\"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\", \"status\": \"enabled\", \"type\": \"channel.follow\", \"version\": \"2\", \"cost\": 1, \"condition\": {\"broadcaster_user_id\": \"12826\", \"moderator_user_id\": \"12826\"}, \"transport\": {\"method\": \"webhook\", \"callback\": \"https://example.com/webhooks/callback\"}, \"created_at\": \"2019-11-16T10:11:12.634234626Z\"}").unwrap()
+}
+
+#[test]
+fn signed_messages_verify() {
+    let secret: Secret = "s3cre7w0rd".parse().unwrap();
+    let signer = MessageSigner::new(secret.clone());
+    let handler = WebhookHandler::new(secret);
+    let subscription = subscription();
+
+    let handled = handler.handle(&signer.verification(&subscription, "challenge"));
+    assert_eq!(handled.response.status(), StatusCode::OK);
+    assert!(matches!(
+        handled.event,
+        Ok(WebhookEvent::Verification { challenge, .. }) if challenge == "challenge"
+    ));
+
+    let request = signer.notification(&subscription, &json!({"user_login": "awesome_user"}));
+    assert_eq!(
+        request.headers()["Twitch-Eventsub-Subscription-Type"],
+        "channel.follow"
+    );
+    let Ok(WebhookEvent::Notification(notification)) = handler.handle(&request).event else {
+        panic!("expected notification");
+    };
+    assert_eq!(notification.event["user_login"], "awesome_user");
+
+    assert!(matches!(
+        handler.handle(&signer.revocation(&subscription)).event,
+        Ok(WebhookEvent::Revocation(_))
+    ));
+}
+
+#[test]
+fn signature_format() {
+    let secret: Secret = "s3cre7w0rd".parse().unwrap();
+    let signature = secret.sign("1", "2019-11-16T10:11:12.634234626Z", b"{}");
+
+    assert!(signature.starts_with("sha256="));
+    assert_eq!(signature.len(), "sha256=".len() + 64);
+
+    let request = MessageSigner::new(secret).sign(WebhookMessageType::Notification, "not json");
+    let handled = WebhookHandler::new("s3cre7w0rd".parse::<Secret>().unwrap()).handle(&request);
+    assert_eq!(handled.response.status(), StatusCode::BAD_REQUEST);
+}
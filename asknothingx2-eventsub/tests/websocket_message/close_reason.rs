@@ -0,0 +1,41 @@
+use asknothingx2_eventsub::twitch::{
+    types::Status,
+    websocket_message::{CloseAction, CloseReason},
+};
+
+#[test]
+pub fn close_codes() {
+    for code in 4000..=4007 {
+        let reason = CloseReason::from_code(code);
+        assert_ne!(reason, CloseReason::Other(code));
+        assert_eq!(reason.code(), code);
+    }
+
+    assert_eq!(CloseReason::from(1000), CloseReason::Other(1000));
+    assert_eq!(
+        CloseReason::ConnectionUnused.to_string(),
+        "connection unused (4003)"
+    );
+}
+
+#[test]
+pub fn close_actions() {
+    assert_eq!(CloseReason::from(4001).action(), CloseAction::Fatal);
+    assert_eq!(CloseReason::from(4003).action(), CloseAction::Resubscribe);
+    for code in [4000, 4002, 4004, 4005, 4006, 4007, 1006] {
+        assert_eq!(CloseReason::from(code).action(), CloseAction::Reconnect);
+    }
+}
+
+#[test]
+pub fn close_status() {
+    assert_eq!(
+        CloseReason::InvalidReconnect.status(),
+        Some(Status::WebsocketFailedToReconnect)
+    );
+    assert_eq!(
+        CloseReason::ClientFailedPingPong.status(),
+        Some(Status::WebsocketFailedPingPong)
+    );
+    assert_eq!(CloseReason::Other(1000).status(), None);
+}
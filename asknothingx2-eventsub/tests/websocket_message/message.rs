@@ -0,0 +1,31 @@
+use asknothingx2_eventsub::twitch::{
+    error::WebSocketError,
+    websocket_message::{MessageType, WebSocketMessage},
+};
+
+#[test]
+pub fn keepalive() {
+    let message: WebSocketMessage = "{\n    \"metadata\": {\n        \"message_id\": \"84c1e79a-2a4b-4c13-ba0b-4312293e9308\",\n        \"message_type\": \"session_keepalive\",\n        \"message_timestamp\": \"2023-07-19T10:11:12.634234626Z\"\n    },\n    \"payload\": {}\n}".parse().unwrap();
+
+    assert!(matches!(message, WebSocketMessage::Keepalive(_)));
+    assert_eq!(message.message_type(), &MessageType::SessionKeepalive);
+}
+
+#[test]
+pub fn notification() {
+    let message: WebSocketMessage = "{\n    \"metadata\": {\n        \"message_id\": \"befa7b53-d79d-478f-86b9-120f112b044e\",\n        \"message_type\": \"notification\",\n        \"message_timestamp\": \"2022-11-16T10:11:12.464757833Z\",\n        \"subscription_type\": \"channel.follow\",\n        \"subscription_version\": \"2\"\n    },\n    \"payload\": {\n        \"subscription\": {\n            \"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\",\n            \"status\": \"enabled\",\n            \"type\": \"channel.follow\",\n            \"version\": \"2\",\n            \"cost\": 1,\n            \"condition\": {\n                \"broadcaster_user_id\": \"12826\"\n            },\n            \"transport\": {\n                \"method\": \"websocket\",\n                \"session_id\": \"AQoQexAWVYKSTIu4ec_2VAxyuhAB\"\n            },\n            \"created_at\": \"2022-11-16T10:11:12.464757833Z\"\n        },\n        \"event\": {\n            \"user_id\": \"1337\",\n            \"user_login\": \"awesome_user\",\n            \"user_name\": \"Awesome_User\",\n            \"broadcaster_user_id\": \"12826\",\n            \"broadcaster_user_login\": \"twitch\",\n            \"broadcaster_user_name\": \"Twitch\",\n            \"followed_at\": \"2023-07-15T18:16:11.17106713Z\"\n        }\n    }\n}".parse().unwrap();
+
+    let WebSocketMessage::Notification(notification) = message else {
+        panic!("expected notification");
+    };
+    assert_eq!(notification.payload.event["user_login"], "awesome_user");
+}
+
+#[test]
+pub fn unknown_message_type() {
+    let err = "{\"metadata\": {\"message_id\": \"1\", \"message_type\": \"session_unknown\", \"message_timestamp\": \"2023-07-19T10:11:12.634234626Z\"}, \"payload\": {}}"
+        .parse::<WebSocketMessage>()
+        .unwrap_err();
+
+    assert!(matches!(err, WebSocketError::InvalidMessageType(kind) if kind == "session_unknown"));
+}
@@ -1,6 +1,10 @@
+mod close_reason;
 mod keepalive;
+mod message;
 mod metadata;
+#[cfg(all(feature = "twitch-webhook", feature = "twitch-conduit"))]
 mod notification;
 mod reconnect;
+#[cfg(all(feature = "twitch-webhook", feature = "twitch-conduit"))]
 mod revocation;
 mod welcome;
@@ -1,12 +1,13 @@
 use asknothingx2_eventsub::twitch::{
-    subscription_types::channel_subscriptions::ChannelFollowPayload,
+    events::channel::ChannelFollowEvent,
+    types::payloads::SubscriptionEventPayload,
     websocket_message::{MessageType, Notification},
+    Condition,
 };
 
 fn_expected_payload!(
     payload: "{\n    \"metadata\": {\n        \"message_id\": \"befa7b53-d79d-478f-86b9-120f112b044e\",\n        \"message_type\": \"notification\",\n        \"message_timestamp\": \"2022-11-16T10:11:12.464757833Z\",\n        \"subscription_type\": \"channel.follow\",\n        \"subscription_version\": \"2\"\n    },\n    \"payload\": {\n        \"subscription\": {\n            \"id\": \"f1c2a387-161a-49f9-a165-0f21d7a4e1c4\",\n            \"status\": \"enabled\",\n            \"type\": \"channel.follow\",\n            \"version\": \"2\",\n            \"cost\": 1,\n            \"condition\": {\n                \"broadcaster_user_id\": \"12826\"\n            },\n            \"transport\": {\n                \"method\": \"websocket\",\n                \"session_id\": \"AQoQexAWVYKSTIu4ec_2VAxyuhAB\"\n            },\n            \"created_at\": \"2022-11-16T10:11:12.464757833Z\"\n        },\n        \"event\": {\n            \"user_id\": \"1337\",\n            \"user_login\": \"awesome_user\",\n            \"user_name\": \"Awesome_User\",\n            \"broadcaster_user_id\": \"12826\",\n            \"broadcaster_user_login\": \"twitch\",\n            \"broadcaster_user_name\": \"Twitch\",\n            \"followed_at\": \"2023-07-15T18:16:11.17106713Z\"\n        }\n    }\n}",
-    from_str: Notification<ChannelFollowPayload>,
-    prefix: payload,
+    from_str: Notification<SubscriptionEventPayload<ChannelFollowEvent>>,
     block meta: {
         message_id: "befa7b53-d79d-478f-86b9-120f112b044e",
         message_type: MessageType::Notification,
@@ -31,14 +32,6 @@ fn_expected_payload!(
         connected_at: None,
         disconnected_at: None
     },
-    block condition: {
-        broadcaster_user_id: Some("12826".to_string()),
-        moderator_user_id: None,
-        broadcaster_id: None,
-        user_id: None,
-        reward_id: None,
-        client_id: None
-    },
     block event: {
         user_id: "1337",
         user_login: "awesome_user",
@@ -48,6 +41,11 @@ fn_expected_payload!(
         broadcaster_user_name: "Twitch",
         followed_at: "2023-07-15T18:16:11.17106713Z"
     },
+    // Twitch's example omits `moderator_user_id`, so the condition isn't a
+    // `BroadcasterModeratorCondition`.
+    extra subscription: {
+        condition: Condition::Unknown(serde_json::json!({"broadcaster_user_id": "12826"}))
+    },
     se contain: [
         "\"message_id\":\"befa7b53-d79d-478f-86b9-120f112b044e\"",
         "\"message_type\":\"notification\"",
@@ -68,6 +66,7 @@ fn_expected_payload!(
         "\"user_name\":\"Awesome_User\"",
         "\"broadcaster_user_id\":\"12826\"",
         "\"broadcaster_user_login\":\"twitch\"",
-        "\"broadcaster_user_name\":\"Twitch\"" // "\"followed_at\":\"2023-07-15T18:16:11.17106713Z\""
+        "\"broadcaster_user_name\":\"Twitch\"",
+        "\"followed_at\":\"2023-07-15T18:16:11.171067130Z\""
     ]
 );
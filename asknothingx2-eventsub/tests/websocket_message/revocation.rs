@@ -30,13 +30,10 @@ fn_expected_payload!(
         connected_at: None,
         disconnected_at: None
     },
-    block condition: {
-            broadcaster_user_id: Some("12826".to_string()),
-            moderator_user_id: None,
-            broadcaster_id: None,
-            user_id: None,
-            reward_id: None,
-            client_id: None
+    // Twitch's example omits `moderator_user_id`, so the condition isn't a
+    // `BroadcasterModeratorCondition`.
+    extra subscription: {
+        condition: Condition::Unknown(serde_json::json!({"broadcaster_user_id": "12826"}))
     },
     se contain: [
         "\"message_id\":\"84c1e79a-2a4b-4c13-ba0b-4312293e9308\"",